@@ -1,4 +1,5 @@
 import React, { useState, useCallback, useEffect, useRef } from 'react';
+import { platform } from '@tauri-apps/plugin-os';
 import Icon from './components/Icon';
 import Sidebar from './components/Sidebar';
 import StatusBar from './components/StatusBar';
@@ -6,14 +7,21 @@ import ProjectList from './components/ProjectList';
 import SettingsPage from './components/SettingsPage';
 import StatisticsPage from './components/StatisticsPage';
 import ActivityPage from './components/ActivityPage';
+import UnpushedPage from './components/UnpushedPage';
+import DuplicatesPage from './components/DuplicatesPage';
 import EmptyState from './components/EmptyState';
 import ToastContainer from './components/ToastContainer';
 import ConfirmDialog from './components/ConfirmDialog';
 import ScriptsModal from './components/ScriptsModal';
+import BranchesModal from './components/BranchesModal';
+import StashesModal from './components/StashesModal';
+import WorktreesModal from './components/WorktreesModal';
+import HistoryModal from './components/HistoryModal';
 import NotesModal from './components/NotesModal';
 import TagsModal from './components/TagsModal';
 import BulkActionsBar from './components/BulkActionsBar';
 import NewProjectModal from './components/NewProjectModal';
+import TeamSetupModal from './components/TeamSetupModal';
 import { useSettings } from './hooks/useSettings';
 import { ViewType, SortOption, Project, ProjectScript } from './types';
 import { useProjects } from './hooks/useProjects';
@@ -40,6 +48,8 @@ const SORT_OPTIONS: { value: SortOption; label: string; icon: string }[] = [
 const App: React.FC = () => {
     const [searchQuery, setSearchQuery] = useState('');
     const [viewMode, setViewMode] = useState<'list' | 'grid'>('list');
+    // App launchers are freedesktop entries, so they only exist on Linux
+    const [isLinux, setIsLinux] = useState(false);
     const [currentView, setCurrentView] = useState<ViewType>('library');
     const [showSortDropdown, setShowSortDropdown] = useState(false);
     const [activityLog, setActivityLog] = useState<ActivityItem[]>(() => {
@@ -53,6 +63,18 @@ const App: React.FC = () => {
     const [scriptsModal, setScriptsModal] = useState<{ isOpen: boolean; project: Project | null; scripts: ProjectScript[]; loading: boolean }>({
         isOpen: false, project: null, scripts: [], loading: false
     });
+    const [branchesModal, setBranchesModal] = useState<{ isOpen: boolean; projectIds: string[] }>({
+        isOpen: false, projectIds: []
+    });
+    const [stashesModal, setStashesModal] = useState<{ isOpen: boolean; project: Project | null }>({
+        isOpen: false, project: null
+    });
+    const [historyModal, setHistoryModal] = useState<{ isOpen: boolean; project: Project | null }>({
+        isOpen: false, project: null
+    });
+    const [worktreesModal, setWorktreesModal] = useState<{ isOpen: boolean; project: Project | null }>({
+        isOpen: false, project: null
+    });
     const [notesModal, setNotesModal] = useState<{ isOpen: boolean; project: Project | null }>({
         isOpen: false, project: null
     });
@@ -70,6 +92,7 @@ const App: React.FC = () => {
     }>({ isOpen: false, title: '', message: '', onConfirm: () => { } });
     const [bulkDeleting, setBulkDeleting] = useState(false);
     const [showNewProjectModal, setShowNewProjectModal] = useState(false);
+    const [showTeamSetup, setShowTeamSetup] = useState(false);
 
     const searchInputRef = useRef<HTMLInputElement>(null);
     const sortDropdownRef = useRef<HTMLDivElement>(null);
@@ -91,6 +114,7 @@ const App: React.FC = () => {
         clearSelection,
         addProject,
         addProjectFromPath,
+        cloneProject,
         removeProject,
         refreshProject,
         openInIde,
@@ -106,8 +130,35 @@ const App: React.FC = () => {
         runScript,
         gitPull,
         gitFetch,
+        bulkGit,
+        bulkGitProgress,
+        getCommitLog,
+        getDiffSummary,
+        getLibraryActivity,
+        getUnpushedReport,
+        findDuplicateClones,
+        removeDuplicateClone,
+        readTeamManifest,
+        bootstrapTeam,
+        launchTeamProfile,
+        updateSubmodules,
+        lfsPull,
+        openRemotePage,
+        listWorktrees,
+        addWorktree,
+        removeWorktree,
+        pruneWorktrees,
+        listStashes,
+        createStash,
+        applyStash,
+        dropStash,
+        listBranches,
+        switchBranch,
+        createBranch,
+        deleteMergedBranches,
         checkHealth,
         togglePin,
+        toggleLauncher,
         updateNotes,
         updateTags,
         bulkDeleteNodeModules,
@@ -120,6 +171,14 @@ const App: React.FC = () => {
         resetSettings,
     } = useSettings();
 
+    useEffect(() => {
+        try {
+            setIsLinux(platform() === 'linux');
+        } catch (e) {
+            console.error('Failed to get platform:', e);
+        }
+    }, []);
+
     // Save activity log to localStorage
     useEffect(() => {
         localStorage.setItem('devlaunch-activity', JSON.stringify(activityLog));
@@ -282,6 +341,102 @@ const App: React.FC = () => {
         }
     }, [scriptsModal.project, runScript, addActivity]);
 
+    const handleOpenBranches = useCallback((projectId: string) => {
+        setBranchesModal({ isOpen: true, projectIds: [projectId] });
+    }, []);
+
+    const handleBulkBranches = useCallback(() => {
+        const ids = projects.filter(p => selectedIds.has(p.id) && p.hasGit).map(p => p.id);
+        if (ids.length > 0) setBranchesModal({ isOpen: true, projectIds: ids });
+    }, [projects, selectedIds]);
+
+    const closeBranchesModal = useCallback(() => {
+        setBranchesModal({ isOpen: false, projectIds: [] });
+    }, []);
+
+    // Projects with uncommitted changes are skipped; offer to stash and retry those
+    const handleSwitchBranch = useCallback(async (branch: string) => {
+        const ids = branchesModal.projectIds;
+        closeBranchesModal();
+        const results = await switchBranch(ids, branch, false);
+        const dirty = projects.filter(p => results.some(r => r.dirty && r.path === p.path));
+        if (dirty.length === 0) return;
+
+        setConfirmDialog({
+            isOpen: true,
+            title: 'Uncommitted changes',
+            message: `${dirty.map(p => p.name).join(', ')} ${dirty.length > 1 ? 'have' : 'has'} uncommitted changes. Stash them and switch to ${branch}?`,
+            variant: 'warning',
+            confirmLabel: 'Stash & Switch',
+            onConfirm: async () => {
+                setConfirmDialog(prev => ({ ...prev, isOpen: false }));
+                await switchBranch(dirty.map(p => p.id), branch, true);
+            },
+        });
+    }, [branchesModal.projectIds, closeBranchesModal, switchBranch, projects]);
+
+    const handleDeleteMergedBranches = useCallback(() => {
+        const ids = branchesModal.projectIds;
+        setConfirmDialog({
+            isOpen: true,
+            title: 'Delete merged branches?',
+            message: `Local branches fully merged into the default branch will be deleted from ${ids.length} project${ids.length > 1 ? 's' : ''}. The current and default branches are kept.`,
+            variant: 'warning',
+            onConfirm: async () => {
+                setConfirmDialog(prev => ({ ...prev, isOpen: false }));
+                closeBranchesModal();
+                await deleteMergedBranches(ids);
+            },
+        });
+    }, [branchesModal.projectIds, closeBranchesModal, deleteMergedBranches]);
+
+    const handleOpenStashes = useCallback((projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (project) setStashesModal({ isOpen: true, project });
+    }, [projects]);
+
+    const handleOpenWorktrees = useCallback((projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (project) setWorktreesModal({ isOpen: true, project });
+    }, [projects]);
+
+    const handleRemoveWorktree = useCallback(async (worktree: string) => {
+        const projectId = worktreesModal.project?.id || '';
+        if (await removeWorktree(projectId, worktree) !== 'dirty') return;
+
+        setWorktreesModal({ isOpen: false, project: null });
+        setConfirmDialog({
+            isOpen: true,
+            title: 'Uncommitted changes',
+            message: `${worktree} has uncommitted or untracked files. Remove it anyway? Those changes will be lost.`,
+            variant: 'danger',
+            confirmLabel: 'Remove Anyway',
+            onConfirm: async () => {
+                setConfirmDialog(prev => ({ ...prev, isOpen: false }));
+                await removeWorktree(projectId, worktree, true);
+            },
+        });
+    }, [worktreesModal.project, removeWorktree]);
+
+    const handleRemoveClone = useCallback((path: string, others: string[], onRemoved: () => void) => {
+        setConfirmDialog({
+            isOpen: true,
+            title: 'Delete clone',
+            message: `Delete ${path} from disk? Nothing is deleted while it has uncommitted changes, ignored files such as .env, stashes or commits that no other clone or remote has. Ignored build output and dependencies are deleted.`,
+            variant: 'danger',
+            confirmLabel: 'Delete Clone',
+            onConfirm: async () => {
+                setConfirmDialog(prev => ({ ...prev, isOpen: false }));
+                if (await removeDuplicateClone(path, others)) onRemoved();
+            },
+        });
+    }, [removeDuplicateClone]);
+
+    const handleOpenHistory = useCallback((projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (project) setHistoryModal({ isOpen: true, project });
+    }, [projects]);
+
     const handleOpenNotes = useCallback((projectId: string) => {
         const project = projects.find(p => p.id === projectId);
         if (project) {
@@ -317,12 +472,24 @@ const App: React.FC = () => {
         }
     }, [projects, installDependencies, addActivity]);
 
-    const handleGitPull = useCallback((projectId: string) => {
+    const handleGitPull = useCallback(async (projectId: string) => {
         const project = projects.find(p => p.id === projectId);
-        gitPull(projectId);
         if (project) {
             addActivity('git', project.name, 'Pulled latest changes', 'download', 'bg-orange-500/10 text-orange-400', project.path);
         }
+        if (await gitPull(projectId) !== 'blocked') return;
+
+        setConfirmDialog({
+            isOpen: true,
+            title: 'Uncommitted changes',
+            message: `Local changes in ${project?.name} would be overwritten by the pull. Stash them, pull, and re-apply them afterwards?`,
+            variant: 'warning',
+            confirmLabel: 'Stash & Pull',
+            onConfirm: async () => {
+                setConfirmDialog(prev => ({ ...prev, isOpen: false }));
+                await gitPull(projectId, true);
+            },
+        });
     }, [projects, gitPull, addActivity]);
 
     const handleGitFetch = useCallback((projectId: string) => {
@@ -434,6 +601,20 @@ const App: React.FC = () => {
                 ) : currentView === 'statistics' ? (
                     <StatisticsPage
                         projects={projects}
+                        loadActivity={getLibraryActivity}
+                        onClose={() => setCurrentView('library')}
+                    />
+                ) : currentView === 'unpushed' ? (
+                    <UnpushedPage
+                        projects={projects}
+                        loadReport={getUnpushedReport}
+                        onClose={() => setCurrentView('library')}
+                    />
+                ) : currentView === 'duplicates' ? (
+                    <DuplicatesPage
+                        projects={projects}
+                        loadGroups={findDuplicateClones}
+                        onRemoveClone={handleRemoveClone}
                         onClose={() => setCurrentView('library')}
                     />
                 ) : currentView === 'activity' ? (
@@ -617,6 +798,9 @@ const App: React.FC = () => {
                             onClearSelection={clearSelection}
                             onBulkDeleteNodeModules={handleBulkDeleteNodeModules}
                             onBulkCleanBuildFolders={handleBulkCleanBuildFolders}
+                            onBulkBranches={handleBulkBranches}
+                            onBulkGit={(op) => bulkGit(Array.from(selectedIds), op)}
+                            bulkGitProgress={bulkGitProgress}
                             isDeleting={bulkDeleting}
                         />
 
@@ -642,10 +826,18 @@ const App: React.FC = () => {
                                 onRunScripts={handleOpenScripts}
                                 onGitPull={handleGitPull}
                                 onGitFetch={handleGitFetch}
+                                onBranches={handleOpenBranches}
+                                onStashes={handleOpenStashes}
+                                onWorktrees={handleOpenWorktrees}
+                                onUpdateSubmodules={updateSubmodules}
+                                onLfsPull={lfsPull}
+                                onOpenUrl={openRemotePage}
+                                onHistory={handleOpenHistory}
                                 onCheckHealth={checkHealth}
                                 onEditNotes={handleOpenNotes}
                                 onManageTags={handleOpenTags}
                                 onTogglePin={togglePin}
+                                onToggleLauncher={isLinux ? toggleLauncher : undefined}
                             />
                         )}
                     </>
@@ -668,6 +860,46 @@ const App: React.FC = () => {
                 onClose={() => setScriptsModal({ isOpen: false, project: null, scripts: [], loading: false })}
             />
 
+            <BranchesModal
+                isOpen={branchesModal.isOpen}
+                projects={projects.filter(p => branchesModal.projectIds.includes(p.id))}
+                loadBranches={listBranches}
+                onSwitch={handleSwitchBranch}
+                onCreate={(name, startPoint, switchTo) => createBranch(branchesModal.projectIds[0], name, startPoint, switchTo)}
+                onDeleteMerged={handleDeleteMergedBranches}
+                onClose={closeBranchesModal}
+            />
+
+            <WorktreesModal
+                isOpen={worktreesModal.isOpen}
+                projectName={worktreesModal.project?.name || ''}
+                projectPath={worktreesModal.project?.path || ''}
+                loadWorktrees={() => listWorktrees(worktreesModal.project?.id || '')}
+                onAdd={(target, branch, create) => addWorktree(worktreesModal.project?.id || '', target, branch, create)}
+                onRemove={handleRemoveWorktree}
+                onPrune={() => pruneWorktrees(worktreesModal.project?.id || '')}
+                onClose={() => setWorktreesModal({ isOpen: false, project: null })}
+            />
+
+            <StashesModal
+                isOpen={stashesModal.isOpen}
+                projectName={stashesModal.project?.name || ''}
+                loadStashes={() => listStashes(stashesModal.project?.id || '')}
+                onCreate={(message, includeUntracked) => createStash(stashesModal.project?.id || '', message, includeUntracked)}
+                onApply={(index, pop) => applyStash(stashesModal.project?.id || '', index, pop)}
+                onDrop={(index) => dropStash(stashesModal.project?.id || '', index)}
+                onClose={() => setStashesModal({ isOpen: false, project: null })}
+            />
+
+            <HistoryModal
+                isOpen={historyModal.isOpen}
+                projectName={historyModal.project?.name || ''}
+                loadLog={(branch) => getCommitLog(historyModal.project?.id || '', branch)}
+                loadChanges={() => getDiffSummary(historyModal.project?.id || '')}
+                loadBranches={() => listBranches(historyModal.project?.id || '')}
+                onClose={() => setHistoryModal({ isOpen: false, project: null })}
+            />
+
             <NotesModal
                 isOpen={notesModal.isOpen}
                 projectName={notesModal.project?.name || ''}
@@ -702,6 +934,8 @@ const App: React.FC = () => {
                 isOpen={showNewProjectModal}
                 onClose={() => setShowNewProjectModal(false)}
                 onImportProject={addProject}
+                onCloneProject={cloneProject}
+                onTeamSetup={() => setShowTeamSetup(true)}
                 onProjectCreated={(path) => {
                     // Show toast that project is being created
                     // The user will need to add it manually after the terminal completes
@@ -720,6 +954,15 @@ const App: React.FC = () => {
                 }}
             />
 
+            {/* Team Workspace Modal */}
+            <TeamSetupModal
+                isOpen={showTeamSetup}
+                readManifest={readTeamManifest}
+                onBootstrap={bootstrapTeam}
+                onLaunchProfile={launchTeamProfile}
+                onClose={() => setShowTeamSetup(false)}
+            />
+
             {/* Import Loading Overlay */}
             {importing && (
                 <div className="fixed inset-0 z-50 flex items-center justify-center bg-background-dark/80 backdrop-blur-sm">
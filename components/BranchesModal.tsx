@@ -0,0 +1,284 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { BranchInfo, Project } from '../types';
+import Icon from './Icon';
+
+interface BranchesModalProps {
+    isOpen: boolean;
+    // One project, or the bulk selection
+    projects: Project[];
+    loadBranches: (projectId: string) => Promise<BranchInfo[]>;
+    onSwitch: (branch: string) => void;
+    onCreate: (name: string, startPoint: string, switchTo: boolean) => Promise<boolean>;
+    onDeleteMerged: () => void;
+    onClose: () => void;
+}
+
+// A branch as seen across the selected projects
+interface BranchRow {
+    branch: BranchInfo;
+    projectCount: number;
+    currentCount: number;
+}
+
+const getRelativeTime = (value: string): string => {
+    const date = new Date(value);
+    if (isNaN(date.getTime())) return '';
+    const diffMin = Math.floor((Date.now() - date.getTime()) / 60000);
+    const diffHour = Math.floor(diffMin / 60);
+    const diffDay = Math.floor(diffHour / 24);
+
+    if (diffMin < 1) return 'just now';
+    if (diffMin < 60) return `${diffMin}m ago`;
+    if (diffHour < 24) return `${diffHour}h ago`;
+    if (diffDay < 30) return `${diffDay}d ago`;
+    return date.toLocaleDateString();
+};
+
+const BranchesModal: React.FC<BranchesModalProps> = ({
+    isOpen,
+    projects,
+    loadBranches,
+    onSwitch,
+    onCreate,
+    onDeleteMerged,
+    onClose,
+}) => {
+    const [rows, setRows] = useState<BranchRow[]>([]);
+    const [loading, setLoading] = useState(false);
+    const [searchQuery, setSearchQuery] = useState('');
+    const [newName, setNewName] = useState('');
+    const [startPoint, setStartPoint] = useState('');
+    const [switchToNew, setSwitchToNew] = useState(true);
+    const [creating, setCreating] = useState(false);
+
+    const isBulk = projects.length > 1;
+
+    const reload = useCallback(async () => {
+        setLoading(true);
+        const lists = await Promise.all(projects.map(p => loadBranches(p.id)));
+        const byName = new Map<string, BranchRow>();
+        lists.flat().forEach(branch => {
+            const row = byName.get(branch.name);
+            if (row) {
+                row.projectCount++;
+                if (branch.isCurrent) row.currentCount++;
+            } else {
+                byName.set(branch.name, { branch, projectCount: 1, currentCount: branch.isCurrent ? 1 : 0 });
+            }
+        });
+        setRows(Array.from(byName.values()));
+        setLoading(false);
+    }, [projects, loadBranches]);
+
+    useEffect(() => {
+        if (isOpen) {
+            reload();
+        } else {
+            setRows([]);
+            setSearchQuery('');
+            setNewName('');
+            setStartPoint('');
+            setSwitchToNew(true);
+        }
+        // Only reload when the modal opens, not on every library change
+    }, [isOpen]);
+
+    useEffect(() => {
+        const handleEsc = (e: KeyboardEvent) => {
+            if (e.key === 'Escape') onClose();
+        };
+        if (isOpen) {
+            document.addEventListener('keydown', handleEsc);
+            return () => document.removeEventListener('keydown', handleEsc);
+        }
+    }, [isOpen, onClose]);
+
+    if (!isOpen || projects.length === 0) return null;
+
+    const filteredRows = rows.filter(row =>
+        row.branch.name.toLowerCase().includes(searchQuery.toLowerCase())
+    );
+    const localRows = filteredRows.filter(row => !row.branch.isRemote);
+    const remoteRows = filteredRows.filter(row => row.branch.isRemote);
+
+    const handleCreate = async () => {
+        if (!newName.trim()) return;
+        setCreating(true);
+        const created = await onCreate(newName.trim(), startPoint.trim(), switchToNew);
+        setCreating(false);
+        if (created) {
+            setNewName('');
+            setStartPoint('');
+            reload();
+        }
+    };
+
+    const renderRow = ({ branch, projectCount, currentCount }: BranchRow) => {
+        const isCurrent = isBulk ? currentCount === projects.length : branch.isCurrent;
+        return (
+            <div
+                key={branch.name}
+                className="group flex items-center justify-between px-3 py-2 rounded-lg hover:bg-surface transition-colors"
+            >
+                <div className="flex-1 min-w-0">
+                    <div className="flex items-center gap-2">
+                        {isCurrent && <Icon name="check_circle" className="text-[14px] text-emerald-400" />}
+                        <span className="text-sm font-medium text-white font-mono truncate">{branch.name}</span>
+                        {!branch.isRemote && (branch.ahead > 0 || branch.behind > 0) && (
+                            <span className="text-[10px] text-yellow-400 font-mono">
+                                {branch.behind > 0 && `↓${branch.behind} `}{branch.ahead > 0 && `↑${branch.ahead}`}
+                            </span>
+                        )}
+                        {isBulk && (
+                            <span className="text-[10px] text-slate-500">
+                                {projectCount}/{projects.length} projects
+                            </span>
+                        )}
+                    </div>
+                    {!isBulk && (
+                        <p className="text-xs text-slate-500 truncate mt-0.5" title={branch.summary}>
+                            <span className="font-mono">{branch.commit}</span> {branch.summary}
+                            <span className="text-slate-600"> · {getRelativeTime(branch.lastCommitDate)}</span>
+                        </p>
+                    )}
+                </div>
+                {!isCurrent && (
+                    <button
+                        onClick={() => onSwitch(branch.name)}
+                        className="opacity-0 group-hover:opacity-100 flex items-center gap-1.5 px-3 py-1.5 bg-primary/10 hover:bg-primary/20 text-primary rounded-lg text-xs font-medium transition-all"
+                    >
+                        <Icon name="call_split" className="text-[14px]" />
+                        Switch
+                    </button>
+                )}
+            </div>
+        );
+    };
+
+    const renderGroup = (label: string, icon: string, group: BranchRow[]) => group.length > 0 && (
+        <div>
+            <div className="flex items-center gap-2 px-2 mb-2">
+                <Icon name={icon} className="text-[14px] text-slate-400" />
+                <span className="text-xs font-medium text-slate-400 uppercase tracking-wide">{label}</span>
+                <span className="text-[10px] text-slate-600 font-mono">({group.length})</span>
+            </div>
+            <div className="space-y-1">{group.map(renderRow)}</div>
+        </div>
+    );
+
+    return (
+        <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/60 backdrop-blur-sm animate-fade-in">
+            <div className="modal-dialog w-full max-w-2xl bg-background border border-border-dim rounded-xl shadow-2xl overflow-hidden">
+                {/* Header */}
+                <div className="flex items-center justify-between px-6 py-4 border-b border-border-dim bg-surface/50">
+                    <div className="flex items-center gap-3">
+                        <div className="size-10 rounded-lg bg-primary/10 border border-primary/20 flex items-center justify-center">
+                            <Icon name="call_split" className="text-[24px] text-primary" />
+                        </div>
+                        <div>
+                            <h2 className="text-lg font-bold text-white">Branches</h2>
+                            <p className="text-xs text-slate-500 font-mono">
+                                {isBulk ? `${projects.length} projects` : projects[0].name}
+                            </p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={onClose}
+                        className="size-8 rounded-lg hover:bg-white/5 flex items-center justify-center text-slate-400 hover:text-white transition-colors"
+                    >
+                        <Icon name="close" className="text-[20px]" />
+                    </button>
+                </div>
+
+                {/* Search */}
+                <div className="px-6 py-3 border-b border-border-dim">
+                    <div className="relative">
+                        <Icon name="search" className="absolute left-3 top-1/2 -translate-y-1/2 text-[18px] text-slate-500" />
+                        <input
+                            type="text"
+                            value={searchQuery}
+                            onChange={(e) => setSearchQuery(e.target.value)}
+                            placeholder="Search branches..."
+                            className="w-full pl-10 pr-4 py-2 bg-surface border border-border-dim rounded-lg text-sm text-white placeholder-slate-500 focus:outline-none focus:border-primary/50"
+                            autoFocus
+                        />
+                    </div>
+                </div>
+
+                {/* Branch List */}
+                <div className="max-h-[360px] overflow-y-auto custom-scrollbar">
+                    {loading ? (
+                        <div className="flex flex-col items-center justify-center py-12 gap-3">
+                            <Icon name="progress_activity" className="text-[32px] text-primary animate-spin" />
+                            <p className="text-sm text-slate-400">Loading branches...</p>
+                        </div>
+                    ) : filteredRows.length === 0 ? (
+                        <div className="flex flex-col items-center justify-center py-12 gap-3">
+                            <Icon name="search_off" className="text-[48px] text-slate-600" />
+                            <p className="text-sm text-slate-400">
+                                {rows.length === 0 ? 'No branches found' : `No branches match "${searchQuery}"`}
+                            </p>
+                        </div>
+                    ) : (
+                        <div className="p-4 space-y-4">
+                            {renderGroup('Local', 'folder', localRows)}
+                            {renderGroup('Remote', 'language', remoteRows)}
+                        </div>
+                    )}
+                </div>
+
+                {/* New Branch */}
+                {!isBulk && (
+                    <div className="px-6 py-3 border-t border-border-dim flex items-center gap-2">
+                        <input
+                            type="text"
+                            value={newName}
+                            onChange={(e) => setNewName(e.target.value)}
+                            onKeyDown={(e) => e.key === 'Enter' && handleCreate()}
+                            placeholder="New branch name"
+                            className="flex-1 px-3 py-1.5 bg-surface border border-border-dim rounded-lg text-sm text-white placeholder-slate-500 focus:outline-none focus:border-primary/50 font-mono"
+                        />
+                        <input
+                            type="text"
+                            value={startPoint}
+                            onChange={(e) => setStartPoint(e.target.value)}
+                            placeholder="from HEAD"
+                            className="w-36 px-3 py-1.5 bg-surface border border-border-dim rounded-lg text-sm text-white placeholder-slate-500 focus:outline-none focus:border-primary/50 font-mono"
+                        />
+                        <label className="flex items-center gap-1.5 text-xs text-slate-400 select-none">
+                            <input type="checkbox" checked={switchToNew} onChange={(e) => setSwitchToNew(e.target.checked)} />
+                            Switch
+                        </label>
+                        <button
+                            onClick={handleCreate}
+                            disabled={!newName.trim() || creating}
+                            className="flex items-center gap-1.5 px-3 py-1.5 bg-primary/10 hover:bg-primary/20 text-primary rounded-lg text-xs font-medium transition-colors disabled:opacity-50"
+                        >
+                            <Icon name={creating ? 'progress_activity' : 'add'} className={`text-[14px] ${creating ? 'animate-spin' : ''}`} />
+                            Create
+                        </button>
+                    </div>
+                )}
+
+                {/* Footer */}
+                <div className="px-6 py-3 border-t border-border-dim bg-surface/30 flex items-center justify-between">
+                    <button
+                        onClick={onDeleteMerged}
+                        className="flex items-center gap-1.5 px-3 py-1.5 text-orange-400 hover:bg-orange-500/10 rounded-lg text-xs font-medium transition-colors"
+                        title="Delete local branches fully merged into the default branch"
+                    >
+                        <Icon name="delete_sweep" className="text-[14px]" />
+                        Delete merged branches
+                    </button>
+                    <div className="flex items-center gap-2">
+                        <span className="text-xs text-slate-600">Press</span>
+                        <kbd className="px-1.5 py-0.5 bg-surface border border-border-dim rounded text-[10px] font-mono text-slate-400">ESC</kbd>
+                        <span className="text-xs text-slate-600">to close</span>
+                    </div>
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default BranchesModal;
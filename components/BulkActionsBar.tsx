@@ -1,6 +1,6 @@
 import React from 'react';
 import Icon from './Icon';
-import { Project } from '../types';
+import { Project, BulkGitOp, BulkGitProgress } from '../types';
 
 interface BulkActionsBarProps {
     selectedCount: number;
@@ -10,6 +10,9 @@ interface BulkActionsBarProps {
     onClearSelection: () => void;
     onBulkDeleteNodeModules: () => void;
     onBulkCleanBuildFolders?: () => void;
+    onBulkBranches?: () => void;
+    onBulkGit?: (op: BulkGitOp) => void;
+    bulkGitProgress?: BulkGitProgress | null;
     isDeleting: boolean;
 }
 
@@ -21,6 +24,9 @@ const BulkActionsBar: React.FC<BulkActionsBarProps> = ({
     onClearSelection,
     onBulkDeleteNodeModules,
     onBulkCleanBuildFolders,
+    onBulkBranches,
+    onBulkGit,
+    bulkGitProgress,
     isDeleting,
 }) => {
     if (selectedCount === 0) return null;
@@ -38,6 +44,7 @@ const BulkActionsBar: React.FC<BulkActionsBarProps> = ({
     // Count projects with node_modules and build folders
     const nodeModulesCount = selectedProjects.filter(p => p.hasNodeModules).length;
     const buildFolderCount = selectedProjects.filter(p => p.hasBuildFolder && !p.hasNodeModules).length;
+    const gitCount = selectedProjects.filter(p => p.hasGit).length;
 
     return (
         <div className="bulk-actions-bar sticky top-0 z-40 mx-6 border-l border-r bg-surface/95 backdrop-blur border-b border-border-dim px-6 py-3 animate-fade-in">
@@ -74,6 +81,38 @@ const BulkActionsBar: React.FC<BulkActionsBarProps> = ({
                 </div>
 
                 <div className="flex items-center gap-3">
+                    {gitCount > 0 && onBulkGit && (bulkGitProgress ? (
+                        <span className="flex items-center gap-2 px-3 py-2 text-xs text-slate-400">
+                            <Icon name="progress_activity" className="text-[16px] text-primary animate-spin" />
+                            {bulkGitProgress.op === 'pull' ? 'Pulling' : bulkGitProgress.op === 'fetch' ? 'Fetching' : 'Checking'}{' '}
+                            {bulkGitProgress.done}/{bulkGitProgress.total}
+                        </span>
+                    ) : (
+                        <div className="flex items-center gap-1">
+                            {([['fetch', 'cloud_download', 'Fetch'], ['pull', 'download', 'Pull'], ['status', 'refresh', 'Status']] as const).map(([op, icon, label]) => (
+                                <button
+                                    key={op}
+                                    onClick={() => onBulkGit(op)}
+                                    className="flex items-center gap-1.5 px-2.5 py-2 text-slate-300 hover:text-white hover:bg-surface-highlight rounded-lg text-xs font-medium transition-colors"
+                                    title={op === 'pull' ? 'Fast-forward only; projects with uncommitted changes are skipped' : undefined}
+                                >
+                                    <Icon name={icon} className="text-[16px]" />
+                                    {label}
+                                </button>
+                            ))}
+                        </div>
+                    ))}
+
+                    {gitCount > 0 && onBulkBranches && (
+                        <button
+                            onClick={onBulkBranches}
+                            className="flex items-center gap-2 px-3 py-2 bg-primary/10 hover:bg-primary/20 border border-primary/20 text-primary rounded-lg text-xs font-medium transition-colors"
+                        >
+                            <Icon name="call_split" className="text-[16px]" />
+                            Branches ({gitCount})
+                        </button>
+                    )}
+
                     {/* Bulk Actions - Conditional based on project types */}
                     {isMixed ? (
                         // Mixed selection: show both options
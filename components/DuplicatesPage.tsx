@@ -0,0 +1,175 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Project, DuplicateGroup } from '../types';
+import Icon from './Icon';
+
+interface DuplicatesPageProps {
+    projects: Project[];
+    loadGroups: () => Promise<DuplicateGroup[]>;
+    // Asks for confirmation, then calls onRemoved once the clone is gone
+    onRemoveClone: (path: string, others: string[], onRemoved: () => void) => void;
+    onClose: () => void;
+}
+
+const formatBytes = (bytes: number): string => {
+    if (bytes < 1024) return bytes + ' B';
+    if (bytes < 1024 * 1024) return (bytes / 1024).toFixed(1) + ' KB';
+    if (bytes < 1024 * 1024 * 1024) return (bytes / (1024 * 1024)).toFixed(1) + ' MB';
+    return (bytes / (1024 * 1024 * 1024)).toFixed(2) + ' GB';
+};
+
+const DuplicatesPage: React.FC<DuplicatesPageProps> = ({ projects, loadGroups, onRemoveClone, onClose }) => {
+    const [groups, setGroups] = useState<DuplicateGroup[]>([]);
+    const [loading, setLoading] = useState(true);
+
+    const reload = useCallback(async () => {
+        setLoading(true);
+        setGroups(await loadGroups());
+        setLoading(false);
+    }, [loadGroups]);
+
+    useEffect(() => {
+        reload();
+    }, []);
+
+    const redundantClones = groups.reduce((sum, group) => sum + group.clones.length - 1, 0);
+    // Space freed by keeping only the largest clone of each repository
+    const reclaimable = groups.reduce((sum, group) => {
+        const sizes = group.clones.map(c => c.size);
+        return sum + sizes.reduce((a, b) => a + b, 0) - Math.max(...sizes);
+    }, 0);
+
+    return (
+        <div className="flex-1 flex flex-col overflow-hidden">
+            {/* Header */}
+            <header className="header-bg h-auto shrink-0 border-b border-border-dim bg-background-dark/80 backdrop-blur-md z-10">
+                <div className="flex items-center justify-between px-6 py-4">
+                    <div className="flex items-center gap-4">
+                        <button
+                            onClick={onClose}
+                            className="size-8 rounded flex items-center justify-center hover:bg-white/5 text-slate-400 hover:text-white transition-colors"
+                        >
+                            <Icon name="arrow_back" className="text-[20px]" />
+                        </button>
+                        <div>
+                            <h2 className="text-white text-xl font-bold tracking-tight">Duplicate Clones</h2>
+                            <p className="text-slate-500 text-xs font-mono">
+                                Repositories cloned more than once, by remote or first commit
+                            </p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={reload}
+                        disabled={loading}
+                        className="flex items-center gap-2 px-3 py-1.5 text-xs text-slate-400 hover:text-white hover:bg-white/5 rounded transition-colors disabled:opacity-50"
+                    >
+                        <Icon name="refresh" className={`text-[16px] ${loading ? 'animate-spin' : ''}`} />
+                        Refresh
+                    </button>
+                </div>
+            </header>
+
+            {/* Content */}
+            <div className="flex-1 overflow-y-auto custom-scrollbar">
+                <div className="max-w-4xl mx-auto p-6">
+                    {/* Stats Cards */}
+                    <div className="grid grid-cols-3 gap-4 mb-6">
+                        <div className="bg-surface border border-border-dim rounded-lg p-4">
+                            <div className="flex items-center gap-3">
+                                <div className="size-10 rounded-lg bg-blue-500/10 flex items-center justify-center">
+                                    <Icon name="content_copy" className="text-blue-400" />
+                                </div>
+                                <div>
+                                    <p className="text-2xl font-bold text-white">{groups.length}</p>
+                                    <p className="text-xs text-slate-500">Repositories</p>
+                                </div>
+                            </div>
+                        </div>
+                        <div className="bg-surface border border-border-dim rounded-lg p-4">
+                            <div className="flex items-center gap-3">
+                                <div className="size-10 rounded-lg bg-orange-500/10 flex items-center justify-center">
+                                    <Icon name="folder" className="text-orange-400" />
+                                </div>
+                                <div>
+                                    <p className="text-2xl font-bold text-white">{redundantClones}</p>
+                                    <p className="text-xs text-slate-500">Redundant Clones</p>
+                                </div>
+                            </div>
+                        </div>
+                        <div className="bg-surface border border-border-dim rounded-lg p-4">
+                            <div className="flex items-center gap-3">
+                                <div className="size-10 rounded-lg bg-emerald-500/10 flex items-center justify-center">
+                                    <Icon name="storage" className="text-emerald-400" />
+                                </div>
+                                <div>
+                                    <p className="text-2xl font-bold text-white">{formatBytes(reclaimable)}</p>
+                                    <p className="text-xs text-slate-500">Reclaimable</p>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+
+                    {/* Groups */}
+                    {loading ? (
+                        <div className="flex flex-col items-center justify-center py-16 gap-3">
+                            <Icon name="progress_activity" className="text-[32px] text-primary animate-spin" />
+                            <p className="text-sm text-slate-400">Comparing repositories...</p>
+                        </div>
+                    ) : groups.length === 0 ? (
+                        <div className="flex flex-col items-center justify-center py-16 text-center">
+                            <div className="size-16 rounded-full bg-surface flex items-center justify-center mb-4">
+                                <Icon name="check_circle" className="text-[32px] text-emerald-400" />
+                            </div>
+                            <h3 className="text-lg font-semibold text-white mb-2">No Duplicates</h3>
+                            <p className="text-sm text-slate-500 max-w-sm">
+                                Every repository in your library is cloned only once.
+                            </p>
+                        </div>
+                    ) : (
+                        <div className="space-y-3">
+                            {groups.map(group => (
+                                <div key={group.repository} className="p-4 bg-surface border border-border-dim rounded-lg">
+                                    <div className="flex items-baseline justify-between gap-4 mb-3">
+                                        <p className="text-sm font-medium text-white font-mono truncate">{group.repository}</p>
+                                        <p className="text-xs text-slate-500 shrink-0">{group.clones.length} clones</p>
+                                    </div>
+                                    <div className="space-y-2">
+                                        {group.clones.map(clone => {
+                                            const project = projects.find(p => p.path === clone.path);
+                                            const others = group.clones.filter(c => c.path !== clone.path).map(c => c.path);
+                                            return (
+                                                <div key={clone.path} className="flex items-center gap-3 px-3 py-2 bg-background-dark border border-border-dim rounded">
+                                                    <div className="flex-1 min-w-0">
+                                                        <p className="text-sm text-white truncate">{project?.name || clone.path}</p>
+                                                        <p className="text-xs text-slate-600 font-mono truncate" title={clone.path}>{clone.path}</p>
+                                                    </div>
+                                                    <span className="flex items-center gap-1 text-xs text-slate-400 font-mono shrink-0">
+                                                        <Icon name="call_split" className="text-[14px]" />
+                                                        {clone.branch}
+                                                    </span>
+                                                    {clone.dirty && (
+                                                        <span className="text-[10px] font-mono px-1.5 rounded bg-yellow-500/20 text-yellow-400 shrink-0">modified</span>
+                                                    )}
+                                                    <span className="text-xs text-slate-500 font-mono w-20 text-right shrink-0">{formatBytes(clone.size)}</span>
+                                                    <button
+                                                        onClick={() => onRemoveClone(clone.path, others, reload)}
+                                                        className="flex items-center gap-1 px-2 py-1 text-xs text-red-400 hover:bg-red-500/10 rounded transition-colors shrink-0"
+                                                        title="Delete this clone from disk"
+                                                    >
+                                                        <Icon name="delete_sweep" className="text-[16px]" />
+                                                        Delete
+                                                    </button>
+                                                </div>
+                                            );
+                                        })}
+                                    </div>
+                                </div>
+                            ))}
+                        </div>
+                    )}
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default DuplicatesPage;
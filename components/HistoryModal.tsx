@@ -0,0 +1,235 @@
+import React, { useState, useEffect } from 'react';
+import { BranchInfo, CommitInfo, DiffSummary, FileChange } from '../types';
+import Icon from './Icon';
+
+interface HistoryModalProps {
+    isOpen: boolean;
+    projectName: string;
+    loadLog: (branch?: string) => Promise<CommitInfo[]>;
+    loadChanges: () => Promise<DiffSummary | null>;
+    loadBranches: () => Promise<BranchInfo[]>;
+    onClose: () => void;
+}
+
+type Tab = 'changes' | 'commits';
+
+const getRelativeTime = (value: string): string => {
+    const date = new Date(value);
+    if (isNaN(date.getTime())) return '';
+    const diffMin = Math.floor((Date.now() - date.getTime()) / 60000);
+    const diffHour = Math.floor(diffMin / 60);
+    const diffDay = Math.floor(diffHour / 24);
+
+    if (diffMin < 1) return 'just now';
+    if (diffMin < 60) return `${diffMin}m ago`;
+    if (diffHour < 24) return `${diffHour}h ago`;
+    if (diffDay < 30) return `${diffDay}d ago`;
+    return date.toLocaleDateString();
+};
+
+const STATUS_STYLES: Record<FileChange['status'], { letter: string; color: string }> = {
+    added: { letter: 'A', color: 'text-emerald-400' },
+    modified: { letter: 'M', color: 'text-yellow-400' },
+    deleted: { letter: 'D', color: 'text-red-400' },
+    renamed: { letter: 'R', color: 'text-blue-400' },
+    copied: { letter: 'C', color: 'text-blue-400' },
+    typechange: { letter: 'T', color: 'text-purple-400' },
+    untracked: { letter: 'U', color: 'text-slate-400' },
+    conflicted: { letter: '!', color: 'text-red-500' },
+};
+
+const LineCounts: React.FC<{ insertions: number; deletions: number }> = ({ insertions, deletions }) => (
+    <span className="text-[11px] font-mono whitespace-nowrap">
+        <span className="text-emerald-400">+{insertions}</span>{' '}
+        <span className="text-red-400">-{deletions}</span>
+    </span>
+);
+
+const HistoryModal: React.FC<HistoryModalProps> = ({
+    isOpen,
+    projectName,
+    loadLog,
+    loadChanges,
+    loadBranches,
+    onClose,
+}) => {
+    const [tab, setTab] = useState<Tab>('changes');
+    const [changes, setChanges] = useState<DiffSummary | null>(null);
+    const [commits, setCommits] = useState<CommitInfo[]>([]);
+    const [branches, setBranches] = useState<BranchInfo[]>([]);
+    const [branch, setBranch] = useState('');
+    const [loading, setLoading] = useState(false);
+
+    useEffect(() => {
+        if (!isOpen) {
+            setTab('changes');
+            setChanges(null);
+            setCommits([]);
+            setBranches([]);
+            setBranch('');
+            return;
+        }
+        setLoading(true);
+        Promise.all([loadChanges(), loadLog(), loadBranches()]).then(([changes, commits, branches]) => {
+            setChanges(changes);
+            setCommits(commits);
+            setBranches(branches.filter(b => !b.isRemote));
+            setLoading(false);
+        });
+    }, [isOpen]);
+
+    useEffect(() => {
+        const handleEsc = (e: KeyboardEvent) => {
+            if (e.key === 'Escape') onClose();
+        };
+        if (isOpen) {
+            document.addEventListener('keydown', handleEsc);
+            return () => document.removeEventListener('keydown', handleEsc);
+        }
+    }, [isOpen, onClose]);
+
+    if (!isOpen) return null;
+
+    const handleBranchChange = async (value: string) => {
+        setBranch(value);
+        setLoading(true);
+        setCommits(await loadLog(value || undefined));
+        setLoading(false);
+    };
+
+    const renderChanges = () => !changes || changes.files.length === 0 ? (
+        <div className="flex flex-col items-center justify-center py-12 gap-3">
+            <Icon name="check_circle" className="text-[48px] text-slate-600" />
+            <p className="text-sm text-slate-400">Working tree clean</p>
+        </div>
+    ) : (
+        <div className="p-4 space-y-1">
+            {changes.files.map(file => {
+                const style = STATUS_STYLES[file.status] || STATUS_STYLES.modified;
+                return (
+                    <div key={file.path} className="flex items-center gap-3 px-3 py-1.5 rounded-lg hover:bg-surface transition-colors">
+                        <span className={`w-4 text-center text-xs font-bold font-mono ${style.color}`} title={file.status}>
+                            {style.letter}
+                        </span>
+                        <span className="flex-1 min-w-0 text-sm text-white font-mono truncate" title={file.path}>
+                            {file.oldPath && <span className="text-slate-500">{file.oldPath} → </span>}
+                            {file.path}
+                        </span>
+                        {file.staged && (
+                            <span className="text-[10px] px-1.5 py-0.5 rounded bg-emerald-500/10 text-emerald-400">staged</span>
+                        )}
+                        <LineCounts insertions={file.insertions} deletions={file.deletions} />
+                    </div>
+                );
+            })}
+        </div>
+    );
+
+    const renderCommits = () => commits.length === 0 ? (
+        <div className="flex flex-col items-center justify-center py-12 gap-3">
+            <Icon name="commit" className="text-[48px] text-slate-600" />
+            <p className="text-sm text-slate-400">No commits yet</p>
+        </div>
+    ) : (
+        <div className="p-4 space-y-1">
+            {commits.map(commit => (
+                <div key={commit.id} className="flex items-center gap-3 px-3 py-2 rounded-lg hover:bg-surface transition-colors">
+                    <div className="flex-1 min-w-0">
+                        <p className="text-sm text-white truncate" title={commit.summary}>{commit.summary}</p>
+                        <p className="text-xs text-slate-500 mt-0.5 truncate">
+                            <span className="font-mono">{commit.shortId}</span>
+                            {` · ${commit.author} · ${getRelativeTime(commit.date)}`}
+                        </p>
+                    </div>
+                    {commit.filesChanged > 0 && (
+                        <div className="flex flex-col items-end">
+                            <LineCounts insertions={commit.insertions} deletions={commit.deletions} />
+                            <span className="text-[10px] text-slate-600">
+                                {commit.filesChanged} file{commit.filesChanged !== 1 ? 's' : ''}
+                            </span>
+                        </div>
+                    )}
+                </div>
+            ))}
+        </div>
+    );
+
+    const tabClass = (value: Tab) =>
+        `px-3 py-1.5 rounded-lg text-xs font-medium transition-colors ${tab === value ? 'bg-primary/10 text-primary' : 'text-slate-400 hover:text-white hover:bg-white/5'}`;
+
+    return (
+        <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/60 backdrop-blur-sm animate-fade-in">
+            <div className="modal-dialog w-full max-w-2xl bg-background border border-border-dim rounded-xl shadow-2xl overflow-hidden">
+                {/* Header */}
+                <div className="flex items-center justify-between px-6 py-4 border-b border-border-dim bg-surface/50">
+                    <div className="flex items-center gap-3">
+                        <div className="size-10 rounded-lg bg-primary/10 border border-primary/20 flex items-center justify-center">
+                            <Icon name="history" className="text-[24px] text-primary" />
+                        </div>
+                        <div>
+                            <h2 className="text-lg font-bold text-white">History</h2>
+                            <p className="text-xs text-slate-500 font-mono">{projectName}</p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={onClose}
+                        className="size-8 rounded-lg hover:bg-white/5 flex items-center justify-center text-slate-400 hover:text-white transition-colors"
+                    >
+                        <Icon name="close" className="text-[20px]" />
+                    </button>
+                </div>
+
+                {/* Tabs */}
+                <div className="px-6 py-3 border-b border-border-dim flex items-center justify-between">
+                    <div className="flex items-center gap-1">
+                        <button onClick={() => setTab('changes')} className={tabClass('changes')}>
+                            Changes{changes && changes.files.length > 0 ? ` (${changes.files.length})` : ''}
+                        </button>
+                        <button onClick={() => setTab('commits')} className={tabClass('commits')}>
+                            Commits
+                        </button>
+                    </div>
+                    {tab === 'changes' && changes && changes.files.length > 0 && (
+                        <LineCounts insertions={changes.insertions} deletions={changes.deletions} />
+                    )}
+                    {tab === 'commits' && branches.length > 1 && (
+                        <select
+                            value={branch}
+                            onChange={(e) => handleBranchChange(e.target.value)}
+                            className="px-2 py-1 bg-surface border border-border-dim rounded-lg text-xs text-white font-mono focus:outline-none focus:border-primary/50"
+                        >
+                            <option value="">HEAD</option>
+                            {branches.map(b => (
+                                <option key={b.name} value={b.name}>{b.name}</option>
+                            ))}
+                        </select>
+                    )}
+                </div>
+
+                {/* Content */}
+                <div className="max-h-[400px] overflow-y-auto custom-scrollbar">
+                    {loading ? (
+                        <div className="flex flex-col items-center justify-center py-12 gap-3">
+                            <Icon name="progress_activity" className="text-[32px] text-primary animate-spin" />
+                            <p className="text-sm text-slate-400">Reading history...</p>
+                        </div>
+                    ) : tab === 'changes' ? renderChanges() : renderCommits()}
+                </div>
+
+                {/* Footer */}
+                <div className="px-6 py-3 border-t border-border-dim bg-surface/30 flex items-center justify-between">
+                    <p className="text-xs text-slate-500">
+                        Line counts are against the last commit
+                    </p>
+                    <div className="flex items-center gap-2">
+                        <span className="text-xs text-slate-600">Press</span>
+                        <kbd className="px-1.5 py-0.5 bg-surface border border-border-dim rounded text-[10px] font-mono text-slate-400">ESC</kbd>
+                        <span className="text-xs text-slate-600">to close</span>
+                    </div>
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default HistoryModal;
@@ -11,7 +11,8 @@ import {
     Clock, Palette, SlidersHorizontal, User, Boxes, SearchX, FileCode,
     ArchiveRestore, CheckSquare, Smartphone, Server, Zap, Globe,
     Coffee, GitBranch as Git, FileJson, Braces, Pin, PinOff, Hexagon,
-    GitCommitHorizontal
+    GitCommitHorizontal, LayoutGrid, CloudUpload, GitFork, Layers, GitPullRequest, Copy,
+    Users, Rocket
 } from 'lucide-react';
 
 // Custom SVG Icons for programming languages/tools
@@ -75,6 +76,8 @@ const iconMap: Record<string, React.FC<{ className?: string; size?: number }>> =
     'pin': Pin,
     'pin_off': PinOff,
     'star_outline': Star,
+    'groups': Users,
+    'rocket_launch': Rocket,
     'play_arrow': Play,
     'download': Download,
     'cloud_download': CloudDownload,
@@ -102,6 +105,7 @@ const iconMap: Record<string, React.FC<{ className?: string; size?: number }>> =
     // Views
     'list': List,
     'grid_view': Grid,
+    'apps': LayoutGrid,
 
     // Files & Folders
     'folder': Folder,
@@ -118,6 +122,11 @@ const iconMap: Record<string, React.FC<{ className?: string; size?: number }>> =
 
     // Git
     'call_split': GitBranch,
+    'cloud_upload': CloudUpload,
+    'fork_right': GitFork,
+    'layers': Layers,
+    'merge': GitPullRequest,
+    'content_copy': Copy,
 
     // Time
     'today': Calendar,
@@ -3,16 +3,19 @@ import { invoke } from '@tauri-apps/api/core';
 import { open } from '@tauri-apps/plugin-dialog';
 import { platform } from '@tauri-apps/plugin-os';
 import { FRAMEWORK_TEMPLATES, FrameworkTemplate } from '../constants';
+import { CloneOptions, CloneProgress } from '../types';
 import Icon from './Icon';
 
 interface NewProjectModalProps {
     isOpen: boolean;
     onClose: () => void;
     onImportProject: () => void;
+    onCloneProject: (url: string, parentDir: string, options: CloneOptions, onProgress: (progress: CloneProgress) => void) => Promise<boolean>;
+    onTeamSetup: () => void;
     onProjectCreated: (path: string) => void;
 }
 
-type Step = 'choose' | 'select-framework' | 'configure';
+type Step = 'choose' | 'select-framework' | 'configure' | 'clone';
 type Category = 'all' | 'web' | 'mobile' | 'backend' | 'desktop';
 
 const CATEGORIES: { value: Category; label: string; icon: string }[] = [
@@ -27,6 +30,8 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
     isOpen,
     onClose,
     onImportProject,
+    onCloneProject,
+    onTeamSetup,
     onProjectCreated,
 }) => {
     const [step, setStep] = useState<Step>('choose');
@@ -38,6 +43,11 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
     const [toolInstalled, setToolInstalled] = useState<boolean | null>(null);
     const [error, setError] = useState<string | null>(null);
     const [currentPlatform, setCurrentPlatform] = useState<string>('');
+    const [cloneUrl, setCloneUrl] = useState('');
+    const [cloneBranch, setCloneBranch] = useState('');
+    const [cloneShallow, setCloneShallow] = useState(false);
+    const [cloneRecursive, setCloneRecursive] = useState(true);
+    const [cloneProgress, setCloneProgress] = useState<CloneProgress | null>(null);
 
     // Get platform on mount
     useEffect(() => {
@@ -72,6 +82,11 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
             setParentPath('');
             setError(null);
             setToolInstalled(null);
+            setCloneUrl('');
+            setCloneBranch('');
+            setCloneShallow(false);
+            setCloneRecursive(true);
+            setCloneProgress(null);
         }
     }, [isOpen]);
 
@@ -157,8 +172,23 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
         }
     };
 
+    const handleClone = async () => {
+        if (!cloneUrl.trim() || !parentPath) return;
+        setIsCreating(true);
+        setCloneProgress(null);
+        const cloned = await onCloneProject(cloneUrl.trim(), parentPath, {
+            branch: cloneBranch.trim() || undefined,
+            depth: cloneShallow ? 1 : undefined,
+            recursive: cloneRecursive,
+        }, setCloneProgress);
+        setIsCreating(false);
+        if (cloned) onClose();
+    };
+
     const handleBack = () => {
-        if (step === 'configure') {
+        if (step === 'clone') {
+            setStep('choose');
+        } else if (step === 'configure') {
             setStep('select-framework');
             setSelectedTemplate(null);
         } else if (step === 'select-framework') {
@@ -177,6 +207,7 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
                         {step !== 'choose' && (
                             <button
                                 onClick={handleBack}
+                                disabled={isCreating}
                                 className="size-8 flex items-center justify-center rounded hover:bg-white/10 text-slate-400 hover:text-white transition-colors"
                             >
                                 <Icon name="arrow_back" className="text-[20px]" />
@@ -187,11 +218,13 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
                                 {step === 'choose' && 'Add New Project'}
                                 {step === 'select-framework' && 'Select Framework'}
                                 {step === 'configure' && `Create ${selectedTemplate?.name} Project`}
+                                {step === 'clone' && 'Clone Repository'}
                             </h2>
                             <p className="text-xs text-slate-500">
-                                {step === 'choose' && 'Import an existing project or create a new one'}
+                                {step === 'choose' && 'Import, create or clone a project, or set up a whole team workspace'}
                                 {step === 'select-framework' && 'Choose a framework to get started'}
                                 {step === 'configure' && 'Configure your new project'}
+                                {step === 'clone' && 'Clone a git repository and add it to your library'}
                             </p>
                         </div>
                     </div>
@@ -236,6 +269,133 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
                                     <p className="text-xs text-slate-500">Start a new project from a template</p>
                                 </div>
                             </button>
+
+                            <button
+                                onClick={() => setStep('clone')}
+                                className="flex flex-col items-center gap-4 p-8 rounded-xl border-2 border-border-dim hover:border-purple-500/50 bg-surface-highlight/50 hover:bg-surface-highlight transition-all group"
+                            >
+                                <div className="size-16 rounded-full bg-purple-500/10 flex items-center justify-center group-hover:bg-purple-500/20 transition-colors">
+                                    <Icon name="cloud_download" className="text-[32px] text-purple-400" />
+                                </div>
+                                <div className="text-center">
+                                    <h3 className="text-white font-semibold mb-1">Clone Repository</h3>
+                                    <p className="text-xs text-slate-500">Clone a git repository from a URL</p>
+                                </div>
+                            </button>
+
+                            <button
+                                onClick={() => {
+                                    onTeamSetup();
+                                    onClose();
+                                }}
+                                className="flex flex-col items-center gap-4 p-8 rounded-xl border-2 border-border-dim hover:border-orange-500/50 bg-surface-highlight/50 hover:bg-surface-highlight transition-all group"
+                            >
+                                <div className="size-16 rounded-full bg-orange-500/10 flex items-center justify-center group-hover:bg-orange-500/20 transition-colors">
+                                    <Icon name="groups" className="text-[32px] text-orange-400" />
+                                </div>
+                                <div className="text-center">
+                                    <h3 className="text-white font-semibold mb-1">Team Workspace</h3>
+                                    <p className="text-xs text-slate-500">Set up every repository of a team manifest</p>
+                                </div>
+                            </button>
+                        </div>
+                    )}
+
+                    {/* Clone a Repository */}
+                    {step === 'clone' && (
+                        <div className="space-y-5">
+                            <div>
+                                <label className="block text-sm font-medium text-slate-300 mb-2">
+                                    Repository URL
+                                </label>
+                                <input
+                                    type="text"
+                                    value={cloneUrl}
+                                    onChange={(e) => setCloneUrl(e.target.value)}
+                                    disabled={isCreating}
+                                    placeholder="git@github.com:owner/repo.git"
+                                    autoFocus
+                                    className="w-full px-4 py-2.5 bg-surface border border-border-dim rounded-lg text-white placeholder-slate-500 focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50 font-mono text-sm"
+                                />
+                            </div>
+
+                            <div>
+                                <label className="block text-sm font-medium text-slate-300 mb-2">
+                                    Parent Directory
+                                </label>
+                                <div className="flex gap-2">
+                                    <input
+                                        type="text"
+                                        value={parentPath}
+                                        readOnly
+                                        placeholder="Select a folder..."
+                                        className="flex-1 px-4 py-2.5 bg-surface border border-border-dim rounded-lg text-white placeholder-slate-500 font-mono text-sm cursor-pointer"
+                                        onClick={handleSelectFolder}
+                                    />
+                                    <button
+                                        onClick={handleSelectFolder}
+                                        disabled={isCreating}
+                                        className="px-4 py-2.5 bg-surface border border-border-dim rounded-lg text-slate-300 hover:text-white hover:border-slate-500 transition-colors"
+                                    >
+                                        <Icon name="folder_open" className="text-[20px]" />
+                                    </button>
+                                </div>
+                            </div>
+
+                            <div>
+                                <label className="block text-sm font-medium text-slate-300 mb-2">
+                                    Branch <span className="text-slate-500 font-normal">(optional)</span>
+                                </label>
+                                <input
+                                    type="text"
+                                    value={cloneBranch}
+                                    onChange={(e) => setCloneBranch(e.target.value)}
+                                    disabled={isCreating}
+                                    placeholder="Default branch"
+                                    className="w-full px-4 py-2.5 bg-surface border border-border-dim rounded-lg text-white placeholder-slate-500 focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50 font-mono text-sm"
+                                />
+                            </div>
+
+                            <div className="space-y-2">
+                                <label className="flex items-center gap-2 text-sm text-slate-300 cursor-pointer">
+                                    <input
+                                        type="checkbox"
+                                        checked={cloneShallow}
+                                        onChange={(e) => setCloneShallow(e.target.checked)}
+                                        disabled={isCreating}
+                                        className="accent-primary"
+                                    />
+                                    Shallow clone <span className="text-slate-500">(latest commit only)</span>
+                                </label>
+                                <label className="flex items-center gap-2 text-sm text-slate-300 cursor-pointer">
+                                    <input
+                                        type="checkbox"
+                                        checked={cloneRecursive}
+                                        onChange={(e) => setCloneRecursive(e.target.checked)}
+                                        disabled={isCreating}
+                                        className="accent-primary"
+                                    />
+                                    Include submodules
+                                </label>
+                            </div>
+
+                            {/* Progress */}
+                            {isCreating && (
+                                <div className="space-y-2">
+                                    <div className="flex items-center justify-between text-xs text-slate-400 font-mono">
+                                        <span className="truncate">{cloneProgress?.phase || 'Connecting...'}</span>
+                                        {cloneProgress?.percent != null && (
+                                            <span>{cloneProgress.percent}%</span>
+                                        )}
+                                    </div>
+                                    <div className="h-1.5 bg-surface rounded-full overflow-hidden">
+                                        <div
+                                            className="h-full bg-primary transition-all duration-300"
+                                            style={{ width: `${cloneProgress?.percent ?? 0}%` }}
+                                        />
+                                    </div>
+                                </div>
+                            )}
                         </div>
                     )}
 
@@ -454,6 +614,34 @@ const NewProjectModal: React.FC<NewProjectModalProps> = ({
                 </div>
 
                 {/* Footer */}
+                {step === 'clone' && (
+                    <div className="flex items-center justify-end gap-3 px-6 py-4 border-t border-border-dim">
+                        <button
+                            onClick={onClose}
+                            disabled={isCreating}
+                            className="px-4 py-2 text-sm font-medium text-slate-400 hover:text-white transition-colors"
+                        >
+                            Cancel
+                        </button>
+                        <button
+                            onClick={handleClone}
+                            disabled={isCreating || !cloneUrl.trim() || !parentPath}
+                            className="px-4 py-2 bg-primary hover:bg-primary/90 text-white text-sm font-medium rounded-lg transition-colors disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                        >
+                            {isCreating ? (
+                                <>
+                                    <Icon name="progress_activity" className="text-[18px] animate-spin" />
+                                    Cloning...
+                                </>
+                            ) : (
+                                <>
+                                    <Icon name="cloud_download" className="text-[18px]" />
+                                    Clone
+                                </>
+                            )}
+                        </button>
+                    </div>
+                )}
                 {step === 'configure' && selectedTemplate && (
                     <div className="flex items-center justify-end gap-3 px-6 py-4 border-t border-border-dim">
                         <button
@@ -1,7 +1,15 @@
 import React, { useState, useRef, useEffect } from 'react';
-import { Project, ActionState } from '../types';
+import { invoke } from '@tauri-apps/api/core';
+import { Project, ActionState, RemoteInfo } from '../types';
 import Icon from './Icon';
 
+const providerNames: Record<string, string> = {
+    github: 'GitHub',
+    gitlab: 'GitLab',
+    bitbucket: 'Bitbucket',
+    gitea: 'Gitea',
+};
+
 interface ProjectActionsProps {
     project: Project;
     actionState?: ActionState;
@@ -17,10 +25,18 @@ interface ProjectActionsProps {
     onRunScripts?: () => void;
     onGitPull?: () => void;
     onGitFetch?: () => void;
+    onBranches?: () => void;
+    onStashes?: () => void;
+    onWorktrees?: () => void;
+    onUpdateSubmodules?: () => void;
+    onLfsPull?: () => void;
+    onOpenUrl?: (url: string) => void;
+    onHistory?: () => void;
     onCheckHealth?: () => void;
     onEditNotes?: () => void;
     onManageTags?: () => void;
     onTogglePin?: () => void;
+    onToggleLauncher?: () => void;
 }
 
 const ProjectActions: React.FC<ProjectActionsProps> = ({
@@ -38,10 +54,18 @@ const ProjectActions: React.FC<ProjectActionsProps> = ({
     onRunScripts,
     onGitPull,
     onGitFetch,
+    onBranches,
+    onStashes,
+    onWorktrees,
+    onUpdateSubmodules,
+    onLfsPull,
+    onOpenUrl,
+    onHistory,
     onCheckHealth,
     onEditNotes,
     onManageTags,
     onTogglePin,
+    onToggleLauncher,
 }) => {
     const [isOpen, setIsOpen] = useState(false);
     const dropdownRef = useRef<HTMLDivElement>(null);
@@ -56,7 +80,20 @@ const ProjectActions: React.FC<ProjectActionsProps> = ({
         return () => document.removeEventListener('mousedown', handleClickOutside);
     }, []);
 
+    // Branch and pull request links follow the checked-out branch, so ask again on every open
+    const [remotes, setRemotes] = useState<RemoteInfo[] | null>(null);
+    useEffect(() => {
+        setRemotes(null);
+        if (!isOpen || !onOpenUrl || !project.remotes?.length) return;
+        let cancelled = false;
+        invoke<RemoteInfo[]>('remote_links', { path: project.path })
+            .then(links => { if (!cancelled) setRemotes(links); })
+            .catch(e => console.error('Failed to read remotes:', e));
+        return () => { cancelled = true; };
+    }, [isOpen, onOpenUrl, project.path, project.remotes]);
+
     const isLoading = !!actionState;
+    const remote = (remotes ?? project.remotes)?.[0];
 
     return (
         <div className="relative" ref={dropdownRef}>
@@ -204,8 +241,43 @@ const ProjectActions: React.FC<ProjectActionsProps> = ({
                         </>
                     )}
 
+                    {/* Web pages of the first remote, origin when there is one */}
+                    {onOpenUrl && remote && (
+                        <>
+                            <div className="px-1 py-1">
+                                <button
+                                    onClick={() => { onOpenUrl(remote.webUrl); setIsOpen(false); }}
+                                    className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                >
+                                    <Icon name="language" className="text-[18px] text-primary" />
+                                    Open on {providerNames[remote.provider] ?? remote.host}
+                                </button>
+                                {remote.branchUrl && (
+                                    <button
+                                        onClick={() => { onOpenUrl(remote.branchUrl!); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="call_split" className="text-[18px] text-primary" />
+                                        Open Branch
+                                        <span className="ml-auto text-[10px] font-mono text-slate-500 truncate max-w-[120px]">{project.gitStatus.branch}</span>
+                                    </button>
+                                )}
+                                {remote.compareUrl && (
+                                    <button
+                                        onClick={() => { onOpenUrl(remote.compareUrl!); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="merge" className="text-[18px] text-primary" />
+                                        {remote.provider === 'gitlab' ? 'Create Merge Request' : 'Create Pull Request'}
+                                    </button>
+                                )}
+                            </div>
+                            <div className="h-px bg-border-dim my-1 mx-2"></div>
+                        </>
+                    )}
+
                     {/* Git Actions */}
-                    {project.hasGit && (onGitPull || onGitFetch) && (
+                    {project.hasGit && (onGitPull || onGitFetch || onBranches || onStashes || onWorktrees || onHistory) && (
                         <>
                             <div className="px-1 py-1">
                                 {onGitPull && (
@@ -226,6 +298,62 @@ const ProjectActions: React.FC<ProjectActionsProps> = ({
                                         Git Fetch
                                     </button>
                                 )}
+                                {onHistory && (
+                                    <button
+                                        onClick={() => { onHistory(); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="history" className="text-[18px] text-primary" />
+                                        Changes & History
+                                    </button>
+                                )}
+                                {onBranches && (
+                                    <button
+                                        onClick={() => { onBranches(); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="call_split" className="text-[18px] text-primary" />
+                                        Branches
+                                    </button>
+                                )}
+                                {onStashes && (
+                                    <button
+                                        onClick={() => { onStashes(); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="inventory" className="text-[18px] text-primary" />
+                                        Stashes
+                                    </button>
+                                )}
+                                {onWorktrees && (
+                                    <button
+                                        onClick={() => { onWorktrees(); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="fork_right" className="text-[18px] text-primary" />
+                                        Worktrees
+                                    </button>
+                                )}
+                                {onUpdateSubmodules && project.gitStatus.submodules && (
+                                    <button
+                                        onClick={() => { onUpdateSubmodules(); setIsOpen(false); }}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                                    >
+                                        <Icon name="layers" className="text-[18px] text-primary" />
+                                        Update Submodules
+                                    </button>
+                                )}
+                                {onLfsPull && project.gitStatus.lfs && (
+                                    <button
+                                        onClick={() => { onLfsPull(); setIsOpen(false); }}
+                                        disabled={!project.gitStatus.lfs.installed}
+                                        title={project.gitStatus.lfs.installed ? undefined : 'Git LFS is not installed'}
+                                        className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors disabled:opacity-50"
+                                    >
+                                        <Icon name="cloud_download" className="text-[18px] text-primary" />
+                                        LFS Pull
+                                    </button>
+                                )}
                             </div>
                             <div className="h-px bg-border-dim my-1 mx-2"></div>
                         </>
@@ -273,6 +401,15 @@ const ProjectActions: React.FC<ProjectActionsProps> = ({
                             <Icon name="folder_open" className="text-[18px] text-slate-400" />
                             Reveal in Explorer
                         </button>
+                        {onToggleLauncher && !project.isArchived && (
+                            <button
+                                onClick={() => { onToggleLauncher(); setIsOpen(false); }}
+                                className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
+                            >
+                                <Icon name="apps" className="text-[18px] text-slate-400" />
+                                {project.hasLauncher ? 'Remove from App Launcher' : 'Add to App Launcher'}
+                            </button>
+                        )}
                         <button
                             onClick={() => { onRefresh(); setIsOpen(false); }}
                             className="w-full flex items-center gap-3 px-3 py-2 text-sm text-slate-300 hover:bg-white/5 hover:text-white rounded transition-colors"
@@ -1,8 +1,40 @@
 import React from 'react';
-import { Project, TechStack, ActionState } from '../types';
+import { Project, TechStack, ActionState, GitStatus } from '../types';
 import ProjectActions from './ProjectActions';
 import Icon from './Icon';
 
+// "↓2 ↑1" against the upstream, or null when in sync or there is no upstream
+const syncLabel = (git: GitStatus): string | null => {
+    const parts = [];
+    if (git.behind) parts.push(`↓${git.behind}`);
+    if (git.ahead) parts.push(`↑${git.ahead}`);
+    return parts.length > 0 ? parts.join(' ') : null;
+};
+
+// Submodules and LFS files the checkout is missing, or null when there are none
+const syncIssues = (git: GitStatus): string | null => {
+    const issues = [];
+    if (git.submodules?.uninitialized) issues.push(`${git.submodules.uninitialized} submodule(s) not initialized`);
+    if (git.submodules?.outOfDate) issues.push(`${git.submodules.outOfDate} submodule(s) out of date`);
+    if (git.submodules?.modified) issues.push(`${git.submodules.modified} submodule(s) with changes`);
+    if (git.lfs?.missing) issues.push(`${git.lfs.missing} LFS file(s) not downloaded`);
+    return issues.length > 0 ? issues.join(', ') : null;
+};
+
+// Linked worktrees follow their repository when it's in the list too
+const groupWorktrees = (projects: Project[]): { project: Project; nested: boolean }[] => {
+    const paths = new Set(projects.map(p => p.path));
+    const isNested = (p: Project) => !!p.gitStatus.worktreeOf && paths.has(p.gitStatus.worktreeOf);
+    return projects
+        .filter(p => !isNested(p))
+        .flatMap(p => [
+            { project: p, nested: false },
+            ...projects
+                .filter(w => isNested(w) && w.gitStatus.worktreeOf === p.path)
+                .map(w => ({ project: w, nested: true })),
+        ]);
+};
+
 interface ProjectListProps {
     projects: Project[];
     viewMode: 'list' | 'grid';
@@ -20,10 +52,18 @@ interface ProjectListProps {
     onRunScripts?: (projectId: string) => void;
     onGitPull?: (projectId: string) => void;
     onGitFetch?: (projectId: string) => void;
+    onBranches?: (projectId: string) => void;
+    onStashes?: (projectId: string) => void;
+    onWorktrees?: (projectId: string) => void;
+    onUpdateSubmodules?: (projectId: string) => void;
+    onLfsPull?: (projectId: string) => void;
+    onOpenUrl?: (url: string) => void;
+    onHistory?: (projectId: string) => void;
     onCheckHealth?: (projectId: string) => void;
     onEditNotes?: (projectId: string) => void;
     onManageTags?: (projectId: string) => void;
     onTogglePin?: (projectId: string) => void;
+    onToggleLauncher?: (projectId: string) => void;
     // Selection
     selectedIds?: Set<string>;
     onToggleSelect?: (projectId: string) => void;
@@ -57,6 +97,8 @@ const TagBadge: React.FC<{ tag: string }> = ({ tag }) => (
 
 interface ProjectRowProps {
     project: Project;
+    // Shown indented under its repository
+    nested?: boolean;
     actionState?: ActionState;
     isSelected?: boolean;
     onToggleSelect?: () => void;
@@ -72,14 +114,23 @@ interface ProjectRowProps {
     onRunScripts?: () => void;
     onGitPull?: () => void;
     onGitFetch?: () => void;
+    onBranches?: () => void;
+    onStashes?: () => void;
+    onWorktrees?: () => void;
+    onUpdateSubmodules?: () => void;
+    onLfsPull?: () => void;
+    onOpenUrl?: (url: string) => void;
+    onHistory?: () => void;
     onCheckHealth?: () => void;
     onEditNotes?: () => void;
     onManageTags?: () => void;
     onTogglePin?: () => void;
+    onToggleLauncher?: () => void;
 }
 
 const ProjectRow: React.FC<ProjectRowProps> = ({
     project,
+    nested,
     actionState,
     isSelected,
     onToggleSelect,
@@ -95,16 +146,24 @@ const ProjectRow: React.FC<ProjectRowProps> = ({
     onRunScripts,
     onGitPull,
     onGitFetch,
+    onBranches,
+    onStashes,
+    onWorktrees,
+    onUpdateSubmodules,
+    onLfsPull,
+    onOpenUrl,
+    onHistory,
     onCheckHealth,
     onEditNotes,
     onManageTags,
     onTogglePin,
+    onToggleLauncher,
 }) => {
     const isArchived = project.isArchived;
     const isLoading = !!actionState;
 
     return (
-        <div className={`group relative grid grid-cols-12 gap-2 lg:gap-4 px-6 py-3 border-b border-l border-r border-border-dim transition-colors items-center project-row
+        <div className={`group relative grid grid-cols-12 gap-2 lg:gap-4 ${nested ? 'pl-12 pr-6' : 'px-6'} py-3 border-b border-l border-r border-border-dim transition-colors items-center project-row
             ${isArchived ? 'bg-surface hover:bg-surface-highlight opacity-60' : 'bg-surface hover:bg-surface-highlight'}
             ${isSelected ? 'bg-primary/5 border-l-2 border-l-primary' : ''}
             ${isLoading ? 'pointer-events-none' : ''}`}
@@ -141,6 +200,9 @@ const ProjectRow: React.FC<ProjectRowProps> = ({
                         <h3 className="text-sm font-semibold leading-tight truncate text-slate-200 group-hover:text-white">
                             {project.name}
                         </h3>
+                        {project.gitStatus.worktreeOf && (
+                            <Icon name="fork_right" className="text-[12px] text-slate-500 shrink-0" title={`Worktree of ${project.gitStatus.worktreeOf}`} />
+                        )}
                         {project.notes && (
                             <Icon name="note" className="text-[10px] text-primary shrink-0" title="Has notes" />
                         )}
@@ -166,10 +228,21 @@ const ProjectRow: React.FC<ProjectRowProps> = ({
                     ${project.gitStatus.type === 'warning' ? 'text-yellow-400' : ''}
                     ${project.gitStatus.type === 'neutral' ? 'text-slate-600' : ''}
                     ${project.gitStatus.type === 'info' ? 'text-blue-400' : ''}
+                    ${project.gitStatus.type === 'error' ? 'text-red-400' : ''}
                 `} />
-                <span className="text-xs font-mono text-slate-300 truncate" title={project.gitStatus.branch}>
+                <span className="text-xs font-mono text-slate-300 truncate" title={project.gitStatus.upstream ? `${project.gitStatus.branch} → ${project.gitStatus.upstream}` : project.gitStatus.branch}>
                     {project.gitStatus.branch}
                 </span>
+                {syncLabel(project.gitStatus) && (
+                    <span className="text-[10px] font-mono text-slate-400 shrink-0" title="Commits behind / ahead of upstream">
+                        {syncLabel(project.gitStatus)}
+                    </span>
+                )}
+                {syncIssues(project.gitStatus) && (
+                    <span className="flex shrink-0" title={syncIssues(project.gitStatus)!}>
+                        <Icon name="layers" className="text-[12px] text-yellow-400" />
+                    </span>
+                )}
             </div>
 
             {/* Storage - Hidden on smaller screens */}
@@ -208,10 +281,18 @@ const ProjectRow: React.FC<ProjectRowProps> = ({
                     onRunScripts={onRunScripts}
                     onGitPull={onGitPull}
                     onGitFetch={onGitFetch}
+                    onBranches={onBranches}
+                    onStashes={onStashes}
+                    onWorktrees={onWorktrees}
+                    onUpdateSubmodules={onUpdateSubmodules}
+                    onLfsPull={onLfsPull}
+                    onOpenUrl={onOpenUrl}
+                    onHistory={onHistory}
                     onCheckHealth={onCheckHealth}
                     onEditNotes={onEditNotes}
                     onManageTags={onManageTags}
                     onTogglePin={onTogglePin}
+                    onToggleLauncher={onToggleLauncher}
                 />
             </div>
         </div>
@@ -235,10 +316,18 @@ interface ProjectGridCardProps {
     onRunScripts?: () => void;
     onGitPull?: () => void;
     onGitFetch?: () => void;
+    onBranches?: () => void;
+    onStashes?: () => void;
+    onWorktrees?: () => void;
+    onUpdateSubmodules?: () => void;
+    onLfsPull?: () => void;
+    onOpenUrl?: (url: string) => void;
+    onHistory?: () => void;
     onCheckHealth?: () => void;
     onEditNotes?: () => void;
     onManageTags?: () => void;
     onTogglePin?: () => void;
+    onToggleLauncher?: () => void;
 }
 
 const ProjectGridCard: React.FC<ProjectGridCardProps> = ({
@@ -258,10 +347,18 @@ const ProjectGridCard: React.FC<ProjectGridCardProps> = ({
     onRunScripts,
     onGitPull,
     onGitFetch,
+    onBranches,
+    onStashes,
+    onWorktrees,
+    onUpdateSubmodules,
+    onLfsPull,
+    onOpenUrl,
+    onHistory,
     onCheckHealth,
     onEditNotes,
     onManageTags,
     onTogglePin,
+    onToggleLauncher,
 }) => {
     const isArchived = project.isArchived;
     const isLoading = !!actionState;
@@ -304,6 +401,9 @@ const ProjectGridCard: React.FC<ProjectGridCardProps> = ({
                             {project.isPinned && (
                                 <Icon name="pin" className="text-[12px] text-amber-400 shrink-0" title="Pinned" />
                             )}
+                            {project.gitStatus.worktreeOf && (
+                                <Icon name="fork_right" className="text-[12px] text-slate-500 shrink-0" title={`Worktree of ${project.gitStatus.worktreeOf}`} />
+                            )}
                             {project.notes && (
                                 <Icon name="note" className="text-[12px] text-primary shrink-0" title="Has notes" />
                             )}
@@ -329,10 +429,18 @@ const ProjectGridCard: React.FC<ProjectGridCardProps> = ({
                     onRunScripts={onRunScripts}
                     onGitPull={onGitPull}
                     onGitFetch={onGitFetch}
+                    onBranches={onBranches}
+                    onStashes={onStashes}
+                    onWorktrees={onWorktrees}
+                    onUpdateSubmodules={onUpdateSubmodules}
+                    onLfsPull={onLfsPull}
+                    onOpenUrl={onOpenUrl}
+                    onHistory={onHistory}
                     onCheckHealth={onCheckHealth}
                     onEditNotes={onEditNotes}
                     onManageTags={onManageTags}
                     onTogglePin={onTogglePin}
+                    onToggleLauncher={onToggleLauncher}
                 />
             </div>
 
@@ -355,15 +463,24 @@ const ProjectGridCard: React.FC<ProjectGridCardProps> = ({
                     ${project.gitStatus.type === 'success' ? 'bg-emerald-500/10 border-emerald-500/20 text-emerald-500' : ''}
                     ${project.gitStatus.type === 'neutral' ? 'bg-slate-500/10 border-slate-500/20 text-slate-400' : ''}
                     ${project.gitStatus.type === 'info' ? 'bg-blue-500/10 border-blue-500/20 text-blue-400' : ''}
-                `}>
+                    ${project.gitStatus.type === 'error' ? 'bg-red-500/10 border-red-500/20 text-red-400' : ''}
+                `} title={project.gitStatus.status}>
                     <Icon name="call_split" className="text-[12px]" />
                     <span className="truncate max-w-[80px]">{project.gitStatus.branch}</span>
+                    {syncLabel(project.gitStatus) && (
+                        <span className="shrink-0">{syncLabel(project.gitStatus)}</span>
+                    )}
                 </div>
                 {project.gitStatus.count && (
                     <span className="text-[10px] text-slate-500">
                         {project.gitStatus.count} {project.gitStatus.status.toLowerCase()}
                     </span>
                 )}
+                {syncIssues(project.gitStatus) && (
+                    <span className="flex shrink-0" title={syncIssues(project.gitStatus)!}>
+                        <Icon name="layers" className="text-[12px] text-yellow-400" />
+                    </span>
+                )}
                 {/* Health indicator */}
                 {project.healthStatus && (
                     <div className={`flex items-center gap-1 px-1.5 py-0.5 rounded text-[9px] font-mono
@@ -438,10 +555,18 @@ const ProjectList: React.FC<ProjectListProps> = ({
     onRunScripts,
     onGitPull,
     onGitFetch,
+    onBranches,
+    onStashes,
+    onWorktrees,
+    onUpdateSubmodules,
+    onLfsPull,
+    onOpenUrl,
+    onHistory,
     onCheckHealth,
     onEditNotes,
     onManageTags,
     onTogglePin,
+    onToggleLauncher,
     selectedIds,
     onToggleSelect,
 }) => {
@@ -449,10 +574,11 @@ const ProjectList: React.FC<ProjectListProps> = ({
         <div className="flex-1 overflow-y-auto custom-scrollbar mx-6 ">
             {viewMode === 'list' ? (
                 <div className="flex flex-col ">
-                    {projects.map(project => (
+                    {groupWorktrees(projects).map(({ project, nested }) => (
                         <ProjectRow
                             key={project.id}
                             project={project}
+                            nested={nested}
                             actionState={getActionState(project.id)}
                             isSelected={selectedIds?.has(project.id)}
                             onToggleSelect={onToggleSelect ? () => onToggleSelect(project.id) : undefined}
@@ -468,16 +594,24 @@ const ProjectList: React.FC<ProjectListProps> = ({
                             onRunScripts={onRunScripts ? () => onRunScripts(project.id) : undefined}
                             onGitPull={onGitPull ? () => onGitPull(project.id) : undefined}
                             onGitFetch={onGitFetch ? () => onGitFetch(project.id) : undefined}
+                            onBranches={onBranches ? () => onBranches(project.id) : undefined}
+                            onStashes={onStashes ? () => onStashes(project.id) : undefined}
+                            onWorktrees={onWorktrees ? () => onWorktrees(project.id) : undefined}
+                            onUpdateSubmodules={onUpdateSubmodules ? () => onUpdateSubmodules(project.id) : undefined}
+                            onLfsPull={onLfsPull ? () => onLfsPull(project.id) : undefined}
+                            onOpenUrl={onOpenUrl}
+                            onHistory={onHistory ? () => onHistory(project.id) : undefined}
                             onCheckHealth={onCheckHealth ? () => onCheckHealth(project.id) : undefined}
                             onEditNotes={onEditNotes ? () => onEditNotes(project.id) : undefined}
                             onManageTags={onManageTags ? () => onManageTags(project.id) : undefined}
                             onTogglePin={onTogglePin ? () => onTogglePin(project.id) : undefined}
+                            onToggleLauncher={onToggleLauncher ? () => onToggleLauncher(project.id) : undefined}
                         />
                     ))}
                 </div>
             ) : (
                 <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 2xl:grid-cols-4 gap-4 p-6">
-                    {projects.map(project => (
+                    {groupWorktrees(projects).map(({ project }) => (
                         <ProjectGridCard
                             key={project.id}
                             project={project}
@@ -496,10 +630,18 @@ const ProjectList: React.FC<ProjectListProps> = ({
                             onRunScripts={onRunScripts ? () => onRunScripts(project.id) : undefined}
                             onGitPull={onGitPull ? () => onGitPull(project.id) : undefined}
                             onGitFetch={onGitFetch ? () => onGitFetch(project.id) : undefined}
+                            onBranches={onBranches ? () => onBranches(project.id) : undefined}
+                            onStashes={onStashes ? () => onStashes(project.id) : undefined}
+                            onWorktrees={onWorktrees ? () => onWorktrees(project.id) : undefined}
+                            onUpdateSubmodules={onUpdateSubmodules ? () => onUpdateSubmodules(project.id) : undefined}
+                            onLfsPull={onLfsPull ? () => onLfsPull(project.id) : undefined}
+                            onOpenUrl={onOpenUrl}
+                            onHistory={onHistory ? () => onHistory(project.id) : undefined}
                             onCheckHealth={onCheckHealth ? () => onCheckHealth(project.id) : undefined}
                             onEditNotes={onEditNotes ? () => onEditNotes(project.id) : undefined}
                             onManageTags={onManageTags ? () => onManageTags(project.id) : undefined}
                             onTogglePin={onTogglePin ? () => onTogglePin(project.id) : undefined}
+                            onToggleLauncher={onToggleLauncher ? () => onToggleLauncher(project.id) : undefined}
                         />
                     ))}
                 </div>
@@ -1,5 +1,5 @@
 import React, { useState, useEffect, useMemo } from 'react';
-import { AppSettings, SortOption } from '../types';
+import { AppSettings, GitHost, SortOption } from '../types';
 import { platform } from '@tauri-apps/plugin-os';
 import Icon from './Icon';
 
@@ -36,6 +36,17 @@ const sortOptions: { value: SortOption; label: string }[] = [
     { value: 'lastActive', label: 'Last Active' },
 ];
 
+const fetchIntervals = [15, 30, 60, 120, 360];
+const fetchRecentDays = [7, 14, 30, 90, 0];
+const autoCleanDays = [14, 30, 60, 90];
+
+const gitProviders = [
+    { value: 'github', label: 'GitHub' },
+    { value: 'gitlab', label: 'GitLab' },
+    { value: 'bitbucket', label: 'Bitbucket' },
+    { value: 'gitea', label: 'Gitea / Forgejo' },
+];
+
 const accentColors = [
     { value: '#1337ec', label: 'Blue', class: 'bg-[#1337ec]' },
     { value: '#8b5cf6', label: 'Purple', class: 'bg-purple-500' },
@@ -51,6 +62,14 @@ const SettingsPage: React.FC<SettingsPageProps> = ({
     const [autostartEnabled, setAutostartEnabled] = useState(false);
     const [autostartLoading, setAutostartLoading] = useState(true);
     const [currentPlatform, setCurrentPlatform] = useState<string>('');
+    const [newHost, setNewHost] = useState<GitHost>({ pattern: '', provider: 'gitlab' });
+
+    const addGitHost = () => {
+        const pattern = newHost.pattern.trim().toLowerCase();
+        if (!pattern || settings.gitHosts.some(h => h.pattern === pattern)) return;
+        onUpdateSetting('gitHosts', [...settings.gitHosts, { ...newHost, pattern }]);
+        setNewHost({ ...newHost, pattern: '' });
+    };
 
     useEffect(() => {
         // Check autostart status and platform on mount
@@ -286,6 +305,178 @@ const SettingsPage: React.FC<SettingsPageProps> = ({
                             </button>
                         </div>
 
+                        <div className="flex items-center justify-between p-4">
+                            <div>
+                                <p className="text-sm text-white">Tray attention badge</p>
+                                <p className="text-xs text-slate-500 mt-0.5">Mark the tray icon when a repository has uncommitted changes</p>
+                            </div>
+                            <button
+                                onClick={() => onUpdateSetting('trayBadge', !settings.trayBadge)}
+                                className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                    ${settings.trayBadge ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                            >
+                                <span
+                                    className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                    style={{ left: settings.trayBadge ? '22px' : '4px' }}
+                                />
+                            </button>
+                        </div>
+
+                        <div className="flex items-center justify-between p-4">
+                            <div>
+                                <p className="text-sm text-white">Keep running in the tray</p>
+                                <p className="text-xs text-slate-500 mt-0.5">Closing the window hides it; status checks, watchers and scheduled jobs keep running in the background</p>
+                            </div>
+                            <button
+                                onClick={() => onUpdateSetting('runInBackground', !settings.runInBackground)}
+                                className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                    ${settings.runInBackground ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                            >
+                                <span
+                                    className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                    style={{ left: settings.runInBackground ? '22px' : '4px' }}
+                                />
+                            </button>
+                        </div>
+
+                        <div className="p-4">
+                            <div className="flex items-center justify-between">
+                                <div>
+                                    <p className="text-sm text-white">Background fetch</p>
+                                    <p className="text-xs text-slate-500 mt-0.5">Fetch recently opened repositories and notify when a branch falls behind or is force-pushed</p>
+                                </div>
+                                <button
+                                    onClick={() => onUpdateSetting('backgroundFetch', !settings.backgroundFetch)}
+                                    className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                        ${settings.backgroundFetch ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                                >
+                                    <span
+                                        className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                        style={{ left: settings.backgroundFetch ? '22px' : '4px' }}
+                                    />
+                                </button>
+                            </div>
+                            {settings.backgroundFetch && (
+                                <div className="grid grid-cols-2 gap-3 mt-3">
+                                    <select
+                                        value={settings.fetchIntervalMinutes}
+                                        onChange={(e) => onUpdateSetting('fetchIntervalMinutes', Number(e.target.value))}
+                                        className="px-3 py-2 bg-background-dark border border-border-dim rounded text-sm text-white focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50"
+                                    >
+                                        {fetchIntervals.map(minutes => (
+                                            <option key={minutes} value={minutes}>
+                                                Every {minutes < 60 ? `${minutes} minutes` : `${minutes / 60} hour${minutes > 60 ? 's' : ''}`}
+                                            </option>
+                                        ))}
+                                    </select>
+                                    <select
+                                        value={settings.fetchRecentDays}
+                                        onChange={(e) => onUpdateSetting('fetchRecentDays', Number(e.target.value))}
+                                        className="px-3 py-2 bg-background-dark border border-border-dim rounded text-sm text-white focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50"
+                                    >
+                                        {fetchRecentDays.map(days => (
+                                            <option key={days} value={days}>
+                                                {days === 0 ? 'All projects' : `Opened in the last ${days} days`}
+                                            </option>
+                                        ))}
+                                    </select>
+                                </div>
+                            )}
+                        </div>
+
+                        <div className="p-4">
+                            <div className="flex items-center justify-between">
+                                <div>
+                                    <p className="text-sm text-white">Unpushed work reminder</p>
+                                    <p className="text-xs text-slate-500 mt-0.5">Notify once a day when repositories have uncommitted changes, unpushed commits or stashes</p>
+                                </div>
+                                <button
+                                    onClick={() => onUpdateSetting('unpushedReminder', !settings.unpushedReminder)}
+                                    className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                        ${settings.unpushedReminder ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                                >
+                                    <span
+                                        className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                        style={{ left: settings.unpushedReminder ? '22px' : '4px' }}
+                                    />
+                                </button>
+                            </div>
+                            {settings.unpushedReminder && (
+                                <input
+                                    type="time"
+                                    value={settings.unpushedReminderTime}
+                                    onChange={(e) => e.target.value && onUpdateSetting('unpushedReminderTime', e.target.value)}
+                                    className="mt-3 px-3 py-2 bg-background-dark border border-border-dim rounded text-sm text-white focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50"
+                                />
+                            )}
+                        </div>
+
+                        <div className="flex items-center justify-between p-4">
+                            <div>
+                                <p className="text-sm text-white">Watch project folders</p>
+                                <p className="text-xs text-slate-500 mt-0.5">Rescan projects when their files change outside DevLaunch</p>
+                            </div>
+                            <button
+                                onClick={() => onUpdateSetting('watchLibrary', !settings.watchLibrary)}
+                                className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                    ${settings.watchLibrary ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                            >
+                                <span
+                                    className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                    style={{ left: settings.watchLibrary ? '22px' : '4px' }}
+                                />
+                            </button>
+                        </div>
+
+                        <div className="p-4">
+                            <div className="flex items-center justify-between">
+                                <div>
+                                    <p className="text-sm text-white">Scheduled cleanup</p>
+                                    <p className="text-xs text-slate-500 mt-0.5">Once a day, remove the build output of projects you haven't opened in a while; pinned projects are skipped</p>
+                                </div>
+                                <button
+                                    onClick={() => onUpdateSetting('autoClean', !settings.autoClean)}
+                                    className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                        ${settings.autoClean ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                                >
+                                    <span
+                                        className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                        style={{ left: settings.autoClean ? '22px' : '4px' }}
+                                    />
+                                </button>
+                            </div>
+                            {settings.autoClean && (
+                                <select
+                                    value={settings.autoCleanDays}
+                                    onChange={(e) => onUpdateSetting('autoCleanDays', Number(e.target.value))}
+                                    className="mt-3 px-3 py-2 bg-background-dark border border-border-dim rounded text-sm text-white focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50"
+                                >
+                                    {autoCleanDays.map(days => (
+                                        <option key={days} value={days}>
+                                            Not opened for {days} days
+                                        </option>
+                                    ))}
+                                </select>
+                            )}
+                        </div>
+
+                        <div className="flex items-center justify-between p-4">
+                            <div>
+                                <p className="text-sm text-white">Local API</p>
+                                <p className="text-xs text-slate-500 mt-0.5">Let editor extensions and scripts query DevLaunch over a local socket (macOS and Linux)</p>
+                            </div>
+                            <button
+                                onClick={() => onUpdateSetting('apiEnabled', !settings.apiEnabled)}
+                                className={`relative w-11 h-6 rounded-full transition-colors flex-shrink-0
+                                    ${settings.apiEnabled ? 'bg-primary' : 'bg-surface-highlight border border-border-dim'}`}
+                            >
+                                <span
+                                    className="absolute top-1 w-4 h-4 rounded-full bg-white shadow-sm transition-all duration-200"
+                                    style={{ left: settings.apiEnabled ? '22px' : '4px' }}
+                                />
+                            </button>
+                        </div>
+
                         <div className="flex items-center justify-between p-4">
                             <div>
                                 <p className="text-sm text-white">Launch at startup</p>
@@ -307,6 +498,62 @@ const SettingsPage: React.FC<SettingsPageProps> = ({
                     </div>
                 </section>
 
+                {/* Git Hosts */}
+                <section className="mb-8">
+                    <h3 className="text-sm font-semibold text-slate-300 uppercase tracking-wider mb-4 flex items-center gap-2">
+                        <Icon name="dns" className="text-[18px] text-primary" />
+                        Git Hosts
+                    </h3>
+                    <div className="bg-surface border border-border-dim rounded-lg p-4 space-y-3">
+                        <p className="text-xs text-slate-500">
+                            Self-hosted servers to recognize in remote URLs, so projects get links to their branches and pull requests.
+                            Use <span className="font-mono">*.example.com</span> to match every subdomain.
+                        </p>
+                        {settings.gitHosts.map(host => (
+                            <div key={host.pattern} className="flex items-center gap-3 px-3 py-2 bg-background-dark border border-border-dim rounded">
+                                <span className="flex-1 text-sm font-mono text-white truncate">{host.pattern}</span>
+                                <span className="text-xs text-slate-400">
+                                    {gitProviders.find(p => p.value === host.provider)?.label ?? host.provider}
+                                </span>
+                                <button
+                                    onClick={() => onUpdateSetting('gitHosts', settings.gitHosts.filter(h => h.pattern !== host.pattern))}
+                                    className="text-slate-500 hover:text-red-400 transition-colors"
+                                    title="Remove"
+                                >
+                                    <Icon name="close" className="text-[16px]" />
+                                </button>
+                            </div>
+                        ))}
+                        <div className="flex gap-2">
+                            <input
+                                type="text"
+                                value={newHost.pattern}
+                                onChange={(e) => setNewHost({ ...newHost, pattern: e.target.value })}
+                                onKeyDown={(e) => e.key === 'Enter' && addGitHost()}
+                                placeholder="git.example.com"
+                                className="flex-1 px-3 py-2 bg-background-dark border border-border-dim rounded text-sm text-white font-mono focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50"
+                            />
+                            <select
+                                value={newHost.provider}
+                                onChange={(e) => setNewHost({ ...newHost, provider: e.target.value })}
+                                className="px-3 py-2 bg-background-dark border border-border-dim rounded text-sm text-white focus:outline-none focus:ring-1 focus:ring-primary/50 focus:border-primary/50"
+                            >
+                                {gitProviders.map(option => (
+                                    <option key={option.value} value={option.value}>{option.label}</option>
+                                ))}
+                            </select>
+                            <button
+                                onClick={addGitHost}
+                                disabled={!newHost.pattern.trim()}
+                                className="px-3 py-2 text-sm font-medium text-white bg-primary rounded hover:bg-primary/90 transition-colors disabled:opacity-50"
+                            >
+                                Add
+                            </button>
+                        </div>
+                        <p className="text-xs text-slate-500">Links update the next time a project is refreshed.</p>
+                    </div>
+                </section>
+
                 {/* Danger Zone */}
                 <section className="mb-8">
                     <h3 className="text-sm font-semibold text-slate-300 uppercase tracking-wider mb-4 flex items-center gap-2">
@@ -63,6 +63,28 @@ const Sidebar: React.FC<SidebarProps> = ({ currentView, onViewChange, projectCou
                         <Icon name="pie_chart" className={currentView === 'statistics' ? 'text-primary' : ''} />
                         <span className="text-sm font-medium">Statistics</span>
                     </button>
+                    <button
+                        onClick={() => onViewChange('unpushed')}
+                        className={`flex items-center gap-3 px-3 py-2 rounded transition-all w-full text-left
+                            ${currentView === 'unpushed'
+                                ? 'bg-primary/10 border border-primary/20 text-white'
+                                : 'hover:bg-white/5 text-slate-400 hover:text-white border border-transparent'
+                            }`}
+                    >
+                        <Icon name="cloud_upload" className={currentView === 'unpushed' ? 'text-primary' : ''} />
+                        <span className="text-sm font-medium">Unpushed Work</span>
+                    </button>
+                    <button
+                        onClick={() => onViewChange('duplicates')}
+                        className={`flex items-center gap-3 px-3 py-2 rounded transition-all w-full text-left
+                            ${currentView === 'duplicates'
+                                ? 'bg-primary/10 border border-primary/20 text-white'
+                                : 'hover:bg-white/5 text-slate-400 hover:text-white border border-transparent'
+                            }`}
+                    >
+                        <Icon name="content_copy" className={currentView === 'duplicates' ? 'text-primary' : ''} />
+                        <span className="text-sm font-medium">Duplicate Clones</span>
+                    </button>
 
                     <div className="h-px bg-border-dim my-2 mx-3"></div>
 
@@ -0,0 +1,207 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { StashEntry } from '../types';
+import Icon from './Icon';
+
+interface StashesModalProps {
+    isOpen: boolean;
+    projectName: string;
+    loadStashes: () => Promise<StashEntry[]>;
+    onCreate: (message: string, includeUntracked: boolean) => Promise<void>;
+    onApply: (index: number, pop: boolean) => Promise<void>;
+    onDrop: (index: number) => Promise<void>;
+    onClose: () => void;
+}
+
+const getRelativeTime = (value: string): string => {
+    const date = new Date(value);
+    if (isNaN(date.getTime())) return '';
+    const diffMin = Math.floor((Date.now() - date.getTime()) / 60000);
+    const diffHour = Math.floor(diffMin / 60);
+    const diffDay = Math.floor(diffHour / 24);
+
+    if (diffMin < 1) return 'just now';
+    if (diffMin < 60) return `${diffMin}m ago`;
+    if (diffHour < 24) return `${diffHour}h ago`;
+    if (diffDay < 30) return `${diffDay}d ago`;
+    return date.toLocaleDateString();
+};
+
+const StashesModal: React.FC<StashesModalProps> = ({
+    isOpen,
+    projectName,
+    loadStashes,
+    onCreate,
+    onApply,
+    onDrop,
+    onClose,
+}) => {
+    const [stashes, setStashes] = useState<StashEntry[]>([]);
+    const [loading, setLoading] = useState(false);
+    const [busy, setBusy] = useState(false);
+    const [message, setMessage] = useState('');
+    const [includeUntracked, setIncludeUntracked] = useState(false);
+
+    const reload = useCallback(async () => {
+        setLoading(true);
+        setStashes(await loadStashes());
+        setLoading(false);
+    }, [loadStashes]);
+
+    useEffect(() => {
+        if (isOpen) {
+            reload();
+        } else {
+            setStashes([]);
+            setMessage('');
+            setIncludeUntracked(false);
+        }
+    }, [isOpen]);
+
+    useEffect(() => {
+        const handleEsc = (e: KeyboardEvent) => {
+            if (e.key === 'Escape') onClose();
+        };
+        if (isOpen) {
+            document.addEventListener('keydown', handleEsc);
+            return () => document.removeEventListener('keydown', handleEsc);
+        }
+    }, [isOpen, onClose]);
+
+    if (!isOpen) return null;
+
+    // Every action renumbers the stash list, so reload after each one
+    const run = async (action: () => Promise<void>) => {
+        setBusy(true);
+        await action();
+        await reload();
+        setBusy(false);
+    };
+
+    const handleCreate = () => run(async () => {
+        await onCreate(message.trim(), includeUntracked);
+        setMessage('');
+    });
+
+    return (
+        <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/60 backdrop-blur-sm animate-fade-in">
+            <div className="modal-dialog w-full max-w-2xl bg-background border border-border-dim rounded-xl shadow-2xl overflow-hidden">
+                {/* Header */}
+                <div className="flex items-center justify-between px-6 py-4 border-b border-border-dim bg-surface/50">
+                    <div className="flex items-center gap-3">
+                        <div className="size-10 rounded-lg bg-primary/10 border border-primary/20 flex items-center justify-center">
+                            <Icon name="inventory" className="text-[24px] text-primary" />
+                        </div>
+                        <div>
+                            <h2 className="text-lg font-bold text-white">Stashes</h2>
+                            <p className="text-xs text-slate-500 font-mono">{projectName}</p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={onClose}
+                        className="size-8 rounded-lg hover:bg-white/5 flex items-center justify-center text-slate-400 hover:text-white transition-colors"
+                    >
+                        <Icon name="close" className="text-[20px]" />
+                    </button>
+                </div>
+
+                {/* New Stash */}
+                <div className="px-6 py-3 border-b border-border-dim flex items-center gap-2">
+                    <input
+                        type="text"
+                        value={message}
+                        onChange={(e) => setMessage(e.target.value)}
+                        onKeyDown={(e) => e.key === 'Enter' && !busy && handleCreate()}
+                        placeholder="Stash message (optional)"
+                        className="flex-1 px-3 py-1.5 bg-surface border border-border-dim rounded-lg text-sm text-white placeholder-slate-500 focus:outline-none focus:border-primary/50"
+                        autoFocus
+                    />
+                    <label className="flex items-center gap-1.5 text-xs text-slate-400 select-none">
+                        <input type="checkbox" checked={includeUntracked} onChange={(e) => setIncludeUntracked(e.target.checked)} />
+                        Include untracked
+                    </label>
+                    <button
+                        onClick={handleCreate}
+                        disabled={busy}
+                        className="flex items-center gap-1.5 px-3 py-1.5 bg-primary/10 hover:bg-primary/20 text-primary rounded-lg text-xs font-medium transition-colors disabled:opacity-50"
+                    >
+                        <Icon name="save" className="text-[14px]" />
+                        Stash
+                    </button>
+                </div>
+
+                {/* Stash List */}
+                <div className="max-h-[400px] overflow-y-auto custom-scrollbar">
+                    {loading ? (
+                        <div className="flex flex-col items-center justify-center py-12 gap-3">
+                            <Icon name="progress_activity" className="text-[32px] text-primary animate-spin" />
+                            <p className="text-sm text-slate-400">Loading stashes...</p>
+                        </div>
+                    ) : stashes.length === 0 ? (
+                        <div className="flex flex-col items-center justify-center py-12 gap-3">
+                            <Icon name="inventory" className="text-[48px] text-slate-600" />
+                            <p className="text-sm text-slate-400">No stashes</p>
+                        </div>
+                    ) : (
+                        <div className="p-4 space-y-1">
+                            {stashes.map(stash => (
+                                <div
+                                    key={stash.index}
+                                    className="group flex items-center justify-between px-3 py-2 rounded-lg hover:bg-surface transition-colors"
+                                >
+                                    <div className="flex-1 min-w-0">
+                                        <span className="text-sm font-medium text-white truncate block" title={stash.message}>
+                                            {stash.message}
+                                        </span>
+                                        <p className="text-xs text-slate-500 font-mono mt-0.5">
+                                            stash@{`{${stash.index}}`}
+                                            {stash.branch && ` · ${stash.branch}`}
+                                            {` · ${getRelativeTime(stash.date)}`}
+                                        </p>
+                                    </div>
+                                    <div className="opacity-0 group-hover:opacity-100 flex items-center gap-1 transition-all">
+                                        <button
+                                            onClick={() => run(() => onApply(stash.index, true))}
+                                            disabled={busy}
+                                            className="px-3 py-1.5 bg-primary/10 hover:bg-primary/20 text-primary rounded-lg text-xs font-medium transition-colors disabled:opacity-50"
+                                            title="Apply and remove from the stash list"
+                                        >
+                                            Pop
+                                        </button>
+                                        <button
+                                            onClick={() => run(() => onApply(stash.index, false))}
+                                            disabled={busy}
+                                            className="px-3 py-1.5 text-slate-300 hover:bg-white/5 rounded-lg text-xs font-medium transition-colors disabled:opacity-50"
+                                        >
+                                            Apply
+                                        </button>
+                                        <button
+                                            onClick={() => run(() => onDrop(stash.index))}
+                                            disabled={busy}
+                                            className="px-3 py-1.5 text-red-400 hover:bg-red-500/10 rounded-lg text-xs font-medium transition-colors disabled:opacity-50"
+                                        >
+                                            Drop
+                                        </button>
+                                    </div>
+                                </div>
+                            ))}
+                        </div>
+                    )}
+                </div>
+
+                {/* Footer */}
+                <div className="px-6 py-3 border-t border-border-dim bg-surface/30 flex items-center justify-between">
+                    <p className="text-xs text-slate-500">
+                        Dropped stashes cannot be recovered from here
+                    </p>
+                    <div className="flex items-center gap-2">
+                        <span className="text-xs text-slate-600">Press</span>
+                        <kbd className="px-1.5 py-0.5 bg-surface border border-border-dim rounded text-[10px] font-mono text-slate-400">ESC</kbd>
+                        <span className="text-xs text-slate-600">to close</span>
+                    </div>
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default StashesModal;
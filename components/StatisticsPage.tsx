@@ -1,12 +1,48 @@
-import React, { useMemo } from 'react';
-import { Project } from '../types';
+import React, { useMemo, useState, useEffect } from 'react';
+import { Project, LibraryActivity, CommitActivity } from '../types';
 import Icon from './Icon';
 
 interface StatisticsPageProps {
     projects: Project[];
+    loadActivity: (days: number) => Promise<LibraryActivity | null>;
     onClose: () => void;
 }
 
+const ACTIVITY_WINDOWS = [30, 90, 365];
+
+// GitHub-style grid: one column per week, Monday at the top
+const ActivityHeatmap: React.FC<{ activity: CommitActivity }> = ({ activity }) => {
+    const max = Math.max(1, ...activity.daily.map(d => d.commits));
+    // Blank cells before the first day so it lands on its weekday
+    const offset = activity.daily.length > 0 ? (new Date(activity.daily[0].date + 'T00:00:00').getDay() + 6) % 7 : 0;
+    const cells = [...Array(offset).fill(null), ...activity.daily];
+    const weeks: (typeof cells)[] = [];
+    for (let i = 0; i < cells.length; i += 7) weeks.push(cells.slice(i, i + 7));
+
+    return (
+        <div className="flex gap-[3px] overflow-x-auto custom-scrollbar pb-1">
+            {weeks.map((week, w) => (
+                <div key={w} className="flex flex-col gap-[3px]">
+                    {week.map((day, d) => day ? (
+                        <div
+                            key={d}
+                            title={`${day.date}: ${day.commits} commit${day.commits === 1 ? '' : 's'}`}
+                            className="size-3 rounded-sm"
+                            style={{
+                                backgroundColor: day.commits === 0
+                                    ? 'rgba(255, 255, 255, 0.06)'
+                                    : `color-mix(in srgb, var(--accent-color) ${25 + Math.round((day.commits / max) * 75)}%, transparent)`,
+                            }}
+                        />
+                    ) : (
+                        <div key={d} className="size-3" />
+                    ))}
+                </div>
+            ))}
+        </div>
+    );
+};
+
 // Simple donut chart component
 interface DonutChartProps {
     data: { label: string; value: number; color: string }[];
@@ -95,7 +131,27 @@ const formatBytes = (bytes: number): string => {
     return (bytes / (1024 * 1024 * 1024)).toFixed(2) + ' GB';
 };
 
-const StatisticsPage: React.FC<StatisticsPageProps> = ({ projects, onClose }) => {
+const StatisticsPage: React.FC<StatisticsPageProps> = ({ projects, loadActivity, onClose }) => {
+    const [activityDays, setActivityDays] = useState(90);
+    const [activity, setActivity] = useState<LibraryActivity | null>(null);
+    const [activityLoading, setActivityLoading] = useState(true);
+    // Empty for the whole library
+    const [activityPath, setActivityPath] = useState('');
+
+    useEffect(() => {
+        let cancelled = false;
+        setActivityLoading(true);
+        loadActivity(activityDays).then(result => {
+            if (cancelled) return;
+            setActivity(result);
+            setActivityLoading(false);
+        });
+        return () => { cancelled = true; };
+    }, [activityDays, loadActivity]);
+
+    const shownActivity = activity && (activity.projects.find(p => p.path === activityPath) || activity.total);
+    const projectName = (path: string) => projects.find(p => p.path === path)?.name || path;
+
     // Calculate storage statistics
     const storageStats = useMemo(() => {
         const projectStorage = projects.map(p => ({
@@ -313,6 +369,103 @@ const StatisticsPage: React.FC<StatisticsPageProps> = ({ projects, onClose }) =>
                     </div>
                 </div>
 
+                {/* Commit Activity */}
+                <div className="bg-surface border border-border-dim rounded-lg p-6 mb-8">
+                    <div className="flex items-center justify-between gap-4 mb-4">
+                        <h3 className="text-sm font-semibold text-white flex items-center gap-2">
+                            <Icon name="history" className="text-[18px] text-primary" />
+                            Commit Activity
+                        </h3>
+                        <div className="flex items-center gap-2">
+                            <select
+                                value={activityPath}
+                                onChange={(e) => setActivityPath(e.target.value)}
+                                className="px-2 py-1 bg-background-dark border border-border-dim rounded text-xs text-slate-300 focus:outline-none"
+                            >
+                                <option value="">All projects</option>
+                                {activity?.projects.filter(p => p.commits > 0).map(p => (
+                                    <option key={p.path} value={p.path}>{projectName(p.path)}</option>
+                                ))}
+                            </select>
+                            <div className="flex rounded border border-border-dim overflow-hidden">
+                                {ACTIVITY_WINDOWS.map(days => (
+                                    <button
+                                        key={days}
+                                        onClick={() => setActivityDays(days)}
+                                        className={`px-2 py-1 text-xs transition-colors ${activityDays === days ? 'bg-primary/20 text-primary' : 'text-slate-400 hover:text-white hover:bg-white/5'}`}
+                                    >
+                                        {days}d
+                                    </button>
+                                ))}
+                            </div>
+                        </div>
+                    </div>
+
+                    {activityLoading && !activity ? (
+                        <div className="flex items-center justify-center gap-2 py-10 text-sm text-slate-400">
+                            <Icon name="progress_activity" className="text-[20px] text-primary animate-spin" />
+                            Counting commits...
+                        </div>
+                    ) : !shownActivity || shownActivity.commits === 0 ? (
+                        <div className="py-10 text-center text-slate-500 text-sm">
+                            No commits in the last {activityDays} days.
+                        </div>
+                    ) : (
+                        <div className={`space-y-6 ${activityLoading ? 'opacity-50' : ''}`}>
+                            <div className="flex items-baseline gap-4">
+                                <p className="text-2xl font-bold text-white">{shownActivity.commits}</p>
+                                <p className="text-xs text-slate-500">commits in the last {shownActivity.days} days</p>
+                                <p className="text-xs font-mono">
+                                    <span className="text-emerald-400">+{shownActivity.insertions}</span>{' '}
+                                    <span className="text-red-400">-{shownActivity.deletions}</span>
+                                </p>
+                            </div>
+
+                            <ActivityHeatmap activity={shownActivity} />
+
+                            <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
+                                <div>
+                                    <p className="text-xs font-medium text-slate-400 uppercase tracking-wider mb-2">Top Authors</p>
+                                    <div className="space-y-1.5">
+                                        {shownActivity.authors.slice(0, 5).map(author => (
+                                            <div key={author.email} className="flex items-center justify-between text-xs">
+                                                <span className="text-slate-300 truncate" title={author.email}>{author.name}</span>
+                                                <span className="text-slate-500 font-mono shrink-0">{author.commits} commits</span>
+                                            </div>
+                                        ))}
+                                    </div>
+                                </div>
+                                <div>
+                                    <p className="text-xs font-medium text-slate-400 uppercase tracking-wider mb-2">Most Changed Files</p>
+                                    <div className="space-y-1.5">
+                                        {shownActivity.files.slice(0, 8).map(file => (
+                                            <div key={file.path} className="flex items-center justify-between gap-3 text-xs">
+                                                <span className="text-slate-300 font-mono truncate" title={file.path}>{file.path}</span>
+                                                <span className="text-slate-500 font-mono shrink-0">{file.commits}×</span>
+                                            </div>
+                                        ))}
+                                    </div>
+                                </div>
+                            </div>
+
+                            {shownActivity.lastOwnCommit && (
+                                <div className="flex items-center gap-2 text-xs text-slate-400 border-t border-border-dim pt-4">
+                                    <Icon name="commit" className="text-[16px] text-primary" />
+                                    <span>Your last commit:</span>
+                                    <span className="font-mono text-slate-500">{shownActivity.lastOwnCommit.shortId}</span>
+                                    <span className="text-slate-300 truncate">{shownActivity.lastOwnCommit.summary}</span>
+                                    {!activityPath && (
+                                        <span className="text-slate-500 shrink-0">
+                                            in {projectName(activity?.projects.find(p => p.lastOwnCommit?.id === shownActivity.lastOwnCommit?.id)?.path || '')}
+                                        </span>
+                                    )}
+                                    <span className="text-slate-500 shrink-0">{new Date(shownActivity.lastOwnCommit.date).toLocaleDateString()}</span>
+                                </div>
+                            )}
+                        </div>
+                    )}
+                </div>
+
                 {/* Storage Breakdown Table */}
                 <div className="bg-surface border border-border-dim rounded-lg overflow-hidden">
                     <div className="px-6 py-4 border-b border-border-dim">
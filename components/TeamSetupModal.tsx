@@ -0,0 +1,236 @@
+import React, { useState, useEffect } from 'react';
+import { open } from '@tauri-apps/plugin-dialog';
+import { TeamManifest, TeamReport, TeamStep } from '../types';
+import Icon from './Icon';
+
+interface TeamSetupModalProps {
+    isOpen: boolean;
+    readManifest: (path: string) => Promise<TeamManifest | null>;
+    onBootstrap: (path: string, reinstall: boolean, onStep: (step: TeamStep) => void) => Promise<TeamReport | null>;
+    onLaunchProfile: (path: string, profile: string) => Promise<void>;
+    onClose: () => void;
+}
+
+const STATUS_STYLES: Record<TeamStep['status'], { icon: string; className: string }> = {
+    ok: { icon: 'check_circle', className: 'text-emerald-400' },
+    failed: { icon: 'error', className: 'text-red-400' },
+    skipped: { icon: 'remove_circle', className: 'text-slate-500' },
+};
+
+const TeamSetupModal: React.FC<TeamSetupModalProps> = ({
+    isOpen,
+    readManifest,
+    onBootstrap,
+    onLaunchProfile,
+    onClose,
+}) => {
+    const [manifestPath, setManifestPath] = useState('');
+    const [manifest, setManifest] = useState<TeamManifest | null>(null);
+    const [reinstall, setReinstall] = useState(false);
+    const [running, setRunning] = useState(false);
+    const [steps, setSteps] = useState<TeamStep[]>([]);
+    const [report, setReport] = useState<TeamReport | null>(null);
+
+    useEffect(() => {
+        if (!isOpen) {
+            setManifestPath('');
+            setManifest(null);
+            setReinstall(false);
+            setSteps([]);
+            setReport(null);
+        }
+    }, [isOpen]);
+
+    useEffect(() => {
+        const handleEsc = (e: KeyboardEvent) => {
+            if (e.key === 'Escape' && !running) onClose();
+        };
+        if (isOpen) {
+            document.addEventListener('keydown', handleEsc);
+            return () => document.removeEventListener('keydown', handleEsc);
+        }
+    }, [isOpen, running, onClose]);
+
+    if (!isOpen) return null;
+
+    const handleSelectManifest = async () => {
+        try {
+            const selected = await open({
+                multiple: false,
+                title: 'Select Team Manifest',
+                filters: [{ name: 'Team Manifest', extensions: ['toml'] }],
+            });
+            if (!selected) return;
+            setManifestPath(selected as string);
+            setSteps([]);
+            setReport(null);
+            setManifest(await readManifest(selected as string));
+        } catch (e) {
+            console.error('Failed to select manifest:', e);
+        }
+    };
+
+    const handleBootstrap = async () => {
+        if (!manifest) return;
+        setRunning(true);
+        setSteps([]);
+        setReport(null);
+        setReport(await onBootstrap(manifestPath, reinstall, step => setSteps(prev => [...prev, step])));
+        setRunning(false);
+    };
+
+    const failed = steps.filter(s => s.status === 'failed');
+
+    return (
+        <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/60 backdrop-blur-sm animate-fade-in">
+            <div className="modal-dialog w-full max-w-2xl max-h-[85vh] flex flex-col bg-background border border-border-dim rounded-xl shadow-2xl overflow-hidden">
+                {/* Header */}
+                <div className="flex items-center justify-between px-6 py-4 border-b border-border-dim bg-surface/50">
+                    <div className="flex items-center gap-3">
+                        <div className="size-10 rounded-lg bg-primary/10 border border-primary/20 flex items-center justify-center">
+                            <Icon name="groups" className="text-[24px] text-primary" />
+                        </div>
+                        <div>
+                            <h2 className="text-lg font-bold text-white">Team Workspace</h2>
+                            <p className="text-xs text-slate-500">Clone, install and tag every repository of a team manifest</p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={onClose}
+                        disabled={running}
+                        className="size-8 rounded-lg hover:bg-white/5 flex items-center justify-center text-slate-400 hover:text-white transition-colors disabled:opacity-50"
+                    >
+                        <Icon name="close" className="text-[20px]" />
+                    </button>
+                </div>
+
+                <div className="flex-1 overflow-y-auto p-6 space-y-5">
+                    {/* Manifest */}
+                    <div>
+                        <label className="block text-sm font-medium text-slate-300 mb-2">Manifest</label>
+                        <div className="flex gap-2">
+                            <input
+                                type="text"
+                                value={manifestPath}
+                                readOnly
+                                placeholder="Select a devlaunch.team.toml..."
+                                className="flex-1 px-4 py-2.5 bg-surface border border-border-dim rounded-lg text-white placeholder-slate-500 font-mono text-sm cursor-pointer"
+                                onClick={handleSelectManifest}
+                            />
+                            <button
+                                onClick={handleSelectManifest}
+                                disabled={running}
+                                className="px-4 py-2.5 bg-surface border border-border-dim rounded-lg text-slate-300 hover:text-white hover:border-slate-500 transition-colors"
+                            >
+                                <Icon name="folder_open" />
+                            </button>
+                        </div>
+                    </div>
+
+                    {/* Plan: install commands run on this machine, so show them before anything runs */}
+                    {manifest && steps.length === 0 && (
+                        <div className="space-y-2">
+                            <p className="text-xs font-medium text-slate-400 uppercase tracking-wider">
+                                {manifest.repos.length} repositories
+                            </p>
+                            {manifest.repos.map(repo => (
+                                <div key={repo.name} className="px-3 py-2 bg-surface border border-border-dim rounded">
+                                    <div className="flex items-center justify-between gap-3">
+                                        <p className="text-sm text-white truncate">{repo.name}</p>
+                                        <div className="flex gap-1 shrink-0">
+                                            {[...manifest.workspace.tags, ...repo.tags].map(tag => (
+                                                <span key={tag} className="text-[10px] font-mono px-1.5 rounded bg-primary/10 text-primary">{tag}</span>
+                                            ))}
+                                        </div>
+                                    </div>
+                                    <p className="text-xs text-slate-500 font-mono truncate" title={repo.url}>{repo.url}</p>
+                                    <p className="text-xs text-slate-600 font-mono truncate" title={repo.path}>→ {repo.path}</p>
+                                    {repo.install.map(command => (
+                                        <p key={command} className="text-xs text-yellow-400/80 font-mono truncate">$ {command}</p>
+                                    ))}
+                                </div>
+                            ))}
+                        </div>
+                    )}
+
+                    {/* Progress */}
+                    {steps.length > 0 && (
+                        <div className="space-y-1">
+                            {steps.map((step, i) => (
+                                <div key={i} className="flex items-start gap-2 text-xs font-mono">
+                                    <Icon name={STATUS_STYLES[step.status].icon} className={`text-[16px] shrink-0 ${STATUS_STYLES[step.status].className}`} />
+                                    <span className="text-slate-300 shrink-0">{step.repo}</span>
+                                    <span className="text-slate-500 shrink-0">{step.step}</span>
+                                    <span className="text-slate-400 break-all">{step.message}</span>
+                                </div>
+                            ))}
+                            {running && (
+                                <div className="flex items-center gap-2 text-xs text-slate-400 pt-1">
+                                    <Icon name="progress_activity" className="text-[16px] text-primary animate-spin" />
+                                    Working...
+                                </div>
+                            )}
+                        </div>
+                    )}
+
+                    {/* Summary */}
+                    {report && failed.length > 0 && (
+                        <div className="p-3 bg-red-500/10 border border-red-500/30 rounded-lg space-y-1">
+                            <p className="text-sm text-red-400 font-medium">{failed.length} step(s) failed</p>
+                            {failed.map((step, i) => (
+                                <p key={i} className="text-xs text-slate-400 font-mono break-all">
+                                    {step.repo} {step.step}: {step.message}
+                                    {step.logFile && <span className="text-slate-600"> (log: {step.logFile})</span>}
+                                </p>
+                            ))}
+                        </div>
+                    )}
+
+                    {/* Launch profiles */}
+                    {manifest && manifest.profiles.length > 0 && (!running || report) && (
+                        <div>
+                            <p className="text-xs font-medium text-slate-400 uppercase tracking-wider mb-2">Launch Profiles</p>
+                            <div className="flex flex-wrap gap-2">
+                                {manifest.profiles.map(profile => (
+                                    <button
+                                        key={profile.name}
+                                        onClick={() => onLaunchProfile(manifestPath, profile.name)}
+                                        title={`${profile.repos.join(', ')}${profile.script ? ` · ${profile.script}` : ''}`}
+                                        className="flex items-center gap-1.5 px-3 py-1.5 text-xs text-slate-300 bg-surface border border-border-dim rounded hover:text-white hover:border-slate-500 transition-colors"
+                                    >
+                                        <Icon name="rocket_launch" className="text-[14px]" />
+                                        {profile.name}
+                                    </button>
+                                ))}
+                            </div>
+                        </div>
+                    )}
+                </div>
+
+                {/* Footer */}
+                <div className="flex items-center justify-between px-6 py-4 border-t border-border-dim bg-surface/30">
+                    <label className="flex items-center gap-2 text-xs text-slate-400 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            checked={reinstall}
+                            onChange={(e) => setReinstall(e.target.checked)}
+                            disabled={running}
+                            className="rounded border-border-dim"
+                        />
+                        Run install steps in existing clones too
+                    </label>
+                    <button
+                        onClick={handleBootstrap}
+                        disabled={!manifest || running}
+                        className="flex items-center gap-2 px-4 py-2 text-sm font-medium bg-primary hover:bg-primary/90 text-white rounded-lg transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+                    >
+                        <Icon name={running ? 'progress_activity' : 'download'} className={`text-[18px] ${running ? 'animate-spin' : ''}`} />
+                        {report ? 'Run Again' : 'Set Up Workspace'}
+                    </button>
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default TeamSetupModal;
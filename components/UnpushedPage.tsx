@@ -0,0 +1,174 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Project, UnpushedWork } from '../types';
+import Icon from './Icon';
+
+interface UnpushedPageProps {
+    projects: Project[];
+    loadReport: () => Promise<UnpushedWork[]>;
+    onClose: () => void;
+}
+
+const plural = (count: number, word: string) => `${count} ${word}${count !== 1 ? 's' : ''}`;
+
+// One line per thing that would be lost with this machine
+const describe = (work: UnpushedWork): { icon: string; color: string; text: string }[] => {
+    const items = [];
+    if (work.uncommitted > 0) {
+        items.push({ icon: 'edit', color: 'text-yellow-400', text: `${plural(work.uncommitted, 'file')} with uncommitted changes` });
+    }
+    for (const branch of work.branches) {
+        items.push(branch.upstream ? {
+            icon: 'arrow_upward',
+            color: 'text-blue-400',
+            text: `${branch.branch}: ${plural(branch.commits, 'commit')} not pushed to ${branch.upstream}`,
+        } : {
+            icon: 'call_split',
+            color: 'text-orange-400',
+            text: work.hasRemote
+                ? `${branch.branch}: no upstream${branch.commits > 0 ? `, ${plural(branch.commits, 'commit')} on no remote` : ''}`
+                : `${branch.branch}: ${plural(branch.commits, 'commit')}`,
+        });
+    }
+    if (work.stashes > 0) {
+        items.push({ icon: 'inventory', color: 'text-purple-400', text: plural(work.stashes, 'stash') });
+    }
+    if (!work.hasRemote) {
+        items.push({ icon: 'warning', color: 'text-red-400', text: 'No remote configured' });
+    }
+    return items;
+};
+
+const UnpushedPage: React.FC<UnpushedPageProps> = ({ projects, loadReport, onClose }) => {
+    const [report, setReport] = useState<UnpushedWork[]>([]);
+    const [loading, setLoading] = useState(true);
+
+    const reload = useCallback(async () => {
+        setLoading(true);
+        setReport(await loadReport());
+        setLoading(false);
+    }, [loadReport]);
+
+    useEffect(() => {
+        reload();
+    }, []);
+
+    const unpushedCommits = report.reduce((sum, work) => sum + work.branches.reduce((n, b) => n + b.commits, 0), 0);
+    const dirtyCount = report.filter(work => work.uncommitted > 0).length;
+    const noRemoteCount = report.filter(work => !work.hasRemote).length;
+
+    return (
+        <div className="flex-1 flex flex-col overflow-hidden">
+            {/* Header */}
+            <header className="header-bg h-auto shrink-0 border-b border-border-dim bg-background-dark/80 backdrop-blur-md z-10">
+                <div className="flex items-center justify-between px-6 py-4">
+                    <div className="flex items-center gap-4">
+                        <button
+                            onClick={onClose}
+                            className="size-8 rounded flex items-center justify-center hover:bg-white/5 text-slate-400 hover:text-white transition-colors"
+                        >
+                            <Icon name="arrow_back" className="text-[20px]" />
+                        </button>
+                        <div>
+                            <h2 className="text-white text-xl font-bold tracking-tight">Unpushed Work</h2>
+                            <p className="text-slate-500 text-xs font-mono">
+                                Work that only exists on this machine
+                            </p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={reload}
+                        disabled={loading}
+                        className="flex items-center gap-2 px-3 py-1.5 text-xs text-slate-400 hover:text-white hover:bg-white/5 rounded transition-colors disabled:opacity-50"
+                    >
+                        <Icon name="refresh" className={`text-[16px] ${loading ? 'animate-spin' : ''}`} />
+                        Refresh
+                    </button>
+                </div>
+            </header>
+
+            {/* Content */}
+            <div className="flex-1 overflow-y-auto custom-scrollbar">
+                <div className="max-w-4xl mx-auto p-6">
+                    {/* Stats Cards */}
+                    <div className="grid grid-cols-3 gap-4 mb-6">
+                        <div className="bg-surface border border-border-dim rounded-lg p-4">
+                            <div className="flex items-center gap-3">
+                                <div className="size-10 rounded-lg bg-blue-500/10 flex items-center justify-center">
+                                    <Icon name="arrow_upward" className="text-blue-400" />
+                                </div>
+                                <div>
+                                    <p className="text-2xl font-bold text-white">{unpushedCommits}</p>
+                                    <p className="text-xs text-slate-500">Unpushed Commits</p>
+                                </div>
+                            </div>
+                        </div>
+                        <div className="bg-surface border border-border-dim rounded-lg p-4">
+                            <div className="flex items-center gap-3">
+                                <div className="size-10 rounded-lg bg-yellow-500/10 flex items-center justify-center">
+                                    <Icon name="edit" className="text-yellow-400" />
+                                </div>
+                                <div>
+                                    <p className="text-2xl font-bold text-white">{dirtyCount}</p>
+                                    <p className="text-xs text-slate-500">Uncommitted Repos</p>
+                                </div>
+                            </div>
+                        </div>
+                        <div className="bg-surface border border-border-dim rounded-lg p-4">
+                            <div className="flex items-center gap-3">
+                                <div className="size-10 rounded-lg bg-red-500/10 flex items-center justify-center">
+                                    <Icon name="warning" className="text-red-400" />
+                                </div>
+                                <div>
+                                    <p className="text-2xl font-bold text-white">{noRemoteCount}</p>
+                                    <p className="text-xs text-slate-500">Without a Remote</p>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+
+                    {/* Report */}
+                    {loading ? (
+                        <div className="flex flex-col items-center justify-center py-16 gap-3">
+                            <Icon name="progress_activity" className="text-[32px] text-primary animate-spin" />
+                            <p className="text-sm text-slate-400">Checking repositories...</p>
+                        </div>
+                    ) : report.length === 0 ? (
+                        <div className="flex flex-col items-center justify-center py-16 text-center">
+                            <div className="size-16 rounded-full bg-surface flex items-center justify-center mb-4">
+                                <Icon name="check_circle" className="text-[32px] text-emerald-400" />
+                            </div>
+                            <h3 className="text-lg font-semibold text-white mb-2">Everything Is Pushed</h3>
+                            <p className="text-sm text-slate-500 max-w-sm">
+                                No repository in your library has uncommitted changes, unpushed commits or stashes.
+                            </p>
+                        </div>
+                    ) : (
+                        <div className="space-y-3">
+                            {report.map(work => {
+                                const project = projects.find(p => p.path === work.path);
+                                return (
+                                    <div key={work.path} className="p-4 bg-surface border border-border-dim rounded-lg">
+                                        <div className="flex items-baseline justify-between gap-4 mb-2">
+                                            <p className="text-sm font-medium text-white truncate">{project?.name || work.path}</p>
+                                            <p className="text-xs text-slate-600 font-mono truncate" title={work.path}>{work.path}</p>
+                                        </div>
+                                        <div className="space-y-1">
+                                            {describe(work).map(item => (
+                                                <div key={item.text} className="flex items-center gap-2 text-xs text-slate-400">
+                                                    <Icon name={item.icon} className={`text-[14px] ${item.color}`} />
+                                                    <span className="font-mono">{item.text}</span>
+                                                </div>
+                                            ))}
+                                        </div>
+                                    </div>
+                                );
+                            })}
+                        </div>
+                    )}
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default UnpushedPage;
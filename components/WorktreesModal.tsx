@@ -0,0 +1,226 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { WorktreeInfo } from '../types';
+import Icon from './Icon';
+
+interface WorktreesModalProps {
+    isOpen: boolean;
+    projectName: string;
+    projectPath: string;
+    loadWorktrees: () => Promise<WorktreeInfo[]>;
+    onAdd: (target: string, branch: string, create: boolean) => Promise<void>;
+    onRemove: (path: string) => Promise<void>;
+    onPrune: () => Promise<void>;
+    onClose: () => void;
+}
+
+// A sibling of the repository named after the branch, e.g. ~/code/api-feature-login
+const suggestTarget = (projectPath: string, branch: string): string => {
+    const separator = projectPath.includes('\\') && !projectPath.includes('/') ? '\\' : '/';
+    const trimmed = projectPath.replace(/[\\/]+$/, '');
+    const parent = trimmed.slice(0, trimmed.lastIndexOf(separator));
+    const name = trimmed.slice(trimmed.lastIndexOf(separator) + 1);
+    return `${parent}${separator}${name}-${branch.replace(/[^A-Za-z0-9._-]+/g, '-')}`;
+};
+
+const WorktreesModal: React.FC<WorktreesModalProps> = ({
+    isOpen,
+    projectName,
+    projectPath,
+    loadWorktrees,
+    onAdd,
+    onRemove,
+    onPrune,
+    onClose,
+}) => {
+    const [worktrees, setWorktrees] = useState<WorktreeInfo[]>([]);
+    const [loading, setLoading] = useState(false);
+    const [busy, setBusy] = useState(false);
+    const [branch, setBranch] = useState('');
+    const [target, setTarget] = useState('');
+    const [targetEdited, setTargetEdited] = useState(false);
+    const [create, setCreate] = useState(true);
+
+    const reload = useCallback(async () => {
+        setLoading(true);
+        setWorktrees(await loadWorktrees());
+        setLoading(false);
+    }, [loadWorktrees]);
+
+    useEffect(() => {
+        if (isOpen) {
+            reload();
+        } else {
+            setWorktrees([]);
+            setBranch('');
+            setTarget('');
+            setTargetEdited(false);
+            setCreate(true);
+        }
+    }, [isOpen]);
+
+    useEffect(() => {
+        const handleEsc = (e: KeyboardEvent) => {
+            if (e.key === 'Escape') onClose();
+        };
+        if (isOpen) {
+            document.addEventListener('keydown', handleEsc);
+            return () => document.removeEventListener('keydown', handleEsc);
+        }
+    }, [isOpen, onClose]);
+
+    if (!isOpen) return null;
+
+    const run = async (action: () => Promise<void>) => {
+        setBusy(true);
+        await action();
+        await reload();
+        setBusy(false);
+    };
+
+    const handleBranchChange = (value: string) => {
+        setBranch(value);
+        if (!targetEdited) setTarget(value.trim() ? suggestTarget(projectPath, value.trim()) : '');
+    };
+
+    const handleAdd = () => {
+        if (!branch.trim() || !target.trim()) return;
+        run(async () => {
+            await onAdd(target.trim(), branch.trim(), create);
+            setBranch('');
+            setTarget('');
+            setTargetEdited(false);
+        });
+    };
+
+    const hasStale = worktrees.some(w => w.prunable);
+
+    return (
+        <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/60 backdrop-blur-sm animate-fade-in">
+            <div className="modal-dialog w-full max-w-2xl bg-background border border-border-dim rounded-xl shadow-2xl overflow-hidden">
+                {/* Header */}
+                <div className="flex items-center justify-between px-6 py-4 border-b border-border-dim bg-surface/50">
+                    <div className="flex items-center gap-3">
+                        <div className="size-10 rounded-lg bg-primary/10 border border-primary/20 flex items-center justify-center">
+                            <Icon name="fork_right" className="text-[24px] text-primary" />
+                        </div>
+                        <div>
+                            <h2 className="text-lg font-bold text-white">Worktrees</h2>
+                            <p className="text-xs text-slate-500 font-mono">{projectName}</p>
+                        </div>
+                    </div>
+                    <button
+                        onClick={onClose}
+                        className="size-8 rounded-lg hover:bg-white/5 flex items-center justify-center text-slate-400 hover:text-white transition-colors"
+                    >
+                        <Icon name="close" className="text-[20px]" />
+                    </button>
+                </div>
+
+                {/* New Worktree */}
+                <div className="px-6 py-3 border-b border-border-dim space-y-2">
+                    <div className="flex items-center gap-2">
+                        <input
+                            type="text"
+                            value={branch}
+                            onChange={(e) => handleBranchChange(e.target.value)}
+                            onKeyDown={(e) => e.key === 'Enter' && !busy && handleAdd()}
+                            placeholder={create ? 'New branch name' : 'Existing branch'}
+                            className="flex-1 px-3 py-1.5 bg-surface border border-border-dim rounded-lg text-sm text-white font-mono placeholder-slate-500 focus:outline-none focus:border-primary/50"
+                            autoFocus
+                        />
+                        <label className="flex items-center gap-1.5 text-xs text-slate-400 select-none">
+                            <input type="checkbox" checked={create} onChange={(e) => setCreate(e.target.checked)} />
+                            Create branch
+                        </label>
+                        <button
+                            onClick={handleAdd}
+                            disabled={busy || !branch.trim() || !target.trim()}
+                            className="flex items-center gap-1.5 px-3 py-1.5 bg-primary/10 hover:bg-primary/20 text-primary rounded-lg text-xs font-medium transition-colors disabled:opacity-50"
+                        >
+                            <Icon name="add" className="text-[14px]" />
+                            Add
+                        </button>
+                    </div>
+                    <input
+                        type="text"
+                        value={target}
+                        onChange={(e) => { setTarget(e.target.value); setTargetEdited(true); }}
+                        placeholder="Worktree directory"
+                        className="w-full px-3 py-1.5 bg-surface border border-border-dim rounded-lg text-xs text-slate-300 font-mono placeholder-slate-500 focus:outline-none focus:border-primary/50"
+                    />
+                </div>
+
+                {/* Worktree List */}
+                <div className="max-h-[400px] overflow-y-auto custom-scrollbar">
+                    {loading ? (
+                        <div className="flex flex-col items-center justify-center py-12 gap-3">
+                            <Icon name="progress_activity" className="text-[32px] text-primary animate-spin" />
+                            <p className="text-sm text-slate-400">Loading worktrees...</p>
+                        </div>
+                    ) : (
+                        <div className="p-4 space-y-1">
+                            {worktrees.map(worktree => (
+                                <div
+                                    key={worktree.path}
+                                    className="group flex items-center justify-between px-3 py-2 rounded-lg hover:bg-surface transition-colors"
+                                >
+                                    <div className="flex-1 min-w-0">
+                                        <div className="flex items-center gap-2">
+                                            <span className="text-sm font-medium text-white font-mono truncate">
+                                                {worktree.branch || (worktree.bare ? '(bare)' : worktree.head?.slice(0, 7) || 'detached')}
+                                            </span>
+                                            {worktree.isMain && (
+                                                <span className="text-[10px] px-1.5 py-0.5 rounded bg-primary/10 text-primary">main</span>
+                                            )}
+                                            {worktree.locked && (
+                                                <span className="text-[10px] px-1.5 py-0.5 rounded bg-slate-500/10 text-slate-400">locked</span>
+                                            )}
+                                            {worktree.prunable && (
+                                                <span className="text-[10px] px-1.5 py-0.5 rounded bg-red-500/10 text-red-400">missing</span>
+                                            )}
+                                        </div>
+                                        <p className="text-xs text-slate-500 font-mono mt-0.5 truncate" title={worktree.path}>{worktree.path}</p>
+                                    </div>
+                                    {!worktree.isMain && !worktree.prunable && (
+                                        <button
+                                            onClick={() => run(() => onRemove(worktree.path))}
+                                            disabled={busy || worktree.locked}
+                                            className="opacity-0 group-hover:opacity-100 px-3 py-1.5 text-red-400 hover:bg-red-500/10 rounded-lg text-xs font-medium transition-all disabled:opacity-50"
+                                        >
+                                            Remove
+                                        </button>
+                                    )}
+                                </div>
+                            ))}
+                        </div>
+                    )}
+                </div>
+
+                {/* Footer */}
+                <div className="px-6 py-3 border-t border-border-dim bg-surface/30 flex items-center justify-between">
+                    {hasStale ? (
+                        <button
+                            onClick={() => run(onPrune)}
+                            disabled={busy}
+                            className="flex items-center gap-1.5 text-xs text-slate-400 hover:text-red-400 transition-colors disabled:opacity-50"
+                        >
+                            <Icon name="delete_sweep" className="text-[16px]" />
+                            Prune missing worktrees
+                        </button>
+                    ) : (
+                        <p className="text-xs text-slate-500">
+                            New worktrees are added to the library
+                        </p>
+                    )}
+                    <div className="flex items-center gap-2">
+                        <span className="text-xs text-slate-600">Press</span>
+                        <kbd className="px-1.5 py-0.5 bg-surface border border-border-dim rounded text-[10px] font-mono text-slate-400">ESC</kbd>
+                        <span className="text-xs text-slate-600">to close</span>
+                    </div>
+                </div>
+            </div>
+        </div>
+    );
+};
+
+export default WorktreesModal;
@@ -1,7 +1,9 @@
 import { useState, useEffect, useCallback, useMemo } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { open } from '@tauri-apps/plugin-dialog';
-import { Project, ProjectScanResult, ActionState, Toast, ProjectScript, HealthStatus, SortOption } from '../types';
+import { openUrl } from '@tauri-apps/plugin-opener';
+import { Project, ProjectScanResult, ActionState, Toast, ProjectScript, HealthStatus, SortOption, BranchInfo, BranchResult, StashEntry, PullResult, CommitInfo, DiffSummary, BulkGitOp, BulkGitResult, BulkGitProgress, UnpushedWork, WorktreeInfo, DuplicateGroup, CloneOptions, CloneProgress, TeamManifest, TeamReport, TeamStep, LibraryActivity } from '../types';
 
 const STORAGE_KEY = 'devlaunch_projects';
 
@@ -22,28 +24,42 @@ export function useProjects() {
     const [loading, setLoading] = useState(true);
     const [importing, setImporting] = useState(false);
     const [actionStates, setActionStates] = useState<ActionState[]>([]);
+    const [bulkGitProgress, setBulkGitProgress] = useState<BulkGitProgress | null>(null);
     const [toasts, setToasts] = useState<Toast[]>([]);
     const [selectedIds, setSelectedIds] = useState<Set<string>>(new Set());
     const [sortBy, setSortBy] = useState<SortOption>('lastOpened');
     const [filterTags, setFilterTags] = useState<string[]>([]);
 
-    // Load projects from localStorage on mount
+    // Load projects on mount: the on-disk library wins, localStorage is the legacy fallback
     useEffect(() => {
-        const stored = localStorage.getItem(STORAGE_KEY);
-        if (stored) {
+        const load = async () => {
             try {
-                setProjects(JSON.parse(stored));
+                const library = await invoke<Project[]>('load_library');
+                if (library.length > 0) {
+                    setProjects(library);
+                    return;
+                }
             } catch (e) {
-                console.error('Failed to parse stored projects:', e);
+                console.error('Failed to load library:', e);
             }
-        }
-        setLoading(false);
+
+            const stored = localStorage.getItem(STORAGE_KEY);
+            if (stored) {
+                try {
+                    setProjects(JSON.parse(stored));
+                } catch (e) {
+                    console.error('Failed to parse stored projects:', e);
+                }
+            }
+        };
+        load().finally(() => setLoading(false));
     }, []);
 
-    // Save projects to localStorage whenever they change
+    // Save projects whenever they change (this also rebuilds the tray menu)
     useEffect(() => {
         if (!loading) {
             localStorage.setItem(STORAGE_KEY, JSON.stringify(projects));
+            invoke('save_library', { projects }).catch(e => console.error('Failed to save library:', e));
         }
     }, [projects, loading]);
 
@@ -88,6 +104,16 @@ export function useProjects() {
         setToasts(prev => prev.filter(t => t.id !== id));
     }, []);
 
+    // Changes made outside the window (tray, CLI, ...) and their notices
+    useEffect(() => {
+        const unlistenLibrary = listen<Project[]>('library-changed', e => setProjects(e.payload));
+        const unlistenNotice = listen<{ kind: Toast['type']; message: string }>('app-notice', e => addToast(e.payload.message, e.payload.kind));
+        return () => {
+            unlistenLibrary.then(unlisten => unlisten());
+            unlistenNotice.then(unlisten => unlisten());
+        };
+    }, [addToast]);
+
     const setActionState = useCallback((projectId: string, action: ActionState['action'] | null) => {
         if (action === null) {
             setActionStates(prev => prev.filter(s => s.projectId !== projectId));
@@ -179,6 +205,28 @@ export function useProjects() {
         }
     }, [projects, addToast]);
 
+    const cloneProject = useCallback(async (url: string, parentDir: string, options: CloneOptions, onProgress: (progress: CloneProgress) => void): Promise<boolean> => {
+        const unlisten = await listen<CloneProgress>('clone-progress', e => onProgress(e.payload));
+        try {
+            const result = await invoke<ProjectScanResult>('clone_project', { url, parentDir, options });
+            const projectWithMeta = {
+                ...result.project,
+                lastOpened: new Date().toISOString(),
+                openCount: 0,
+                tags: [],
+                notes: '',
+            };
+            setProjects(prev => [projectWithMeta, ...prev]);
+            addToast(`Cloned "${result.project.name}" into the library`, 'success');
+            return true;
+        } catch (e) {
+            addToast(`${e}`, 'error');
+            return false;
+        } finally {
+            unlisten();
+        }
+    }, [addToast]);
+
     const removeProject = useCallback((projectId: string) => {
         setProjects(prev => prev.filter(p => p.id !== projectId));
         setSelectedIds(prev => { const next = new Set(prev); next.delete(projectId); return next; });
@@ -196,7 +244,7 @@ export function useProjects() {
             if (result.detected) {
                 setProjects(prev => prev.map(p =>
                     p.id === projectId
-                        ? { ...result.project, id: projectId, lastActive: 'Just now', tags: p.tags, notes: p.notes, isPinned: p.isPinned, openCount: p.openCount }
+                        ? { ...result.project, id: projectId, lastActive: 'Just now', tags: p.tags, notes: p.notes, isPinned: p.isPinned, hasLauncher: p.hasLauncher, openCount: p.openCount }
                         : p
                 ));
                 addToast('Project refreshed', 'success');
@@ -316,6 +364,18 @@ export function useProjects() {
         addToast('Project pin toggled', 'info');
     }, [addToast]);
 
+    const toggleLauncher = useCallback(async (projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            // The backend updates the library and sends it back through library-changed
+            await invoke('set_desktop_launcher', { projectId, enabled: !project.hasLauncher });
+            addToast(project.hasLauncher ? `Removed ${project.name} from the app launcher` : `Added ${project.name} to the app launcher`, 'success');
+        } catch (e) {
+            addToast(`Failed to update app launcher: ${e}`, 'error');
+        }
+    }, [projects, addToast]);
+
     const updateNotes = useCallback((projectId: string, notes: string) => {
         setProjects(prev => prev.map(p => p.id === projectId ? { ...p, notes } : p));
     }, []);
@@ -373,6 +433,8 @@ export function useProjects() {
         try {
             setActionState(projectId, 'running-script');
             const result = await invoke<string>('run_script', { path: project.path, scriptName, packageManager: project.packageManager });
+            // The last script run becomes the tray's quick action for this project
+            setProjects(prev => prev.map(p => p.id === projectId ? { ...p, favoriteScript: scriptName } : p));
             addToast(result, 'success');
         } catch (e) {
             addToast(`Failed to run script: ${e}`, 'error');
@@ -381,15 +443,22 @@ export function useProjects() {
         }
     }, [projects, setActionState, addToast]);
 
-    const gitPull = useCallback(async (projectId: string) => {
+    // Resolves to 'blocked' when local changes stop the pull, so the caller can offer to stash
+    const gitPull = useCallback(async (projectId: string, autostash = false): Promise<'blocked' | void> => {
         const project = projects.find(p => p.id === projectId);
         if (!project) return;
         try {
             setActionState(projectId, 'pulling');
-            const result = await invoke<string>('git_pull', { path: project.path });
+            const result = await invoke<PullResult>('git_pull', { path: project.path, autostash });
             await refreshProject(projectId);
-            addToast(result || 'Pull completed', 'success');
+            if (result.conflicts.length > 0) {
+                const kept = result.stashed && !result.restored ? '; your changes are kept in the stash' : '';
+                addToast(`Pulled with conflicts in ${result.conflicts.join(', ')}${kept}`, 'warning');
+            } else {
+                addToast(result.message || 'Pull completed', 'success');
+            }
         } catch (e) {
+            if (!autostash && `${e}`.includes('local changes')) return 'blocked';
             addToast(`Git pull failed: ${e}`, 'error');
         } finally {
             setActionState(projectId, null);
@@ -410,6 +479,359 @@ export function useProjects() {
         }
     }, [projects, setActionState, addToast]);
 
+    const bulkGit = useCallback(async (projectIds: string[], op: BulkGitOp) => {
+        const targets = projects.filter(p => projectIds.includes(p.id) && p.hasGit);
+        if (targets.length === 0) {
+            addToast('No git projects selected', 'warning');
+            return;
+        }
+        const action = op === 'pull' ? 'pulling' : op === 'fetch' ? 'fetching' : 'scanning';
+        targets.forEach(p => setActionState(p.id, action));
+        setBulkGitProgress({ op, done: 0, total: targets.length });
+
+        // Each repository reports in as it finishes, so cards update while the rest still run
+        const unlisten = await listen<BulkGitProgress & { result: BulkGitResult }>('bulk-git-progress', e => {
+            const { result, done, total } = e.payload;
+            const project = targets.find(p => p.path === result.path);
+            if (e.payload.op !== op || !project) return;
+            setActionState(project.id, null);
+            if (result.status) {
+                setProjects(prev => prev.map(p => p.id === project.id ? { ...p, gitStatus: result.status! } : p));
+            }
+            setBulkGitProgress({ op, done, total });
+        });
+
+        try {
+            const results = await invoke<BulkGitResult[]>('bulk_git', { op, paths: targets.map(p => p.path) });
+            const nameOf = (path: string) => targets.find(p => p.path === path)?.name || path;
+            const succeeded = results.filter(r => r.ok).length;
+            const skipped = results.filter(r => r.skipped);
+            const failed = results.filter(r => !r.ok && !r.skipped);
+            const verb = op === 'pull' ? 'Pulled' : op === 'fetch' ? 'Fetched' : 'Refreshed';
+
+            addToast(`${verb} ${succeeded}/${results.length} projects`, failed.length > 0 ? 'warning' : 'success');
+            if (skipped.length > 0) {
+                addToast(`Skipped with uncommitted changes: ${skipped.map(r => nameOf(r.path)).join(', ')}`, 'info');
+            }
+            if (failed.length > 0) {
+                addToast(`Failed: ${failed.map(r => `${nameOf(r.path)} (${r.message.trim()})`).join(', ')}`, 'error');
+            }
+        } catch (e) {
+            addToast(`Bulk git operation failed: ${e}`, 'error');
+        } finally {
+            unlisten();
+            targets.forEach(p => setActionState(p.id, null));
+            setBulkGitProgress(null);
+        }
+    }, [projects, setActionState, addToast]);
+
+    const getCommitLog = useCallback(async (projectId: string, branch?: string): Promise<CommitInfo[]> => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return [];
+        try {
+            return await invoke<CommitInfo[]>('git_log', { path: project.path, limit: 50, branch: branch || null });
+        } catch (e) {
+            addToast(`Failed to read commit log: ${e}`, 'error');
+            return [];
+        }
+    }, [projects, addToast]);
+
+    // Cached per HEAD by the backend, so reopening the statistics is cheap
+    const getLibraryActivity = useCallback(async (days: number): Promise<LibraryActivity | null> => {
+        try {
+            return await invoke<LibraryActivity>('library_activity', { days });
+        } catch (e) {
+            addToast(`Failed to count commits: ${e}`, 'error');
+            return null;
+        }
+    }, [addToast]);
+
+    const getDiffSummary = useCallback(async (projectId: string): Promise<DiffSummary | null> => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return null;
+        try {
+            return await invoke<DiffSummary>('git_diff_summary', { path: project.path });
+        } catch (e) {
+            addToast(`Failed to read changes: ${e}`, 'error');
+            return null;
+        }
+    }, [projects, addToast]);
+
+    const updateSubmodules = useCallback(async (projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            setActionState(projectId, 'pulling');
+            const result = await invoke<string>('update_submodules', { path: project.path });
+            await refreshProject(projectId);
+            addToast(result, 'success');
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        } finally {
+            setActionState(projectId, null);
+        }
+    }, [projects, setActionState, addToast, refreshProject]);
+
+    const lfsPull = useCallback(async (projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            setActionState(projectId, 'pulling');
+            const result = await invoke<string>('lfs_pull', { path: project.path });
+            await refreshProject(projectId);
+            addToast(result, 'success');
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        } finally {
+            setActionState(projectId, null);
+        }
+    }, [projects, setActionState, addToast, refreshProject]);
+
+    const openRemotePage = useCallback(async (url: string) => {
+        try {
+            await openUrl(url);
+        } catch (e) {
+            addToast(`Failed to open ${url}: ${e}`, 'error');
+        }
+    }, [addToast]);
+
+    const listWorktrees = useCallback(async (projectId: string): Promise<WorktreeInfo[]> => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return [];
+        try {
+            return await invoke<WorktreeInfo[]>('list_worktrees', { path: project.path });
+        } catch (e) {
+            addToast(`Failed to list worktrees: ${e}`, 'error');
+            return [];
+        }
+    }, [projects, addToast]);
+
+    // New worktrees are added to the library, where they're grouped under their repository
+    const addWorktree = useCallback(async (projectId: string, target: string, branch: string, create: boolean) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            await invoke<string>('add_worktree', { path: project.path, target, branch, create });
+            await addProjectFromPath(target);
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        }
+    }, [projects, addToast, addProjectFromPath]);
+
+    const removeWorktree = useCallback(async (projectId: string, worktree: string, force = false): Promise<'dirty' | void> => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            const result = await invoke<string>('remove_worktree', { path: project.path, worktree, force });
+            setProjects(prev => prev.filter(p => p.path !== worktree));
+            addToast(result, 'success');
+        } catch (e) {
+            if (!force && `${e}`.includes('modified or untracked files')) return 'dirty';
+            addToast(`${e}`, 'error');
+        }
+    }, [projects, addToast]);
+
+    const pruneWorktrees = useCallback(async (projectId: string) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            const pruned = await invoke<string[]>('prune_worktrees', { path: project.path });
+            addToast(pruned.length > 0 ? `Pruned ${pruned.join(', ')}` : 'No stale worktrees', pruned.length > 0 ? 'success' : 'info');
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        }
+    }, [projects, addToast]);
+
+    const getUnpushedReport = useCallback(async (): Promise<UnpushedWork[]> => {
+        try {
+            return await invoke<UnpushedWork[]>('get_unpushed_report');
+        } catch (e) {
+            addToast(`Failed to check for unpushed work: ${e}`, 'error');
+            return [];
+        }
+    }, [addToast]);
+
+    const findDuplicateClones = useCallback(async (): Promise<DuplicateGroup[]> => {
+        try {
+            return await invoke<DuplicateGroup[]>('find_duplicate_clones');
+        } catch (e) {
+            addToast(`Failed to look for duplicate clones: ${e}`, 'error');
+            return [];
+        }
+    }, [addToast]);
+
+    // Deletes the clone from disk and drops it from the library; refused while it holds unique work
+    const removeDuplicateClone = useCallback(async (path: string, others: string[]): Promise<boolean> => {
+        try {
+            const result = await invoke<string>('remove_duplicate_clone', { path, others });
+            setProjects(prev => prev.filter(p => p.path !== path));
+            addToast(result, 'success');
+            return true;
+        } catch (e) {
+            addToast(`${e}`, 'error');
+            return false;
+        }
+    }, [addToast]);
+
+    const readTeamManifest = useCallback(async (path: string): Promise<TeamManifest | null> => {
+        try {
+            return await invoke<TeamManifest>('read_team_manifest', { path });
+        } catch (e) {
+            addToast(`${e}`, 'error');
+            return null;
+        }
+    }, [addToast]);
+
+    // The backend adds the repositories to the library and sends it back through library-changed
+    const bootstrapTeam = useCallback(async (path: string, reinstall: boolean, onStep: (step: TeamStep) => void): Promise<TeamReport | null> => {
+        const unlisten = await listen<TeamStep>('team-progress', e => onStep(e.payload));
+        try {
+            const report = await invoke<TeamReport>('bootstrap_team', { path, reinstall });
+            const failed = report.steps.filter(s => s.status === 'failed').length;
+            if (failed > 0) {
+                addToast(`Team workspace set up with ${failed} failed step(s)`, 'warning');
+            } else {
+                addToast('Team workspace ready', 'success');
+            }
+            return report;
+        } catch (e) {
+            addToast(`${e}`, 'error');
+            return null;
+        } finally {
+            unlisten();
+        }
+    }, [addToast]);
+
+    const launchTeamProfile = useCallback(async (path: string, profile: string) => {
+        try {
+            const result = await invoke<string>('launch_team_profile', { path, profile });
+            addToast(result, 'success');
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        }
+    }, [addToast]);
+
+    const listStashes = useCallback(async (projectId: string): Promise<StashEntry[]> => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return [];
+        try {
+            return await invoke<StashEntry[]>('list_stashes', { path: project.path });
+        } catch (e) {
+            addToast(`Failed to list stashes: ${e}`, 'error');
+            return [];
+        }
+    }, [projects, addToast]);
+
+    const createStash = useCallback(async (projectId: string, message: string, includeUntracked: boolean) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            const result = await invoke<string>('create_stash', { path: project.path, message: message || null, includeUntracked });
+            await refreshProject(projectId);
+            addToast(result, 'success');
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        }
+    }, [projects, addToast, refreshProject]);
+
+    const applyStash = useCallback(async (projectId: string, index: number, pop: boolean) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            const conflicts = await invoke<string[]>('apply_stash', { path: project.path, index, pop });
+            await refreshProject(projectId);
+            if (conflicts.length > 0) {
+                addToast(`Applied with conflicts in ${conflicts.join(', ')}; the stash was kept`, 'warning');
+            } else {
+                addToast(pop ? 'Stash popped' : 'Stash applied', 'success');
+            }
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        }
+    }, [projects, addToast, refreshProject]);
+
+    const dropStash = useCallback(async (projectId: string, index: number) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return;
+        try {
+            addToast(await invoke<string>('drop_stash', { path: project.path, index }), 'success');
+        } catch (e) {
+            addToast(`${e}`, 'error');
+        }
+    }, [projects, addToast]);
+
+    const listBranches = useCallback(async (projectId: string): Promise<BranchInfo[]> => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return [];
+        try {
+            return await invoke<BranchInfo[]>('list_branches', { path: project.path });
+        } catch (e) {
+            addToast(`Failed to list branches: ${e}`, 'error');
+            return [];
+        }
+    }, [projects, addToast]);
+
+    // Reports failures other than dirty trees, which the caller offers to stash, and refreshes
+    // the projects that changed
+    const reportBranchResults = useCallback(async (results: BranchResult[], action: string) => {
+        const nameOf = (path: string) => projects.find(p => p.path === path)?.name || path;
+        const succeeded = results.filter(r => r.ok);
+        const failed = results.filter(r => !r.ok && !r.dirty);
+
+        if (results.length === 1 && succeeded.length === 1) {
+            addToast(succeeded[0].message, 'success');
+        } else if (succeeded.length > 0) {
+            addToast(`${action}: ${succeeded.length}/${results.length} projects`, 'success');
+        }
+        failed.forEach(r => addToast(`${nameOf(r.path)}: ${r.message}`, 'error'));
+
+        for (const result of succeeded) {
+            const project = projects.find(p => p.path === result.path);
+            if (project) await refreshProject(project.id);
+        }
+    }, [projects, addToast, refreshProject]);
+
+    const switchBranch = useCallback(async (projectIds: string[], branch: string, stash: boolean): Promise<BranchResult[]> => {
+        const paths = projects.filter(p => projectIds.includes(p.id)).map(p => p.path);
+        try {
+            const results = await invoke<BranchResult[]>('switch_branch', { paths, branch, stash });
+            await reportBranchResults(results, `Switched to ${branch}`);
+            return results;
+        } catch (e) {
+            addToast(`Failed to switch branch: ${e}`, 'error');
+            return [];
+        }
+    }, [projects, addToast, reportBranchResults]);
+
+    const createBranch = useCallback(async (projectId: string, name: string, startPoint: string, switchTo: boolean) => {
+        const project = projects.find(p => p.id === projectId);
+        if (!project) return false;
+        try {
+            const result = await invoke<string>('create_branch', {
+                path: project.path,
+                name,
+                startPoint: startPoint || null,
+                switch: switchTo,
+            });
+            if (switchTo) await refreshProject(projectId);
+            addToast(result, 'success');
+            return true;
+        } catch (e) {
+            addToast(`${e}`, 'error');
+            return false;
+        }
+    }, [projects, addToast, refreshProject]);
+
+    const deleteMergedBranches = useCallback(async (projectIds: string[]) => {
+        const paths = projects.filter(p => projectIds.includes(p.id)).map(p => p.path);
+        try {
+            const results = await invoke<BranchResult[]>('delete_merged_branches', { paths });
+            await reportBranchResults(results, 'Deleted merged branches');
+        } catch (e) {
+            addToast(`Failed to delete merged branches: ${e}`, 'error');
+        }
+    }, [projects, addToast, reportBranchResults]);
+
     const checkHealth = useCallback(async (projectId: string) => {
         const project = projects.find(p => p.id === projectId);
         if (!project) return;
@@ -510,6 +932,7 @@ export function useProjects() {
         clearSelection,
         addProject,
         addProjectFromPath,
+        cloneProject,
         removeProject,
         refreshProject,
         openInIde,
@@ -521,10 +944,37 @@ export function useProjects() {
         bulkCleanBuildFolders,
         gitPull,
         gitFetch,
+        bulkGit,
+        bulkGitProgress,
+        getCommitLog,
+        getDiffSummary,
+        getLibraryActivity,
+        getUnpushedReport,
+        findDuplicateClones,
+        removeDuplicateClone,
+        readTeamManifest,
+        bootstrapTeam,
+        launchTeamProfile,
+        updateSubmodules,
+        lfsPull,
+        openRemotePage,
+        listWorktrees,
+        addWorktree,
+        removeWorktree,
+        pruneWorktrees,
+        listStashes,
+        createStash,
+        applyStash,
+        dropStash,
+        listBranches,
+        switchBranch,
+        createBranch,
+        deleteMergedBranches,
         checkHealth,
         getScripts,
         runScript,
         togglePin,
+        toggleLauncher,
         updateNotes,
         updateTags,
         addTag,
@@ -1,4 +1,5 @@
 import { useState, useEffect, useCallback } from 'react';
+import { invoke } from '@tauri-apps/api/core';
 import { AppSettings } from '../types';
 
 const SETTINGS_KEY = 'devlaunch_settings';
@@ -11,6 +12,19 @@ const defaultSettings: AppSettings = {
     confirmBeforeDelete: true,
     showArchivedProjects: false,
     defaultSort: 'lastOpened',
+    trayBadge: true,
+    statusPollMinutes: 5,
+    runInBackground: true,
+    apiEnabled: false,
+    backgroundFetch: false,
+    fetchIntervalMinutes: 30,
+    fetchRecentDays: 14,
+    unpushedReminder: false,
+    unpushedReminderTime: '18:00',
+    autoClean: false,
+    autoCleanDays: 30,
+    watchLibrary: true,
+    gitHosts: [],
 };
 
 export function useSettings() {
@@ -33,6 +47,7 @@ export function useSettings() {
     useEffect(() => {
         if (loaded) {
             localStorage.setItem(SETTINGS_KEY, JSON.stringify(settings));
+            invoke('save_settings', { settings }).catch(e => console.error('Failed to save settings:', e));
             // Apply theme to document
             document.documentElement.setAttribute('data-theme', settings.theme);
             // Apply accent color as CSS variable
@@ -0,0 +1,92 @@
+//! Library-wide git refresh: status of every repository, once per backend.
+//!
+//! `cargo bench -p devlaunch-core --bench git_status`; set `DEVLAUNCH_BENCH_REPOS` to change
+//! the library size (default 100).
+
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use devlaunch_core::git::{get_git_info, CliBackend, GitBackend};
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Bench")
+        .env("GIT_AUTHOR_EMAIL", "bench@example.com")
+        .env("GIT_COMMITTER_NAME", "Bench")
+        .env("GIT_COMMITTER_EMAIL", "bench@example.com")
+        .status()
+        .expect("git must be installed to run this benchmark");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Repositories with some history, an upstream and a dirty working tree, like a real library
+fn library(root: &Path, count: usize) -> Vec<PathBuf> {
+    let remote = root.join("remote.git");
+    git(root, &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+
+    (0..count)
+        .map(|i| {
+            let repo = root.join(format!("project-{}", i));
+            fs::create_dir_all(repo.join("src")).unwrap();
+            git(&repo, &["init", "-q", "-b", "main"]);
+            for n in 0..20 {
+                fs::write(repo.join("src").join(format!("file{}.txt", n)), format!("{}\n", n)).unwrap();
+            }
+            git(&repo, &["add", "."]);
+            git(&repo, &["commit", "-q", "-m", "initial"]);
+            git(&repo, &["remote", "add", "origin", remote.to_str().unwrap()]);
+            git(&repo, &["push", "-q", "-u", "origin", &format!("main:project-{}", i)]);
+            fs::write(repo.join("src/file0.txt"), "changed\n").unwrap();
+            fs::write(repo.join("notes.txt"), "untracked\n").unwrap();
+            repo
+        })
+        .collect()
+}
+
+fn refresh(c: &mut Criterion) {
+    let count = std::env::var("DEVLAUNCH_BENCH_REPOS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(100);
+    let root = tempfile::tempdir().unwrap();
+    let repos = library(root.path(), count);
+
+    let mut group = c.benchmark_group(format!("refresh {} repos", count));
+    group.sample_size(10);
+
+    group.bench_function("cli", |b| {
+        b.iter(|| {
+            for repo in &repos {
+                black_box(CliBackend.status(repo).unwrap());
+            }
+        })
+    });
+
+    #[cfg(feature = "git2")]
+    group.bench_function("git2", |b| {
+        use devlaunch_core::git::Git2Backend;
+        b.iter(|| {
+            for repo in &repos {
+                black_box(Git2Backend.status(repo).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("get_git_info", |b| {
+        b.iter(|| {
+            for repo in &repos {
+                black_box(get_git_info(repo));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, refresh);
+criterion_main!(benches);
@@ -0,0 +1,297 @@
+//! Opt-in local JSON-RPC 2.0 API for editor extensions, shell prompts and scripts.
+//!
+//! The server listens on `api.sock` in the runtime directory (`$XDG_RUNTIME_DIR/devlaunch`)
+//! and reads one request per line. A connection must first call `auth` with the contents of
+//! `api.token`, which only the current user can read. Results use the same JSON shapes as the
+//! Tauri commands.
+//!
+//! Methods: `auth {token}`, `library.list`, `project.byPath {path}`, `git.status {path}`,
+//! `script.run {path, script, packageManager?}`, `script.stop {id}`, `script.list`,
+//! `cleanup.plan {path, projectType?}`.
+
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::cleanup::plan_build_cleanup;
+use crate::detect::detect_project_type;
+use crate::git::get_git_info;
+use crate::launch::read_scripts;
+use crate::model::Project;
+use crate::runner::ProcessRunner;
+use crate::store;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVER_ERROR: i64 = -32000;
+const UNAUTHORIZED: i64 = -32001;
+
+pub fn socket_path() -> PathBuf {
+    store::runtime_dir().join("api.sock")
+}
+
+pub fn token_path() -> PathBuf {
+    store::runtime_dir().join("api.token")
+}
+
+/// A running API server; dropping it stops listening and removes the socket and token file
+pub struct ApiServer {
+    socket: PathBuf,
+    token_file: PathBuf,
+    stopped: Arc<AtomicBool>,
+}
+
+#[derive(Deserialize)]
+struct Request {
+    jsonrpc: Option<String>,
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl From<String> for RpcError {
+    fn from(message: String) -> Self {
+        RpcError { code: SERVER_ERROR, message }
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthParams {
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct PathParams {
+    path: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunParams {
+    path: String,
+    script: String,
+    package_manager: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IdParams {
+    id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CleanupParams {
+    path: String,
+    project_type: Option<String>,
+}
+
+impl ApiServer {
+    /// Starts serving on the default socket and writes a fresh token
+    pub fn start(runner: Arc<ProcessRunner>) -> Result<Self, String> {
+        Self::start_at(socket_path(), token_path(), runner)
+    }
+
+    pub fn start_at(socket: PathBuf, token_file: PathBuf, runner: Arc<ProcessRunner>) -> Result<Self, String> {
+        if let Some(parent) = socket.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        if UnixStream::connect(&socket).is_ok() {
+            return Err(format!("Another server is already listening on {}", socket.display()));
+        }
+        // Nobody answered, so a leftover socket file is stale
+        let _ = fs::remove_file(&socket);
+
+        let listener = UnixListener::bind(&socket).map_err(|e| format!("Failed to listen on {}: {}", socket.display(), e))?;
+        fs::set_permissions(&socket, fs::Permissions::from_mode(0o600))
+            .map_err(|e| format!("Failed to restrict {}: {}", socket.display(), e))?;
+
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        write_private(&token_file, &token)?;
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stop_flag = stopped.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let token = token.clone();
+                    let runner = runner.clone();
+                    std::thread::spawn(move || serve_connection(stream, &token, &runner));
+                }
+            }
+        });
+
+        Ok(ApiServer { socket, token_file, stopped })
+    }
+}
+
+impl Drop for ApiServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it sees the flag
+        let _ = UnixStream::connect(&self.socket);
+        let _ = fs::remove_file(&self.socket);
+        let _ = fs::remove_file(&self.token_file);
+    }
+}
+
+/// Writes a file only the current user can read, replacing any existing one
+fn write_private(path: &Path, content: &str) -> Result<(), String> {
+    let _ = fs::remove_file(path);
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn serve_connection(stream: UnixStream, token: &str, runner: &ProcessRunner) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(_) => return,
+    };
+    let mut authorized = false;
+
+    for line in BufReader::new(stream).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = respond(&line, token, &mut authorized, runner) {
+            if writeln!(writer, "{}", response).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// Handles one request line; notifications (requests without an id) get no response
+fn respond(line: &str, token: &str, authorized: &mut bool, runner: &ProcessRunner) -> Option<Value> {
+    let request: Request = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return Some(error_response(Value::Null, PARSE_ERROR, format!("Parse error: {}", e))),
+    };
+    let id = request.id.clone();
+
+    let result = if request.jsonrpc.as_deref() != Some("2.0") {
+        Err(RpcError { code: INVALID_REQUEST, message: "Only JSON-RPC 2.0 is supported".to_string() })
+    } else if request.method == "auth" {
+        params::<AuthParams>(request.params).and_then(|p| {
+            *authorized = constant_time_eq(p.token.as_bytes(), token.as_bytes());
+            if *authorized {
+                Ok(json!(true))
+            } else {
+                Err(RpcError { code: UNAUTHORIZED, message: "Invalid token".to_string() })
+            }
+        })
+    } else if !*authorized {
+        Err(RpcError { code: UNAUTHORIZED, message: "Call auth with the token from api.token first".to_string() })
+    } else {
+        call(&request.method, request.params, runner)
+    };
+
+    let id = id?;
+    Some(match result {
+        Ok(value) => json!({ "jsonrpc": "2.0", "id": id, "result": value }),
+        Err(e) => error_response(id, e.code, e.message),
+    })
+}
+
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn params<T: DeserializeOwned>(value: Value) -> Result<T, RpcError> {
+    serde_json::from_value(value).map_err(|e| RpcError { code: INVALID_PARAMS, message: format!("Invalid params: {}", e) })
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(|e| RpcError::from(format!("Failed to serialize: {}", e)))
+}
+
+fn existing_dir(path: &str) -> Result<&Path, RpcError> {
+    let dir = Path::new(path);
+    if dir.is_dir() {
+        Ok(dir)
+    } else {
+        Err(RpcError { code: INVALID_PARAMS, message: format!("Not a directory: {}", path) })
+    }
+}
+
+/// The library project containing `path` (the deepest one, for nested projects)
+fn project_for_path(projects: Vec<Project>, path: &str) -> Option<Project> {
+    let canonical = |p: &str| fs::canonicalize(p).unwrap_or_else(|_| PathBuf::from(p));
+    let target = canonical(path);
+    projects
+        .into_iter()
+        .map(|p| (canonical(&p.path), p))
+        .filter(|(root, _)| target.starts_with(root))
+        .max_by_key(|(root, _)| root.components().count())
+        .map(|(_, p)| p)
+}
+
+fn call(method: &str, raw_params: Value, runner: &ProcessRunner) -> Result<Value, RpcError> {
+    match method {
+        "library.list" => to_value(store::load_projects()),
+        "project.byPath" => {
+            let p: PathParams = params(raw_params)?;
+            to_value(project_for_path(store::load_projects(), &p.path))
+        }
+        "git.status" => {
+            let p: PathParams = params(raw_params)?;
+            to_value(get_git_info(existing_dir(&p.path)?))
+        }
+        "script.run" => {
+            let p: RunParams = params(raw_params)?;
+            let scripts = read_scripts(existing_dir(&p.path)?)?;
+            if !scripts.iter().any(|s| s.name == p.script) {
+                return Err(RpcError { code: INVALID_PARAMS, message: format!("No \"{}\" script in {}", p.script, p.path) });
+            }
+            to_value(runner.start(&p.path, &p.script, p.package_manager)?)
+        }
+        "script.stop" => {
+            let p: IdParams = params(raw_params)?;
+            to_value(runner.stop(&p.id)?)
+        }
+        "script.list" => to_value(runner.list()),
+        "cleanup.plan" => {
+            let p: CleanupParams = params(raw_params)?;
+            let dir = existing_dir(&p.path)?;
+            let project_type = p.project_type.unwrap_or_else(|| detect_project_type(dir));
+            let folders = plan_build_cleanup(dir, &project_type);
+            Ok(json!({
+                "projectType": project_type,
+                "totalBytes": folders.iter().map(|(_, size)| size).sum::<u64>(),
+                "folders": folders.iter().map(|(folder, size)| json!({ "folder": folder, "bytes": size })).collect::<Vec<_>>(),
+            }))
+        }
+        _ => Err(RpcError { code: METHOD_NOT_FOUND, message: format!("Unknown method \"{}\"", method) }),
+    }
+}
@@ -0,0 +1,181 @@
+//! Build output, dependency folder and duplicate clone cleanup.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::detect::detect_project_type;
+use crate::git::clone_removal_blockers;
+use crate::size::{format_size, get_directory_size};
+
+/// First non-empty build output folder for the project type, with its size in bytes
+pub fn detect_build_folder(project_path: &Path, project_type: &str) -> Option<(String, u64)> {
+    // Define build folders for different project types
+    let build_folders: Vec<&str> = match project_type {
+        "flutter" => vec!["build"],
+        "android" => vec!["app/build", "build", ".gradle"],
+        "node" => vec!["dist", "build", ".next", ".nuxt", "out", ".output"],
+        "python" => vec!["__pycache__", ".pytest_cache", "dist", "build", "*.egg-info", ".venv", "venv"],
+        "rust" => vec!["target"],
+        "go" => vec!["bin"],
+        _ => vec!["build", "dist", "out"],
+    };
+
+    for folder in build_folders {
+        let folder_path = project_path.join(folder);
+        if folder_path.exists() && folder_path.is_dir() {
+            let size = get_directory_size(&folder_path);
+            if size > 0 {
+                return Some((folder.to_string(), size));
+            }
+        }
+    }
+    None
+}
+
+/// Total size of the project type's build output folders, not counting `node_modules`
+pub fn get_cleanable_size(project_path: &Path, project_type: &str) -> u64 {
+    let mut total_size = 0u64;
+
+    match project_type {
+        "flutter" => {
+            // Flutter: build folder
+            let build_path = project_path.join("build");
+            if build_path.exists() {
+                total_size += get_directory_size(&build_path);
+            }
+        }
+        "android" => {
+            // Android: build folders and .gradle
+            for folder in &["app/build", "build", ".gradle"] {
+                let folder_path = project_path.join(folder);
+                if folder_path.exists() {
+                    total_size += get_directory_size(&folder_path);
+                }
+            }
+        }
+        "node" => {
+            // Node: node_modules is handled separately, but also check build outputs
+            for folder in &["dist", "build", ".next", ".nuxt", "out", ".output"] {
+                let folder_path = project_path.join(folder);
+                if folder_path.exists() {
+                    total_size += get_directory_size(&folder_path);
+                }
+            }
+        }
+        "rust" => {
+            // Rust: target folder
+            let target_path = project_path.join("target");
+            if target_path.exists() {
+                total_size += get_directory_size(&target_path);
+            }
+        }
+        "python" => {
+            // Python: __pycache__, .venv, etc.
+            for folder in &["__pycache__", ".pytest_cache", "dist", "build", ".venv", "venv"] {
+                let folder_path = project_path.join(folder);
+                if folder_path.exists() {
+                    total_size += get_directory_size(&folder_path);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    total_size
+}
+
+/// Folders `clean_build_folders` removes for each project type
+pub fn build_folders_to_clean(project_type: &str) -> Vec<&'static str> {
+    match project_type {
+        "flutter" => vec!["build", ".dart_tool"],
+        "android" => vec!["app/build", "build", ".gradle"],
+        "node" => vec!["dist", "build", ".next", ".nuxt", "out", ".output", ".cache"],
+        "rust" => vec!["target"],
+        "python" => vec!["__pycache__", ".pytest_cache", "dist", "build", ".eggs"],
+        "go" => vec!["bin"],
+        _ => vec!["build", "dist", "out"],
+    }
+}
+
+/// Build folders that currently exist in the project, with their sizes, without touching them
+pub fn plan_build_cleanup(project_path: &Path, project_type: &str) -> Vec<(String, u64)> {
+    build_folders_to_clean(project_type)
+        .into_iter()
+        .filter(|folder| project_path.join(folder).is_dir())
+        .map(|folder| (folder.to_string(), get_directory_size(&project_path.join(folder))))
+        .collect()
+}
+
+/// Deletes the build folders for the project type (detected when not given) and reports what was freed
+pub fn clean_build_folders(path: &str, project_type: Option<String>) -> Result<String, String> {
+    let project_path = PathBuf::from(path);
+    let ptype = project_type.unwrap_or_else(|| detect_project_type(&project_path));
+    
+    let mut cleaned_count = 0;
+    let mut total_freed = 0u64;
+    let mut errors = Vec::new();
+    
+    for (folder, size) in plan_build_cleanup(&project_path, &ptype) {
+        match fs::remove_dir_all(project_path.join(&folder)) {
+            Ok(_) => {
+                cleaned_count += 1;
+                total_freed += size;
+            }
+            Err(e) => {
+                errors.push(format!("{}: {}", folder, e));
+            }
+        }
+    }
+    
+    // For Python, also clean __pycache__ in subdirectories
+    if ptype == "python" {
+        if let Ok(entries) = fs::read_dir(&project_path) {
+            for entry in entries.flatten() {
+                let cache_path = entry.path().join("__pycache__");
+                if cache_path.exists() && fs::remove_dir_all(&cache_path).is_ok() {
+                    cleaned_count += 1;
+                }
+            }
+        }
+    }
+    
+    if cleaned_count > 0 {
+        let freed_str = format_size(total_freed);
+        if errors.is_empty() {
+            Ok(format!("Cleaned {} folder(s), freed {}", cleaned_count, freed_str))
+        } else {
+            Ok(format!("Cleaned {} folder(s), freed {}. Errors: {}", cleaned_count, freed_str, errors.join(", ")))
+        }
+    } else if !errors.is_empty() {
+        Err(format!("Failed to clean: {}", errors.join(", ")))
+    } else {
+        Ok("No build folders to clean".to_string())
+    }
+}
+
+/// Deletes the project's `node_modules` folder
+pub fn remove_node_modules(path: &str) -> Result<String, String> {
+    let node_modules_path = PathBuf::from(path).join("node_modules");
+
+    if !node_modules_path.exists() {
+        return Err("node_modules directory does not exist".to_string());
+    }
+
+    fs::remove_dir_all(&node_modules_path)
+        .map_err(|e| format!("Failed to delete node_modules: {}", e))?;
+
+    Ok("node_modules deleted successfully".to_string())
+}
+
+/// Deletes a redundant clone of a repository that one of `others` also holds. Refused while the
+/// clone has anything that would be lost with it, such as uncommitted files or commits no remote
+/// or other clone has.
+pub fn remove_duplicate_clone(path: &Path, others: &[PathBuf]) -> Result<String, String> {
+    let blockers = clone_removal_blockers(path, others)?;
+    if !blockers.is_empty() {
+        return Err(format!("Clone has work that exists nowhere else: {}", blockers.join(", ")));
+    }
+    let size = get_directory_size(path);
+    fs::remove_dir_all(path).map_err(|e| format!("Failed to delete clone: {}", e))?;
+    Ok(format!("Clone deleted, freed {}", format_size(size)))
+}
@@ -0,0 +1,131 @@
+//! Parsing and validation of `devlaunch://` links.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use url::Url;
+
+pub const SCHEME: &str = "devlaunch";
+
+/// IDE ids a link may ask for, the same ones the settings page offers
+pub const KNOWN_IDES: &[&str] = &["code", "vscode", "cursor", "webstorm", "idea", "zed", "xcode", "android-studio"];
+
+/// What a link points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// An absolute directory path (`path=`)
+    Path(String),
+    /// A library project, looked up like the search box (`project=`)
+    Project(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    /// `devlaunch://open?path=...&ide=cursor`
+    Open { target: LinkTarget, ide: Option<String> },
+    /// `devlaunch://terminal?project=api`
+    Terminal { target: LinkTarget },
+    /// `devlaunch://run?project=api&script=dev`
+    Run { target: LinkTarget, script: String },
+    /// `devlaunch://add?path=...`
+    Add { path: String },
+}
+
+impl DeepLink {
+    /// Whether following the link executes commands, so the user has to confirm it first
+    pub fn needs_confirmation(&self) -> bool {
+        matches!(self, DeepLink::Run { .. })
+    }
+}
+
+/// Whether a command-line argument is a `devlaunch:` link rather than a path
+pub fn is_deep_link(arg: &str) -> bool {
+    arg.get(..=SCHEME.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("devlaunch:"))
+}
+
+/// Parses a link, rejecting unknown actions, unexpected or duplicate parameters and unsafe values
+pub fn parse(link: &str) -> Result<DeepLink, String> {
+    let url = Url::parse(link).map_err(|e| format!("Invalid link: {}", e))?;
+    if url.scheme() != SCHEME {
+        return Err(format!("Not a {}:// link: {}", SCHEME, link));
+    }
+
+    // `devlaunch://open?...` carries the action as the host, `devlaunch:open?...` as the path
+    let action = match url.host_str() {
+        Some(host) => host.to_lowercase(),
+        None => url.path().trim_matches('/').to_lowercase(),
+    };
+
+    let mut params = HashMap::new();
+    for (key, value) in url.query_pairs() {
+        if params.insert(key.to_string(), value.to_string()).is_some() {
+            return Err(format!("Duplicate \"{}\" parameter", key));
+        }
+    }
+
+    let allowed: &[&str] = match action.as_str() {
+        "open" => &["path", "project", "ide"],
+        "terminal" => &["path", "project"],
+        "run" => &["path", "project", "script"],
+        "add" => &["path"],
+        other => return Err(format!("Unknown link action \"{}\"", other)),
+    };
+    if let Some(key) = params.keys().find(|k| !allowed.contains(&k.as_str())) {
+        return Err(format!("Unexpected \"{}\" parameter for {}", key, action));
+    }
+
+    match action.as_str() {
+        "open" => Ok(DeepLink::Open {
+            target: target(&mut params)?,
+            ide: params.remove("ide").map(validate_ide).transpose()?,
+        }),
+        "terminal" => Ok(DeepLink::Terminal { target: target(&mut params)? }),
+        "run" => Ok(DeepLink::Run {
+            target: target(&mut params)?,
+            script: validate_script(params.remove("script").ok_or("Missing \"script\" parameter")?)?,
+        }),
+        _ => Ok(DeepLink::Add {
+            path: validate_path(params.remove("path").ok_or("Missing \"path\" parameter")?)?,
+        }),
+    }
+}
+
+fn target(params: &mut HashMap<String, String>) -> Result<LinkTarget, String> {
+    match (params.remove("path"), params.remove("project")) {
+        (Some(path), None) => Ok(LinkTarget::Path(validate_path(path)?)),
+        (None, Some(project)) if !project.trim().is_empty() => Ok(LinkTarget::Project(project)),
+        (None, Some(_)) => Err("Empty \"project\" parameter".to_string()),
+        (Some(_), Some(_)) => Err("Use either \"path\" or \"project\", not both".to_string()),
+        (None, None) => Err("Missing \"path\" or \"project\" parameter".to_string()),
+    }
+}
+
+// Links can come from anywhere, so only absolute paths that don't climb out with `..`
+fn validate_path(path: String) -> Result<String, String> {
+    let p = Path::new(&path);
+    if !p.is_absolute() || p.components().any(|c| c == std::path::Component::ParentDir) {
+        return Err(format!("Link paths must be absolute: {}", path));
+    }
+    Ok(path)
+}
+
+fn validate_ide(ide: String) -> Result<String, String> {
+    if KNOWN_IDES.contains(&ide.as_str()) {
+        Ok(ide)
+    } else {
+        Err(format!("Unknown IDE \"{}\"", ide))
+    }
+}
+
+// Script names end up in a shell command line, so keep them to what package.json names use
+fn validate_script(script: String) -> Result<String, String> {
+    let valid = !script.is_empty()
+        && !script.starts_with('-')
+        && script.chars().all(|c| c.is_ascii_alphanumeric() || ":-_./@+".contains(c));
+    if valid {
+        Ok(script)
+    } else {
+        Err(format!("Invalid script name \"{}\"", script))
+    }
+}
@@ -0,0 +1,167 @@
+//! Freedesktop `.desktop` launchers that make projects show up in GNOME/KDE application search.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::launch::read_scripts;
+use crate::model::Project;
+
+const FILE_PREFIX: &str = "devlaunch-project-";
+
+/// `~/.local/share/applications`
+pub fn applications_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("applications"))
+}
+
+/// File name of a project's launcher; ids are reduced to characters desktop file ids allow
+pub fn launcher_file_name(project_id: &str) -> String {
+    let id: String = project_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    format!("{}{}.desktop", FILE_PREFIX, id)
+}
+
+/// Quotes one `Exec` argument: the quoting rule first, then the string escape, then field codes
+pub fn exec_arg(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted.replace('\\', "\\\\").replace('%', "%%")
+}
+
+// Keys of type localestring can't hold raw newlines or tabs
+fn escape_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+}
+
+/// Icon theme name closest to the project's Material icon
+pub fn theme_icon(material_icon: &str) -> &'static str {
+    match material_icon {
+        "phone_iphone" | "phone_android" => "phone",
+        "desktop_windows" => "computer",
+        "dns" => "network-server",
+        "code" => "text-html",
+        "data_object" => "text-x-python",
+        "folder" => "folder",
+        _ => "applications-development",
+    }
+}
+
+/// Script the "Run" action starts: the tray's favorite if set, otherwise `dev` or `start`
+fn launcher_script(project: &Project) -> Option<String> {
+    let scripts = read_scripts(Path::new(&project.path)).unwrap_or_default();
+    project
+        .favorite_script
+        .as_deref()
+        .into_iter()
+        .chain(["dev", "start"])
+        .find(|name| scripts.iter().any(|s| s.name == *name))
+        .map(str::to_string)
+}
+
+/// Contents of the launcher for `project`, started through the DevLaunch executable `exe`
+pub fn launcher_entry(project: &Project, exe: &Path) -> String {
+    let exe = exec_arg(&exe.to_string_lossy());
+    let path = exec_arg(&project.path);
+    let script = launcher_script(project);
+
+    let mut actions = vec!["terminal"];
+    if script.is_some() {
+        actions.push("run");
+    }
+
+    let mut entry = format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name={name}\n\
+         Comment={comment}\n\
+         Icon={icon}\n\
+         Exec={exe} {path}\n\
+         Terminal=false\n\
+         Categories=Development;\n\
+         Keywords=devlaunch;{keywords}\n\
+         Actions={actions};\n\
+         X-DevLaunch-Project={id}\n\
+         \n\
+         [Desktop Action terminal]\n\
+         Name=Open Terminal\n\
+         Exec={exe} --terminal {path}\n",
+        name = escape_value(&project.name),
+        comment = escape_value(&format!("Open {} in your IDE", project.path)),
+        icon = theme_icon(&project.icon),
+        keywords = project
+            .tags()
+            .iter()
+            .map(|t| format!("{};", escape_value(&t.replace(';', " "))))
+            .collect::<String>(),
+        actions = actions.join(";"),
+        id = escape_value(&project.id),
+    );
+    if let Some(script) = script {
+        entry.push_str(&format!(
+            "\n[Desktop Action run]\nName=Run \"{}\"\nExec={} {} {}\n",
+            escape_value(&script),
+            exe,
+            exec_arg(&format!("--run={}", script)),
+            path
+        ));
+    }
+    entry
+}
+
+/// Writes or refreshes the launcher for `project`
+pub fn write_launcher(dir: &Path, project: &Project, exe: &Path) -> Result<PathBuf, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = dir.join(launcher_file_name(&project.id));
+    let entry = launcher_entry(project, exe);
+    if fs::read_to_string(&path).ok().as_deref() != Some(entry.as_str()) {
+        fs::write(&path, entry).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(path)
+}
+
+/// Removes a project's launcher. Returns false when there was none.
+pub fn remove_launcher(dir: &Path, project_id: &str) -> Result<bool, String> {
+    let path = dir.join(launcher_file_name(project_id));
+    if !path.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+/// Writes launchers for projects that have one enabled and aren't archived, and removes
+/// all other launchers this module created
+pub fn sync_launchers(dir: &Path, projects: &[Project], exe: &Path) -> Result<(), String> {
+    let wanted: Vec<&Project> = projects
+        .iter()
+        .filter(|p| p.has_launcher.unwrap_or(false) && !p.is_archived.unwrap_or(false))
+        .collect();
+    for project in &wanted {
+        write_launcher(dir, project, exe)?;
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(()),
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let ours = file_name.starts_with(FILE_PREFIX) && file_name.ends_with(".desktop");
+        if ours && !wanted.iter().any(|p| launcher_file_name(&p.id) == file_name) {
+            fs::remove_file(entry.path())
+                .map_err(|e| format!("Failed to remove {}: {}", entry.path().display(), e))?;
+        }
+    }
+    Ok(())
+}
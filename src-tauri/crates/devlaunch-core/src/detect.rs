@@ -0,0 +1,246 @@
+//! Detection of project type, package manager, tech stack and icon from files on disk.
+
+use std::fs;
+use std::path::Path;
+
+use crate::model::TechStack;
+
+/// Package manager inferred from lockfiles, defaulting to npm when only `package.json` exists
+pub fn detect_package_manager(project_path: &Path) -> Option<String> {
+    if project_path.join("pnpm-lock.yaml").exists() {
+        Some("pnpm".to_string())
+    } else if project_path.join("yarn.lock").exists() {
+        Some("yarn".to_string())
+    } else if project_path.join("bun.lockb").exists() {
+        Some("bun".to_string())
+    } else if project_path.join("package-lock.json").exists() || project_path.join("package.json").exists() {
+        Some("npm".to_string()) // Default to npm if package.json exists
+    } else {
+        None
+    }
+}
+
+/// Broad project type (`flutter`, `android`, `python`, `rust`, `go`, `node` or `other`) used to pick build folders
+pub fn detect_project_type(project_path: &Path) -> String {
+    // Flutter
+    if project_path.join("pubspec.yaml").exists() {
+        return "flutter".to_string();
+    }
+    // Android
+    if (project_path.join("build.gradle").exists() || project_path.join("build.gradle.kts").exists())
+        && project_path.join("app").exists()
+    {
+        return "android".to_string();
+    }
+    // Python
+    if project_path.join("requirements.txt").exists()
+        || project_path.join("pyproject.toml").exists()
+        || project_path.join("manage.py").exists()
+    {
+        return "python".to_string();
+    }
+    // Rust
+    if project_path.join("Cargo.toml").exists() {
+        return "rust".to_string();
+    }
+    // Go
+    if project_path.join("go.mod").exists() {
+        return "go".to_string();
+    }
+    // Node.js (check last as it's most common)
+    if project_path.join("package.json").exists() {
+        return "node".to_string();
+    }
+    "other".to_string()
+}
+
+/// Frameworks, languages and tooling found in the project's manifests
+pub fn detect_tech_stack(project_path: &Path) -> Vec<TechStack> {
+    let mut stack: Vec<TechStack> = Vec::new();
+
+    // Check package.json for dependencies
+    let package_json_path = project_path.join("package.json");
+    if package_json_path.exists() {
+        if let Ok(content) = fs::read_to_string(&package_json_path) {
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                let deps = json.get("dependencies").cloned().unwrap_or(serde_json::Value::Null);
+                let dev_deps = json.get("devDependencies").cloned().unwrap_or(serde_json::Value::Null);
+
+                // Check for React Native first (before React)
+                if deps.get("react-native").is_some() || dev_deps.get("react-native").is_some() {
+                    stack.push(TechStack { name: "React Native".to_string(), tech_type: "blue".to_string() });
+                } else if deps.get("react").is_some() || dev_deps.get("react").is_some() {
+                    stack.push(TechStack { name: "React".to_string(), tech_type: "default".to_string() });
+                }
+                
+                // Check for Expo
+                if deps.get("expo").is_some() || dev_deps.get("expo").is_some() {
+                    stack.push(TechStack { name: "Expo".to_string(), tech_type: "default".to_string() });
+                }
+                
+                if deps.get("vue").is_some() || dev_deps.get("vue").is_some() {
+                    stack.push(TechStack { name: "Vue".to_string(), tech_type: "green".to_string() });
+                }
+                if deps.get("next").is_some() || dev_deps.get("next").is_some() {
+                    stack.push(TechStack { name: "Next.js".to_string(), tech_type: "default".to_string() });
+                }
+                if deps.get("nuxt").is_some() || dev_deps.get("nuxt").is_some() {
+                    stack.push(TechStack { name: "Nuxt".to_string(), tech_type: "green".to_string() });
+                }
+                if deps.get("svelte").is_some() || dev_deps.get("svelte").is_some() {
+                    stack.push(TechStack { name: "Svelte".to_string(), tech_type: "orange".to_string() });
+                }
+                if deps.get("express").is_some() {
+                    stack.push(TechStack { name: "Express".to_string(), tech_type: "default".to_string() });
+                }
+                if deps.get("fastify").is_some() {
+                    stack.push(TechStack { name: "Fastify".to_string(), tech_type: "default".to_string() });
+                }
+                if deps.get("@tauri-apps/api").is_some() || dev_deps.get("@tauri-apps/cli").is_some() {
+                    stack.push(TechStack { name: "Tauri".to_string(), tech_type: "yellow".to_string() });
+                }
+                if deps.get("electron").is_some() || dev_deps.get("electron").is_some() {
+                    stack.push(TechStack { name: "Electron".to_string(), tech_type: "blue".to_string() });
+                }
+                if dev_deps.get("vite").is_some() {
+                    stack.push(TechStack { name: "Vite".to_string(), tech_type: "purple".to_string() });
+                }
+                if dev_deps.get("typescript").is_some() {
+                    stack.push(TechStack { name: "TypeScript".to_string(), tech_type: "blue".to_string() });
+                }
+            }
+        }
+
+        // Add package manager
+        if let Some(pm) = detect_package_manager(project_path) {
+            let pm_type = match pm.as_str() {
+                "pnpm" => "blue",
+                "yarn" => "pink",
+                "bun" => "orange",
+                _ => "default",
+            };
+            stack.push(TechStack { name: pm, tech_type: pm_type.to_string() });
+        }
+    }
+
+    // Check for Flutter
+    if project_path.join("pubspec.yaml").exists() {
+        stack.push(TechStack { name: "Flutter".to_string(), tech_type: "blue".to_string() });
+        stack.push(TechStack { name: "Dart".to_string(), tech_type: "blue".to_string() });
+    }
+
+    // Check for Android (Kotlin/Compose)
+    if project_path.join("build.gradle.kts").exists() || project_path.join("build.gradle").exists() {
+        let gradle_path = if project_path.join("build.gradle.kts").exists() {
+            project_path.join("build.gradle.kts")
+        } else {
+            project_path.join("build.gradle")
+        };
+        
+        if let Ok(content) = fs::read_to_string(&gradle_path) {
+            if content.contains("com.android") || project_path.join("app/src/main/AndroidManifest.xml").exists() {
+                stack.push(TechStack { name: "Android".to_string(), tech_type: "green".to_string() });
+                
+                if content.contains("kotlin") || !project_path.join("app/src/main/java").exists() {
+                    stack.push(TechStack { name: "Kotlin".to_string(), tech_type: "purple".to_string() });
+                }
+                
+                if content.contains("compose") {
+                    stack.push(TechStack { name: "Compose".to_string(), tech_type: "green".to_string() });
+                }
+            }
+        }
+    }
+
+    // Check for Rust/Cargo
+    if project_path.join("Cargo.toml").exists() {
+        stack.push(TechStack { name: "Rust".to_string(), tech_type: "orange".to_string() });
+    }
+
+    // Check for Python frameworks
+    if project_path.join("requirements.txt").exists() || project_path.join("pyproject.toml").exists() {
+        stack.push(TechStack { name: "Python".to_string(), tech_type: "yellow".to_string() });
+        
+        // Check for specific Python frameworks
+        let req_path = project_path.join("requirements.txt");
+        let pyproject_path = project_path.join("pyproject.toml");
+        
+        let mut framework_content = String::new();
+        if let Ok(content) = fs::read_to_string(&req_path) {
+            framework_content.push_str(&content);
+        }
+        if let Ok(content) = fs::read_to_string(&pyproject_path) {
+            framework_content.push_str(&content);
+        }
+        
+        let framework_lower = framework_content.to_lowercase();
+        if framework_lower.contains("django") || project_path.join("manage.py").exists() {
+            stack.push(TechStack { name: "Django".to_string(), tech_type: "green".to_string() });
+        }
+        if framework_lower.contains("flask") {
+            stack.push(TechStack { name: "Flask".to_string(), tech_type: "default".to_string() });
+        }
+        if framework_lower.contains("fastapi") {
+            stack.push(TechStack { name: "FastAPI".to_string(), tech_type: "green".to_string() });
+        }
+    }
+
+    // Check for Go
+    if project_path.join("go.mod").exists() {
+        stack.push(TechStack { name: "Go".to_string(), tech_type: "blue".to_string() });
+    }
+
+    stack
+}
+
+/// Material icon name for the project, preferring mobile, desktop and backend stacks
+pub fn get_project_icon(tech_stack: &[TechStack], path: &Path) -> String {
+    // Determine icon based on tech stack or project type
+    // Mobile icons have higher priority
+    for tech in tech_stack {
+        match tech.name.as_str() {
+            "Flutter" | "React Native" | "Expo" => return "phone_iphone".to_string(),
+            "Android" | "Kotlin" | "Compose" => return "phone_android".to_string(),
+            _ => {}
+        }
+    }
+    
+    // Desktop apps
+    for tech in tech_stack {
+        match tech.name.as_str() {
+            "Tauri" | "Electron" => return "desktop_windows".to_string(),
+            _ => {}
+        }
+    }
+    
+    // Backend/API
+    for tech in tech_stack {
+        match tech.name.as_str() {
+            "Django" | "Flask" | "FastAPI" | "Express" | "Fastify" => return "dns".to_string(),
+            _ => {}
+        }
+    }
+    
+    // Other frameworks
+    for tech in tech_stack {
+        match tech.name.as_str() {
+            "React" | "Vue" | "Svelte" | "Next.js" | "Nuxt" => return "code".to_string(),
+            "Rust" => return "memory".to_string(),
+            "Python" => return "data_object".to_string(),
+            "Go" => return "speed".to_string(),
+            _ => {}
+        }
+    }
+
+    if path.join("pubspec.yaml").exists() {
+        "phone_iphone".to_string()
+    } else if path.join("Cargo.toml").exists() {
+        "memory".to_string()
+    } else if path.join("package.json").exists() {
+        "code".to_string()
+    } else if path.join("requirements.txt").exists() || path.join("manage.py").exists() {
+        "data_object".to_string()
+    } else {
+        "folder".to_string()
+    }
+}
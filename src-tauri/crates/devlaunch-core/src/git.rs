@@ -0,0 +1,231 @@
+//! Git inspection and sync. Read-only queries go through a [`GitBackend`], in-process when
+//! built with the `git2` feature; everything else runs the `git` executable.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::launch::silent_command;
+use crate::model::{CommitInfo, GitStatus, PullResult};
+
+mod activity;
+mod branches;
+mod bulk;
+mod cli;
+mod clone;
+mod diff;
+mod duplicates;
+mod lfs;
+mod remotes;
+mod stash;
+mod submodules;
+mod unpushed;
+mod upstream;
+mod worktree;
+#[cfg(feature = "git2")]
+mod native;
+
+pub use activity::{commit_activity, library_activity};
+pub use branches::{create_branch, default_branch, delete_merged_branches, has_local_changes, list_branches, switch_branch};
+pub use bulk::{run_bulk, BulkOp};
+pub use cli::CliBackend;
+pub use clone::{clone_folder_name, clone_repo};
+pub use diff::diff_summary;
+pub use duplicates::{clone_removal_blockers, find_duplicates};
+pub use lfs::lfs_pull;
+pub use remotes::remote_links;
+pub use stash::{apply_stash, create_stash, drop_stash, list_stashes};
+pub use submodules::update_submodules;
+pub use unpushed::unpushed_work;
+pub use upstream::fetch_tracked;
+pub use worktree::{add_worktree, list_worktrees, prune_worktrees, remove_worktree};
+#[cfg(feature = "git2")]
+pub use native::Git2Backend;
+
+/// Read-only repository queries
+pub trait GitBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+    /// Branch, upstream, ahead/behind and change counts. `status`, `type` and `operation`
+    /// are filled in by [`get_git_info`].
+    fn status(&self, repo: &Path) -> Result<GitStatus, String>;
+    /// The latest `limit` commits reachable from `rev` (HEAD when `None`), newest first
+    fn log(&self, repo: &Path, rev: Option<&str>, limit: usize) -> Result<Vec<CommitInfo>, String>;
+}
+
+/// The fastest backend this build has
+pub fn default_backend() -> &'static dyn GitBackend {
+    #[cfg(feature = "git2")]
+    let backend: &'static dyn GitBackend = &Git2Backend;
+    #[cfg(not(feature = "git2"))]
+    let backend: &'static dyn GitBackend = &CliBackend;
+    backend
+}
+
+// The in-process backend doesn't cover every repository layout or extension, so any
+// failure there is retried with the git executable
+fn with_fallback<T>(query: impl Fn(&dyn GitBackend) -> Result<T, String>) -> Result<T, String> {
+    let backend = default_backend();
+    match query(backend) {
+        Err(_) if backend.name() != CliBackend.name() => query(&CliBackend),
+        result => result,
+    }
+}
+
+/// Branch, upstream and working tree state, or a "No Git" placeholder outside a repository
+pub fn get_git_info(project_path: &Path) -> GitStatus {
+    let git_dir = match resolve_git_dir(project_path) {
+        Some(dir) => dir,
+        None => {
+            return GitStatus {
+                branch: "No Git".to_string(),
+                status: "N/A".to_string(),
+                status_type: "neutral".to_string(),
+                ..Default::default()
+            }
+        }
+    };
+
+    let mut status = match with_fallback(|backend| backend.status(project_path)) {
+        Ok(status) => status,
+        Err(_) => {
+            return GitStatus {
+                branch: "unknown".to_string(),
+                status: "Unknown".to_string(),
+                status_type: "neutral".to_string(),
+                ..Default::default()
+            }
+        }
+    };
+
+    status.operation = operation_in_progress(&git_dir);
+    status.worktree_of = worktree::main_worktree(&git_dir).map(|main| main.to_string_lossy().to_string());
+    status.submodules = submodules::submodule_status(project_path);
+    status.lfs = lfs::lfs_status(project_path);
+    // A rebase detaches HEAD; the branch being rebased is recorded next to the todo list
+    if status.operation.as_deref() == Some("rebase") {
+        let head_name = ["rebase-merge", "rebase-apply"]
+            .iter()
+            .find_map(|dir| fs::read_to_string(git_dir.join(dir).join("head-name")).ok());
+        if let Some(name) = head_name {
+            status.branch = name.trim().trim_start_matches("refs/heads/").to_string();
+        }
+    }
+
+    summarize(&mut status);
+    status
+}
+
+/// The repository's git directory; `.git` is a file pointing elsewhere in worktrees and submodules
+fn resolve_git_dir(project_path: &Path) -> Option<PathBuf> {
+    let dot_git = project_path.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    let content = fs::read_to_string(&dot_git).ok()?;
+    let target = content.strip_prefix("gitdir:")?.trim();
+    Some(project_path.join(target))
+}
+
+fn operation_in_progress(git_dir: &Path) -> Option<String> {
+    let markers = [
+        ("rebase-merge", "rebase"),
+        ("rebase-apply", "rebase"),
+        ("MERGE_HEAD", "merge"),
+        ("CHERRY_PICK_HEAD", "cherry-pick"),
+        ("REVERT_HEAD", "revert"),
+    ];
+    markers
+        .iter()
+        .find(|(marker, _)| git_dir.join(marker).exists())
+        .map(|(_, operation)| operation.to_string())
+}
+
+/// Submodules that aren't checked out at their recorded commits, or LFS files not downloaded
+fn needs_sync(status: &GitStatus) -> bool {
+    let submodules = status.submodules.as_ref().is_some_and(|s| s.uninitialized + s.out_of_date > 0);
+    let lfs = status.lfs.as_ref().is_some_and(|l| l.missing > 0);
+    submodules || lfs
+}
+
+/// Fills in the one-word summary and its color
+fn summarize(status: &mut GitStatus) {
+    let ahead = status.ahead.unwrap_or(0);
+    let behind = status.behind.unwrap_or(0);
+    let (summary, status_type) = match status.operation.as_deref() {
+        Some("rebase") => ("Rebasing", "error"),
+        Some("merge") => ("Merging", "error"),
+        Some("cherry-pick") => ("Cherry-picking", "error"),
+        Some(_) => ("Reverting", "error"),
+        None if status.conflicted > 0 => ("Conflicted", "error"),
+        None if status.count.is_some() => ("Modified", "warning"),
+        None if needs_sync(status) => ("Out of sync", "warning"),
+        None if ahead > 0 && behind > 0 => ("Diverged", "warning"),
+        None if behind > 0 => ("Behind", "warning"),
+        None if ahead > 0 => ("Ahead", "info"),
+        None if status.detached => ("Detached", "neutral"),
+        None => ("Clean", "success"),
+    };
+    status.status = summary.to_string();
+    status.status_type = status_type.to_string();
+}
+
+/// The latest `limit` commits of the repository at `path`, from HEAD or from branch or ref `rev`
+pub fn recent_commits(path: &Path, rev: Option<&str>, limit: usize) -> Result<Vec<CommitInfo>, String> {
+    with_fallback(|backend| backend.log(path, rev, limit))
+}
+
+/// Runs `git pull` in `path`. With `autostash`, uncommitted changes are stashed first and
+/// re-applied afterwards; conflicts from either step are reported rather than failing.
+pub fn pull_repo(path: &str, autostash: bool) -> Result<PullResult, String> {
+    let repo = Path::new(path);
+    let mut result = PullResult::default();
+    if autostash && has_local_changes(repo) {
+        cli::git_output(repo, &["stash", "push", "-m", "devlaunch: autostash before pull"])
+            .map_err(|e| format!("Failed to stash changes: {}", e))?;
+        result.stashed = true;
+    }
+
+    let output = silent_command("git")
+        .args(["pull"])
+        .current_dir(path)
+        .output()
+        .map_err(|e| format!("Failed to run git pull: {}", e))?;
+
+    if !output.status.success() {
+        let conflicts = stash::conflicted_files(repo);
+        if conflicts.is_empty() {
+            // Nothing was merged, so put the changes back where they were
+            if result.stashed {
+                let _ = apply_stash(repo, 0, true);
+            }
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Git pull failed: {}", stderr));
+        }
+        // The stash stays put until the merge is resolved
+        result.message = String::from_utf8_lossy(&output.stdout).to_string();
+        result.conflicts = conflicts;
+        return Ok(result);
+    }
+
+    result.message = String::from_utf8_lossy(&output.stdout).to_string();
+    if result.stashed {
+        result.conflicts = apply_stash(repo, 0, true)?;
+        result.restored = result.conflicts.is_empty();
+    }
+    Ok(result)
+}
+
+/// Runs `git fetch --all` in `path`
+pub fn fetch_repo(path: &str) -> Result<String, String> {
+    let output = silent_command("git")
+        .args(["fetch", "--all"])
+        .current_dir(path)
+        .output()
+        .map_err(|e| format!("Failed to run git fetch: {}", e))?;
+
+    if output.status.success() {
+        Ok("Fetch completed successfully".to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("Git fetch failed: {}", stderr))
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::cli::{git_output, parse_commit};
+use super::resolve_git_dir;
+use crate::model::{ActivityCount, AuthorActivity, CommitActivity, CommitInfo, FileActivity, LibraryActivity};
+use crate::store;
+
+/// Most changed files kept per repository and in library-wide totals
+const TOP_FILES: usize = 20;
+
+/// Everything the statistics depend on; any change means counting again
+#[derive(Serialize, Deserialize, PartialEq)]
+struct CacheKey {
+    head: String,
+    days: u32,
+    today: String,
+    user: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    key: CacheKey,
+    activity: CommitActivity,
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` gives the same value on every Rust release,
+/// so cache files keep their names across toolchain updates
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+fn cache_file(path: &Path) -> PathBuf {
+    let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let hash = fnv1a(canonical.to_string_lossy().as_bytes());
+    store::cache_dir().join("activity").join(format!("{:016x}.json", hash))
+}
+
+/// Commit statistics of the last `days` days (today included) of HEAD's history, from
+/// `git log --numstat`. Results are cached on disk until HEAD, the day or `user.email` changes.
+pub fn commit_activity(path: &Path, days: u32) -> Result<CommitActivity, String> {
+    if resolve_git_dir(path).is_none() {
+        return Err(format!("{} is not a git repository", path.display()));
+    }
+    let days = days.clamp(1, 3660);
+    let today = Local::now().date_naive();
+    let Ok(head) = git_output(path, &["rev-parse", "--verify", "--quiet", "HEAD"]) else {
+        // No commits yet
+        return Ok(empty_activity(&path.to_string_lossy(), days, today));
+    };
+    let user = git_output(path, &["config", "user.email"])
+        .map(|email| email.trim().to_string())
+        .ok()
+        .filter(|email| !email.is_empty());
+    let key = CacheKey { head: head.trim().to_string(), days, today: today.to_string(), user };
+
+    let file = cache_file(path);
+    let cached = fs::read_to_string(&file).ok().and_then(|c| serde_json::from_str::<CacheEntry>(&c).ok());
+    if let Some(entry) = cached.filter(|entry| entry.key == key) {
+        return Ok(CommitActivity { path: path.to_string_lossy().to_string(), ..entry.activity });
+    }
+
+    let activity = count_activity(path, days, today, key.user.as_deref())?;
+    // A cache that can't be written only costs the next view a recount
+    if let Some(dir) = file.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(&CacheEntry { key, activity: activity.clone() }) {
+        let _ = fs::write(&file, json);
+    }
+    Ok(activity)
+}
+
+fn monday(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+fn empty_activity(path: &str, days: u32, today: NaiveDate) -> CommitActivity {
+    let start = today - Duration::days(days as i64 - 1);
+    let daily = start
+        .iter_days()
+        .take(days as usize)
+        .map(|date| ActivityCount { date: date.to_string(), commits: 0 })
+        .collect();
+    let weekly = monday(start)
+        .iter_weeks()
+        .take_while(|week| *week <= today)
+        .map(|week| ActivityCount { date: week.to_string(), commits: 0 })
+        .collect();
+    CommitActivity { path: path.to_string(), days, daily, weekly, ..Default::default() }
+}
+
+/// Adds commits on `date` to the day and week it falls in, when it is inside the window
+/// starting on `start`
+fn count_day(activity: &mut CommitActivity, start: NaiveDate, date: NaiveDate, commits: u32) -> bool {
+    let Some(day) = usize::try_from((date - start).num_days()).ok().and_then(|i| activity.daily.get_mut(i)) else {
+        return false;
+    };
+    day.commits += commits;
+    let week = (monday(date) - monday(start)).num_days() as usize / 7;
+    if let Some(week) = activity.weekly.get_mut(week) {
+        week.commits += commits;
+    }
+    true
+}
+
+fn count_activity(path: &Path, days: u32, today: NaiveDate, user: Option<&str>) -> Result<CommitActivity, String> {
+    let mut activity = empty_activity(&path.to_string_lossy(), days, today);
+    let start = today - Duration::days(days as i64 - 1);
+    let since = format!("--since={}T00:00:00", start);
+    // Renames count as a deletion and an addition, so numstat paths stay plain
+    let output = git_output(
+        path,
+        &["log", &since, "--no-renames", "--format=%x1e%H%x1f%an%x1f%ae%x1f%aI", "--numstat", "HEAD", "--"],
+    )?;
+
+    let mut authors: HashMap<String, AuthorActivity> = HashMap::new();
+    let mut files: HashMap<String, FileActivity> = HashMap::new();
+    for record in output.split('\x1e').filter(|r| !r.trim().is_empty()) {
+        let (header, numstat) = record.split_once('\n').unwrap_or((record, ""));
+        let fields: Vec<&str> = header.split('\x1f').collect();
+        let [_, name, email, date] = fields[..] else { continue };
+        // --since goes by committer date, the window by author date in the author's time zone
+        let Ok(date) = DateTime::parse_from_rfc3339(date) else { continue };
+        if !count_day(&mut activity, start, date.date_naive(), 1) {
+            continue;
+        }
+
+        let author = authors.entry(email.to_lowercase()).or_insert_with(|| AuthorActivity {
+            name: name.to_string(),
+            email: email.to_string(),
+            commits: 0,
+            insertions: 0,
+            deletions: 0,
+        });
+        author.commits += 1;
+        activity.commits += 1;
+
+        for line in numstat.lines() {
+            let mut parts = line.splitn(3, '\t');
+            let (Some(added), Some(removed), Some(file)) = (parts.next(), parts.next(), parts.next()) else {
+                continue;
+            };
+            // Binary files show `-` for both counts
+            let (added, removed) = (added.parse().unwrap_or(0), removed.parse().unwrap_or(0));
+            author.insertions += added;
+            author.deletions += removed;
+            activity.insertions += added;
+            activity.deletions += removed;
+            let file = unquote_path(file);
+            let entry = files.entry(file.clone()).or_insert_with(|| FileActivity {
+                path: file,
+                commits: 0,
+                insertions: 0,
+                deletions: 0,
+            });
+            entry.commits += 1;
+            entry.insertions += added;
+            entry.deletions += removed;
+        }
+    }
+
+    activity.authors = sorted_authors(authors.into_values().collect());
+    activity.files = top_files(files.into_values().collect());
+    activity.last_own_commit = user.and_then(|email| last_commit_by(path, email));
+    Ok(activity)
+}
+
+fn last_commit_by(path: &Path, email: &str) -> Option<CommitInfo> {
+    let author = format!("--author=<{}>", email);
+    let output = git_output(
+        path,
+        &[
+            "log",
+            "-1",
+            "--fixed-strings",
+            &author,
+            "--format=%x1e%H%x1f%an%x1f%ae%x1f%aI%x1f%s",
+            "--shortstat",
+            "HEAD",
+            "--",
+        ],
+    )
+    .ok()?;
+    output.split('\x1e').find_map(parse_commit)
+}
+
+/// Paths with unusual characters come quoted and escaped, like `"caf\303\251.txt"`
+fn unquote_path(path: &str) -> String {
+    let Some(inner) = path.strip_prefix('"').and_then(|p| p.strip_suffix('"')) else {
+        return path.to_string();
+    };
+    let mut bytes = Vec::new();
+    let mut chars = inner.bytes().peekable();
+    while let Some(byte) = chars.next() {
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+        match chars.next() {
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(digit @ b'0'..=b'7') => {
+                let mut value = (digit - b'0') as u32;
+                for _ in 0..2 {
+                    if let Some(next @ b'0'..=b'7') = chars.peek().copied() {
+                        value = value * 8 + (next - b'0') as u32;
+                        chars.next();
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => bytes.push(other),
+            None => {}
+        }
+    }
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+fn sorted_authors(mut authors: Vec<AuthorActivity>) -> Vec<AuthorActivity> {
+    authors.sort_by(|a, b| b.commits.cmp(&a.commits).then_with(|| a.name.cmp(&b.name)));
+    authors
+}
+
+fn top_files(mut files: Vec<FileActivity>) -> Vec<FileActivity> {
+    files.sort_by(|a, b| {
+        b.commits
+            .cmp(&a.commits)
+            .then_with(|| (b.insertions + b.deletions).cmp(&(a.insertions + a.deletions)))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    files.truncate(TOP_FILES);
+    files
+}
+
+/// [`commit_activity`] for every repository in `paths`, a few at a time, and their sum.
+/// Repositories that can't be read are left out.
+pub fn library_activity(paths: &[String], days: u32) -> LibraryActivity {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..paths.len().clamp(1, 4) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(path) = paths.get(index) else { break };
+                if let Ok(activity) = commit_activity(Path::new(path), days) {
+                    results.lock().unwrap().push(activity);
+                }
+            });
+        }
+    });
+    let mut projects = results.into_inner().unwrap();
+    projects.sort_by(|a, b| b.commits.cmp(&a.commits).then_with(|| a.path.cmp(&b.path)));
+
+    let days = days.clamp(1, 3660);
+    let today = Local::now().date_naive();
+    let start = today - Duration::days(days as i64 - 1);
+    let mut total = empty_activity("", days, today);
+    let mut authors: HashMap<String, AuthorActivity> = HashMap::new();
+    let mut files = Vec::new();
+    for project in &projects {
+        total.commits += project.commits;
+        total.insertions += project.insertions;
+        total.deletions += project.deletions;
+        for day in &project.daily {
+            if let Ok(date) = day.date.parse() {
+                count_day(&mut total, start, date, day.commits);
+            }
+        }
+        for author in &project.authors {
+            let entry = authors.entry(author.email.to_lowercase()).or_insert_with(|| AuthorActivity {
+                commits: 0,
+                insertions: 0,
+                deletions: 0,
+                ..author.clone()
+            });
+            entry.commits += author.commits;
+            entry.insertions += author.insertions;
+            entry.deletions += author.deletions;
+        }
+        let folder = Path::new(&project.path).file_name().unwrap_or_default().to_string_lossy();
+        files.extend(project.files.iter().map(|file| FileActivity {
+            path: format!("{}/{}", folder, file.path),
+            ..file.clone()
+        }));
+        let newer = match (&project.last_own_commit, &total.last_own_commit) {
+            (Some(commit), Some(latest)) => {
+                DateTime::parse_from_rfc3339(&commit.date).ok() > DateTime::parse_from_rfc3339(&latest.date).ok()
+            }
+            (Some(_), None) => true,
+            _ => false,
+        };
+        if newer {
+            total.last_own_commit = project.last_own_commit.clone();
+        }
+    }
+    total.authors = sorted_authors(authors.into_values().collect());
+    total.files = top_files(files);
+
+    LibraryActivity { total, projects }
+}
@@ -0,0 +1,180 @@
+use std::path::Path;
+
+use super::cli::git_output;
+use super::with_fallback;
+use crate::model::BranchInfo;
+
+fn ref_exists(repo: &Path, refname: &str) -> bool {
+    git_output(repo, &["rev-parse", "--verify", "--quiet", refname]).is_ok()
+}
+
+fn current_branch(repo: &Path) -> Option<String> {
+    git_output(repo, &["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .ok()
+        .map(|name| name.trim().to_string())
+}
+
+/// The current branch, other local branches, then remote-tracking ones, each group most
+/// recently committed first
+pub fn list_branches(path: &Path) -> Result<Vec<BranchInfo>, String> {
+    let output = git_output(
+        path,
+        &[
+            "for-each-ref",
+            "--sort=refname",
+            "--sort=-committerdate",
+            "--format=%(refname)%1f%(refname:short)%1f%(objectname:short)%1f%(committerdate:iso-strict)%1f%(upstream:short)%1f%(upstream:track,nobracket)%1f%(HEAD)%1f%(subject)",
+            "refs/heads",
+            "refs/remotes",
+        ],
+    )?;
+
+    let mut branches: Vec<BranchInfo> = output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(8, '\x1f');
+            let refname = fields.next()?;
+            // `origin/HEAD` only points at the remote's default branch
+            if refname.ends_with("/HEAD") {
+                return None;
+            }
+            let name = fields.next()?.to_string();
+            let commit = fields.next()?.to_string();
+            let last_commit_date = fields.next()?.to_string();
+            let upstream = Some(fields.next()?).filter(|u| !u.is_empty()).map(str::to_string);
+            let (ahead, behind) = parse_track(fields.next()?);
+            Some(BranchInfo {
+                is_remote: refname.starts_with("refs/remotes/"),
+                is_current: fields.next()? == "*",
+                summary: fields.next().unwrap_or_default().to_string(),
+                name,
+                commit,
+                last_commit_date,
+                upstream,
+                ahead,
+                behind,
+            })
+        })
+        .collect();
+    branches.sort_by_key(|branch| (branch.is_remote, !branch.is_current));
+    Ok(branches)
+}
+
+/// Parses `%(upstream:track,nobracket)`: "ahead 2, behind 1", "gone" or empty
+fn parse_track(track: &str) -> (u32, u32) {
+    let mut counts = (0, 0);
+    for part in track.split(", ") {
+        match part.split_once(' ') {
+            Some(("ahead", n)) => counts.0 = n.parse().unwrap_or(0),
+            Some(("behind", n)) => counts.1 = n.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+    counts
+}
+
+/// Whether tracked files have uncommitted changes. Untracked files don't count: checking out
+/// another branch leaves them alone.
+pub fn has_local_changes(path: &Path) -> bool {
+    with_fallback(|backend| backend.status(path))
+        .map(|status| status.staged + status.unstaged + status.conflicted > 0)
+        .unwrap_or(false)
+}
+
+/// Checks out `name`, a local branch or a remote-tracking one such as `origin/feature`, which
+/// gets a local tracking branch. A dirty tree is refused unless `stash` is set, in which case
+/// the changes are stashed first.
+pub fn switch_branch(path: &Path, name: &str, stash: bool) -> Result<String, String> {
+    // Anything starting with a dash would be read as an option
+    if name.is_empty() || name.starts_with('-') {
+        return Err(format!("Invalid branch name: {}", name));
+    }
+    let mut stashed = false;
+    if has_local_changes(path) {
+        if !stash {
+            return Err("Uncommitted changes; commit or stash them before switching branches".to_string());
+        }
+        git_output(path, &["stash", "push", "-m", &format!("devlaunch: switch to {}", name)])
+            .map_err(|e| format!("Failed to stash changes: {}", e))?;
+        stashed = true;
+    }
+
+    let remote_ref = format!("refs/remotes/{}", name);
+    let result = if !ref_exists(path, &format!("refs/heads/{}", name)) && ref_exists(path, &remote_ref) {
+        git_output(path, &["switch", "--track", name])
+    } else {
+        git_output(path, &["switch", name])
+    };
+    if let Err(e) = result {
+        // Put the changes back where they were; only a conflicting pop leaves them stashed
+        if stashed && git_output(path, &["stash", "pop"]).is_err() {
+            return Err(format!("Failed to switch to {}: {} (your changes are kept in the stash)", name, e));
+        }
+        return Err(format!("Failed to switch to {}: {}", name, e));
+    }
+
+    let branch = current_branch(path).unwrap_or_else(|| name.to_string());
+    Ok(if stashed {
+        format!("Switched to {} (changes stashed)", branch)
+    } else {
+        format!("Switched to {}", branch)
+    })
+}
+
+/// Creates branch `name` at `start_point` (any ref or commit, HEAD when `None`) and
+/// optionally checks it out
+pub fn create_branch(path: &Path, name: &str, start_point: Option<&str>, switch: bool) -> Result<String, String> {
+    git_output(path, &["check-ref-format", "--branch", name])
+        .map_err(|_| format!("\"{}\" is not a valid branch name", name))?;
+    if let Some(start) = start_point.filter(|s| s.starts_with('-')) {
+        return Err(format!("Invalid revision: {}", start));
+    }
+
+    let mut args = if switch { vec!["switch", "-c", name] } else { vec!["branch", name] };
+    args.extend(start_point.filter(|s| !s.is_empty()));
+    git_output(path, &args).map_err(|e| format!("Failed to create branch {}: {}", name, e))?;
+
+    Ok(format!("Created {}", name))
+}
+
+/// The branch the remote's HEAD points at, falling back to a local `main` or `master`
+pub fn default_branch(path: &Path) -> Option<String> {
+    if let Ok(remote_head) = git_output(path, &["symbolic-ref", "--quiet", "--short", "refs/remotes/origin/HEAD"]) {
+        if let Some(name) = remote_head.trim().strip_prefix("origin/") {
+            return Some(name.to_string());
+        }
+    }
+    ["main", "master"]
+        .into_iter()
+        .find(|name| ref_exists(path, &format!("refs/heads/{}", name)))
+        .map(str::to_string)
+}
+
+/// Deletes local branches fully merged into the default branch, except the default and the
+/// current one, and returns their names. With `dry_run` nothing is deleted.
+pub fn delete_merged_branches(path: &Path, dry_run: bool) -> Result<Vec<String>, String> {
+    let default = default_branch(path).ok_or("Could not determine the default branch")?;
+    // Compare against the remote's copy when the default branch isn't checked out locally
+    let base = if ref_exists(path, &format!("refs/heads/{}", default)) {
+        default.clone()
+    } else {
+        format!("origin/{}", default)
+    };
+    let current = current_branch(path);
+
+    let merged = git_output(path, &["for-each-ref", "--merged", &base, "--format=%(refname:short)", "refs/heads"])?;
+    let branches: Vec<String> = merged
+        .lines()
+        .filter(|name| !name.is_empty() && *name != default && Some(*name) != current.as_deref())
+        .map(str::to_string)
+        .collect();
+
+    if !dry_run {
+        for branch in &branches {
+            // `-d` checks against HEAD or the upstream, not the default branch, so it would
+            // refuse branches merged there; mergedness was checked above
+            git_output(path, &["branch", "-D", branch]).map_err(|e| format!("Failed to delete {}: {}", branch, e))?;
+        }
+    }
+    Ok(branches)
+}
@@ -0,0 +1,90 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+
+use super::cli::git_output;
+use super::{fetch_repo, get_git_info, has_local_changes, resolve_git_dir};
+use crate::model::BulkGitResult;
+
+/// An operation that can run across many repositories at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkOp {
+    Fetch,
+    /// Fast-forward only; repositories with uncommitted changes are skipped
+    Pull,
+    Status,
+}
+
+/// Runs `op` on every path with at most `concurrency` running at a time. `on_result` is
+/// called as each repository finishes; the returned results keep the order of `paths`.
+pub fn run_bulk(
+    op: BulkOp,
+    paths: &[String],
+    concurrency: usize,
+    on_result: impl Fn(&BulkGitResult) + Sync,
+) -> Vec<BulkGitResult> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; paths.len()]);
+    let workers = concurrency.clamp(1, paths.len().max(1));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(path) = paths.get(index) else { break };
+                let result = run_one(op, path);
+                on_result(&result);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results.into_inner().unwrap().into_iter().flatten().collect()
+}
+
+fn run_one(op: BulkOp, path: &str) -> BulkGitResult {
+    let repo = Path::new(path);
+    let mut result = BulkGitResult {
+        path: path.to_string(),
+        ok: false,
+        skipped: false,
+        message: String::new(),
+        status: None,
+    };
+    if resolve_git_dir(repo).is_none() {
+        result.message = "Not a git repository".to_string();
+        return result;
+    }
+
+    let outcome = match op {
+        BulkOp::Fetch => fetch_repo(path),
+        BulkOp::Pull if has_local_changes(repo) => {
+            result.skipped = true;
+            Err("Skipped: uncommitted changes".to_string())
+        }
+        BulkOp::Pull => git_output(repo, &["pull", "--ff-only"]).map(|output| {
+            if output.contains("Already up to date") {
+                "Already up to date".to_string()
+            } else {
+                "Fast-forwarded".to_string()
+            }
+        }),
+        BulkOp::Status => Ok(String::new()),
+    };
+
+    // Every operation reports where the repository stands afterwards
+    let status = get_git_info(repo);
+    match outcome {
+        Ok(message) => {
+            result.ok = true;
+            result.message = if message.is_empty() { status.status.clone() } else { message };
+        }
+        Err(e) => result.message = e,
+    }
+    result.status = Some(status);
+    result
+}
@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use super::GitBackend;
+use crate::launch::silent_command;
+use crate::model::{CommitInfo, GitStatus};
+
+/// Answers queries by running the `git` executable
+pub struct CliBackend;
+
+pub(super) fn git_output(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let output = silent_command("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(format!("git {} failed: {}", args[0], String::from_utf8_lossy(&output.stderr).trim()))
+    }
+}
+
+impl GitBackend for CliBackend {
+    fn name(&self) -> &'static str {
+        "cli"
+    }
+
+    fn status(&self, repo: &Path) -> Result<GitStatus, String> {
+        git_output(repo, &["status", "--porcelain=v2", "--branch"]).map(|output| parse_status(&output))
+    }
+
+    fn log(&self, repo: &Path, rev: Option<&str>, limit: usize) -> Result<Vec<CommitInfo>, String> {
+        // Record and unit separators keep summaries with spaces, tabs or blank lines intact
+        let count = format!("-{}", limit);
+        let mut args = vec!["log", &count, "--format=%x1e%H%x1f%an%x1f%ae%x1f%aI%x1f%s", "--shortstat"];
+        if let Some(rev) = rev {
+            if rev.starts_with('-') {
+                return Err(format!("Invalid revision: {}", rev));
+            }
+            args.push(rev);
+        }
+        args.push("--");
+        let output = match git_output(repo, &args) {
+            Ok(output) => output,
+            // A repository without commits has no log rather than a broken one
+            Err(e) if e.contains("does not have any commits") => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        Ok(output.split('\x1e').filter_map(parse_commit).collect())
+    }
+}
+
+/// One commit of the log: the formatted fields, then its `--shortstat` line if it changed anything
+pub(super) fn parse_commit(record: &str) -> Option<CommitInfo> {
+    let (header, stat) = record.split_once('\n').unwrap_or((record, ""));
+    let mut fields = header.splitn(5, '\x1f');
+    let id = fields.next()?.to_string();
+    let mut commit = CommitInfo {
+        short_id: id.chars().take(7).collect(),
+        id,
+        author: fields.next()?.to_string(),
+        author_email: fields.next()?.to_string(),
+        date: fields.next()?.to_string(),
+        summary: fields.next()?.to_string(),
+        files_changed: 0,
+        insertions: 0,
+        deletions: 0,
+    };
+    // " 2 files changed, 5 insertions(+), 1 deletion(-)"
+    for part in stat.trim().split(", ") {
+        let Some((count, what)) = part.split_once(' ') else { continue };
+        let count = count.parse().unwrap_or(0);
+        if what.starts_with("file") {
+            commit.files_changed = count;
+        } else if what.starts_with("insertion") {
+            commit.insertions = count;
+        } else if what.starts_with("deletion") {
+            commit.deletions = count;
+        }
+    }
+    Some(commit)
+}
+
+/// Parses `git status --porcelain=v2 --branch` into branch, upstream and change counts
+fn parse_status(output: &str) -> GitStatus {
+    let mut status = GitStatus::default();
+    let mut oid = String::new();
+    // A file can be both staged and modified again, so count entries rather than adding up
+    let mut changed = 0;
+
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("# ") {
+            let (key, value) = header.split_once(' ').unwrap_or((header, ""));
+            match key {
+                "branch.oid" => oid = value.to_string(),
+                "branch.head" if value == "(detached)" => status.detached = true,
+                "branch.head" => status.branch = value.to_string(),
+                "branch.upstream" => status.upstream = Some(value.to_string()),
+                "branch.ab" => {
+                    // "+<ahead> -<behind>", only present when the upstream branch exists
+                    let mut counts = value.split(' ').map(|n| n.trim_start_matches(['+', '-']).parse().ok());
+                    status.ahead = counts.next().flatten();
+                    status.behind = counts.next().flatten();
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let mut fields = line.splitn(3, ' ');
+        let kind = fields.next();
+        if matches!(kind, Some("1" | "2" | "u" | "?")) {
+            changed += 1;
+        }
+        match (kind, fields.next()) {
+            (Some("1") | Some("2"), Some(xy)) => {
+                let mut xy = xy.chars();
+                if xy.next().is_some_and(|x| x != '.') {
+                    status.staged += 1;
+                }
+                if xy.next().is_some_and(|y| y != '.') {
+                    status.unstaged += 1;
+                }
+            }
+            (Some("u"), _) => status.conflicted += 1,
+            (Some("?"), _) => status.untracked += 1,
+            _ => {}
+        }
+    }
+
+    if status.detached {
+        status.branch = oid.chars().take(7).collect();
+    }
+    status.count = (changed > 0).then_some(changed);
+    status
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use crate::launch::silent_command;
+use crate::model::{CloneOptions, CloneProgress};
+
+/// Folder name git would pick for `url`: its last path segment without `.git`
+pub fn clone_folder_name(url: &str) -> Option<String> {
+    let trimmed = url.trim().trim_end_matches('/');
+    let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed).trim_end_matches('/');
+    let name = trimmed.rsplit(['/', '\\', ':']).next()?;
+    valid_folder_name(name).then(|| name.to_string())
+}
+
+fn valid_folder_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\'])
+}
+
+/// `Receiving objects:  45% (450/1000), 1.2 MiB | 2.0 MiB/s` as a phase and a percentage
+fn parse_progress(line: &str) -> CloneProgress {
+    let (phase, rest) = line.split_once(':').unwrap_or((line, ""));
+    let percent = rest
+        .split_whitespace()
+        .next()
+        .and_then(|word| word.strip_suffix('%'))
+        .and_then(|n| n.parse().ok());
+    CloneProgress {
+        phase: if percent.is_some() { phase.trim().to_string() } else { line.to_string() },
+        percent,
+    }
+}
+
+/// Clones `url` into a new folder under `parent`, reporting `git clone --progress` output as
+/// it arrives, and returns the folder. A failed clone leaves nothing behind.
+pub fn clone_repo(
+    url: &str,
+    parent: &Path,
+    options: &CloneOptions,
+    mut on_progress: impl FnMut(CloneProgress),
+) -> Result<PathBuf, String> {
+    let url = url.trim();
+    // Anything starting with a dash would be read as an option
+    if url.is_empty() || url.starts_with('-') {
+        return Err(format!("Invalid repository URL: {}", url));
+    }
+    if !parent.is_dir() {
+        return Err(format!("{} is not a directory", parent.display()));
+    }
+    let name = match options.directory.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        Some(name) if valid_folder_name(name) => name.to_string(),
+        Some(name) => return Err(format!("Invalid folder name: {}", name)),
+        None => clone_folder_name(url).ok_or_else(|| format!("Cannot name a folder after {}", url))?,
+    };
+    let target = parent.join(&name);
+    if target.exists() {
+        return Err(format!("{} already exists", target.display()));
+    }
+
+    let mut args = vec!["clone".to_string(), "--progress".to_string()];
+    if let Some(branch) = options.branch.as_deref().map(str::trim).filter(|b| !b.is_empty()) {
+        if branch.starts_with('-') {
+            return Err(format!("Invalid branch name: {}", branch));
+        }
+        args.extend(["--branch".to_string(), branch.to_string()]);
+    }
+    if let Some(depth) = options.depth.filter(|d| *d > 0) {
+        args.push(format!("--depth={}", depth));
+    }
+    if options.recursive {
+        args.push("--recurse-submodules".to_string());
+        if options.depth.is_some_and(|d| d > 0) {
+            args.push("--shallow-submodules".to_string());
+        }
+    }
+    args.extend(["--".to_string(), url.to_string(), name]);
+
+    let mut child = silent_command("git")
+        .args(&args)
+        .current_dir(parent)
+        // Credentials prompts would hang with no terminal to answer them
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    // Progress lines are redrawn with carriage returns, messages end with newlines
+    let mut messages: Vec<String> = Vec::new();
+    if let Some(mut stderr) = child.stderr.take() {
+        let mut line = Vec::new();
+        let mut buf = [0u8; 4096];
+        while let Ok(n) = stderr.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            for &byte in &buf[..n] {
+                if byte != b'\r' && byte != b'\n' {
+                    line.push(byte);
+                    continue;
+                }
+                let text = String::from_utf8_lossy(&line).trim().to_string();
+                line.clear();
+                if text.is_empty() {
+                    continue;
+                }
+                let text = text.strip_prefix("remote: ").unwrap_or(&text).to_string();
+                on_progress(parse_progress(&text));
+                if byte == b'\n' {
+                    messages.push(text);
+                }
+            }
+        }
+    }
+
+    let status = child.wait().map_err(|e| format!("Failed to run git: {}", e))?;
+    if !status.success() {
+        if target.exists() {
+            let _ = fs::remove_dir_all(&target);
+        }
+        let reason = messages
+            .iter()
+            .rev()
+            .find(|m| m.starts_with("fatal:") || m.starts_with("error:"))
+            .or(messages.last())
+            .map(|m| m.trim_start_matches("fatal: ").trim_start_matches("error: ").to_string())
+            .unwrap_or_else(|| format!("git exited with {}", status));
+        return Err(format!("Failed to clone {}: {}", url, reason));
+    }
+    Ok(target)
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::cli::git_output;
+use crate::model::{DiffSummary, FileChange};
+
+// Untracked files larger than this aren't read to count their lines
+const MAX_COUNTED_SIZE: u64 = 1024 * 1024;
+
+/// Every uncommitted change in the repository at `path`, with line counts against HEAD
+pub fn diff_summary(path: &Path) -> Result<DiffSummary, String> {
+    let status = git_output(path, &["status", "--porcelain=v2", "-z"])?;
+    let mut files = parse_entries(&status);
+
+    // The empty tree stands in for HEAD before the first commit
+    let base = if git_output(path, &["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok() {
+        "HEAD"
+    } else {
+        "4b825dc642cb6eb9a060e54bf8d69288fbee4904"
+    };
+    let numstat = git_output(path, &["diff", base, "--numstat", "-z", "-M", "--"])?;
+    let counts = parse_numstat(&numstat);
+
+    let mut summary = DiffSummary::default();
+    for file in &mut files {
+        let (insertions, deletions) = match file.status.as_str() {
+            "untracked" => (count_lines(&path.join(&file.path)), 0),
+            _ => counts.get(&file.path).copied().unwrap_or_default(),
+        };
+        file.insertions = insertions;
+        file.deletions = deletions;
+        summary.insertions += insertions;
+        summary.deletions += deletions;
+    }
+    summary.files = files;
+    Ok(summary)
+}
+
+/// Parses `git status --porcelain=v2 -z` entries
+fn parse_entries(output: &str) -> Vec<FileChange> {
+    let mut files = Vec::new();
+    let mut records = output.split('\0');
+    while let Some(record) = records.next() {
+        if let Some(path) = record.strip_prefix("? ") {
+            files.push(FileChange {
+                path: path.to_string(),
+                old_path: None,
+                status: "untracked".to_string(),
+                staged: false,
+                unstaged: true,
+                insertions: 0,
+                deletions: 0,
+            });
+            continue;
+        }
+
+        // Fields before the path: ordinary 8, renamed/copied 9, unmerged 10
+        let kind = record.split(' ').next().unwrap_or_default();
+        let fields = match kind {
+            "1" => 8,
+            "2" => 9,
+            "u" => 10,
+            _ => continue,
+        };
+        let mut parts = record.splitn(fields + 1, ' ');
+        let mut xy = parts.nth(1).unwrap_or_default().chars();
+        let (x, y) = (xy.next().unwrap_or('.'), xy.next().unwrap_or('.'));
+        let Some(path) = parts.last() else { continue };
+        // Renames and copies are followed by the original path
+        let old_path = (kind == "2").then(|| records.next().unwrap_or_default().to_string());
+
+        let status = match (kind, if x != '.' { x } else { y }) {
+            ("u", _) => "conflicted",
+            (_, 'A') => "added",
+            (_, 'D') => "deleted",
+            (_, 'R') => "renamed",
+            (_, 'C') => "copied",
+            (_, 'T') => "typechange",
+            _ => "modified",
+        };
+        files.push(FileChange {
+            path: path.to_string(),
+            old_path,
+            status: status.to_string(),
+            staged: kind != "u" && x != '.',
+            unstaged: kind == "u" || y != '.',
+            insertions: 0,
+            deletions: 0,
+        });
+    }
+    files
+}
+
+/// Parses `git diff --numstat -z` into insertions and deletions by (new) path
+fn parse_numstat(output: &str) -> HashMap<String, (u32, u32)> {
+    let mut counts = HashMap::new();
+    let mut records = output.split('\0');
+    while let Some(record) = records.next() {
+        let mut fields = record.splitn(3, '\t');
+        let (Some(insertions), Some(deletions), Some(path)) = (fields.next(), fields.next(), fields.next()) else {
+            continue;
+        };
+        // Renames leave the path empty and follow with the old and new paths
+        let path = if path.is_empty() {
+            records.next();
+            records.next().unwrap_or_default()
+        } else {
+            path
+        };
+        // Binary files show "-" for both
+        counts.insert(path.to_string(), (insertions.parse().unwrap_or(0), deletions.parse().unwrap_or(0)));
+    }
+    counts
+}
+
+fn count_lines(file: &Path) -> u32 {
+    let too_big = fs::metadata(file).map_or(true, |m| !m.is_file() || m.len() > MAX_COUNTED_SIZE);
+    if too_big {
+        return 0;
+    }
+    match fs::read(file) {
+        Ok(content) if !content.contains(&0) => {
+            let newlines = content.iter().filter(|&&b| b == b'\n').count();
+            // A last line without a newline still counts
+            (newlines + usize::from(content.last().is_some_and(|&b| b != b'\n'))) as u32
+        }
+        _ => 0,
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::cli::git_output;
+use super::remotes::remote_urls;
+use super::resolve_git_dir;
+use super::worktree::{list_worktrees, main_worktree};
+use crate::model::{DuplicateClone, DuplicateGroup};
+use crate::remote::parse_remote_url;
+use crate::size::get_directory_size;
+
+/// What identifies the repository behind a clone: its remotes, normalized so SSH and HTTPS
+/// forms agree, and its root commits. Linked worktrees are not clones and give nothing.
+fn identity(path: &Path) -> Vec<String> {
+    let Some(git_dir) = resolve_git_dir(path) else {
+        return Vec::new();
+    };
+    if main_worktree(&git_dir).is_some() {
+        return Vec::new();
+    }
+
+    let mut keys: Vec<String> = remote_urls(path)
+        .iter()
+        .filter_map(|(_, url)| parse_remote_url(url))
+        .map(|remote| format!("{}/{}", remote.host, remote.path.to_lowercase()))
+        .collect();
+    if let Ok(roots) = git_output(path, &["rev-list", "--max-parents=0", "HEAD"]) {
+        keys.extend(roots.lines().map(|sha| format!("root:{}", sha.trim())));
+    }
+    keys
+}
+
+/// Groups the repositories at `paths` that share a remote or a root commit. Only groups
+/// with more than one clone are returned, largest first.
+pub fn find_duplicates(paths: &[PathBuf]) -> Vec<DuplicateGroup> {
+    let mut clones: Vec<PathBuf> = Vec::new();
+    for path in paths {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+        if !clones.contains(&path) {
+            clones.push(path);
+        }
+    }
+
+    // Union-find over clones, joined through any key they have in common
+    let mut parent: Vec<usize> = (0..clones.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    let identities: Vec<Vec<String>> = clones.iter().map(|path| identity(path)).collect();
+    let mut owner: HashMap<&str, usize> = HashMap::new();
+    for (i, keys) in identities.iter().enumerate() {
+        for key in keys {
+            match owner.get(key.as_str()) {
+                Some(&j) => {
+                    let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+                    parent[a] = b;
+                }
+                None => {
+                    owner.insert(key, i);
+                }
+            }
+        }
+    }
+
+    let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..clones.len() {
+        members.entry(root(&mut parent, i)).or_default().push(i);
+    }
+
+    let mut groups: Vec<DuplicateGroup> = members
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            // Named after a remote when there is one
+            let repository = group
+                .iter()
+                .flat_map(|&i| &identities[i])
+                .find(|key| !key.starts_with("root:"))
+                .cloned()
+                .unwrap_or_else(|| clones[group[0]].file_name().unwrap_or_default().to_string_lossy().to_string());
+            let mut clones: Vec<DuplicateClone> = group.iter().map(|&i| describe(&clones[i])).collect();
+            clones.sort_by(|a, b| a.path.cmp(&b.path));
+            DuplicateGroup { repository, clones }
+        })
+        .collect();
+    groups.sort_by_key(|g| std::cmp::Reverse(g.clones.iter().map(|c| c.size).sum::<u64>()));
+    groups
+}
+
+fn describe(path: &Path) -> DuplicateClone {
+    let branch = git_output(path, &["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .map(|b| b.trim().to_string())
+        .unwrap_or_else(|_| "HEAD (detached)".to_string());
+    let dirty = git_output(path, &["status", "--porcelain"]).is_ok_and(|s| !s.trim().is_empty());
+    DuplicateClone {
+        path: path.to_string_lossy().to_string(),
+        branch,
+        dirty,
+        size: get_directory_size(path),
+    }
+}
+
+/// Ignored folders that a build or an install recreates, so deleting them loses nothing
+const REBUILDABLE: &[&str] = &[
+    "node_modules", "target", "dist", "build", ".next", ".nuxt", ".output", ".gradle", ".dart_tool", "__pycache__",
+    ".pytest_cache", ".venv", "venv",
+];
+
+/// Ignored files and folders that exist only in this clone (`.env`, local configs), leaving
+/// out build output and dependencies
+fn ignored_files(path: &Path) -> Result<Vec<String>, String> {
+    let output = git_output(path, &["ls-files", "-z", "--others", "--ignored", "--exclude-standard", "--directory"])?;
+    Ok(output
+        .split('\0')
+        .filter(|entry| !entry.is_empty())
+        .filter(|entry| !entry.split('/').any(|part| REBUILDABLE.contains(&part) || part.ends_with(".egg-info")))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Reasons the clone at `path` cannot be deleted without losing work: anything uncommitted,
+/// ignored files, stashes, linked worktrees and commits that no remote and none of `others`
+/// can reach.
+/// `others` must hold at least one other clone of the same repository.
+pub fn clone_removal_blockers(path: &Path, others: &[PathBuf]) -> Result<Vec<String>, String> {
+    let keys = identity(path);
+    if keys.is_empty() {
+        return Err(format!("{} is not a clone of a git repository", path.display()));
+    }
+    let others: Vec<&PathBuf> = others
+        .iter()
+        .filter(|other| !same_path(other, path) && identity(other).iter().any(|k| keys.contains(k)))
+        .collect();
+    if others.is_empty() {
+        return Err("No other clone of this repository is left to keep".to_string());
+    }
+
+    let mut blockers = Vec::new();
+    let changes = git_output(path, &["status", "--porcelain"])?;
+    let changed = changes.lines().count();
+    if changed > 0 {
+        blockers.push(format!("{} uncommitted file(s)", changed));
+    }
+    let ignored = ignored_files(path)?;
+    if !ignored.is_empty() {
+        let examples = ignored.iter().take(3).map(String::as_str).collect::<Vec<_>>().join(", ");
+        blockers.push(format!("{} ignored file(s) such as {}", ignored.len(), examples));
+    }
+    let stashes = git_output(path, &["stash", "list"]).map(|s| s.lines().count()).unwrap_or(0);
+    if stashes > 0 {
+        blockers.push(format!("{} stash(es)", stashes));
+    }
+    let worktrees = list_worktrees(path).map(|w| w.len()).unwrap_or(1);
+    if worktrees > 1 {
+        blockers.push(format!("{} linked worktree(s)", worktrees - 1));
+    }
+
+    let mut refs = git_output(path, &["for-each-ref", "--format=%(refname:short) %(objectname)", "refs/heads"])?;
+    // Commits made on a detached HEAD are on no branch at all
+    if git_output(path, &["symbolic-ref", "--quiet", "HEAD"]).is_err() {
+        if let Ok(head) = git_output(path, &["rev-parse", "HEAD"]) {
+            refs.push_str(&format!("HEAD {}\n", head.trim()));
+        }
+    }
+    for line in refs.lines() {
+        let Some((branch, sha)) = line.split_once(' ') else {
+            continue;
+        };
+        let unpushed: u32 = git_output(path, &["rev-list", "--count", sha, "--not", "--remotes"])
+            .ok()
+            .and_then(|c| c.trim().parse().ok())
+            .unwrap_or(0);
+        if unpushed == 0 {
+            continue;
+        }
+        // Every commit of the branch survives when a ref of another clone reaches its tip; an
+        // object that is merely present could be garbage collected. Unknown commits are errors.
+        let reachable = |other: &&PathBuf| {
+            git_output(other, &["for-each-ref", "--contains", sha]).is_ok_and(|refs| !refs.trim().is_empty())
+        };
+        if !others.iter().any(reachable) {
+            blockers.push(format!("{}: {} commit(s) found in no other clone or remote", branch, unpushed));
+        }
+    }
+    Ok(blockers)
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
@@ -0,0 +1,46 @@
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use super::cli::git_output;
+use crate::model::LfsStatus;
+
+const POINTER_PREFIX: &[u8] = b"version https://git-lfs.github.com/spec/v1";
+
+/// LFS state for a repository whose attributes route files through the LFS filter. Files
+/// still holding pointers are found by reading them, so this works without git-lfs installed.
+pub(super) fn lfs_status(path: &Path) -> Option<LfsStatus> {
+    let attributes = fs::read_to_string(path.join(".gitattributes")).ok()?;
+    if !attributes.contains("filter=lfs") {
+        return None;
+    }
+
+    let files = git_output(path, &["ls-files", "-z", "--", ":(attr:filter=lfs)"]).ok()?;
+    let mut status = LfsStatus {
+        installed: git_output(path, &["lfs", "version"]).is_ok(),
+        ..Default::default()
+    };
+    for file in files.split('\0').filter(|f| !f.is_empty()) {
+        status.files += 1;
+        if is_pointer(&path.join(file)) {
+            status.missing += 1;
+        }
+    }
+    Some(status)
+}
+
+fn is_pointer(file: &Path) -> bool {
+    let mut head = [0u8; POINTER_PREFIX.len()];
+    File::open(file)
+        .and_then(|mut f| f.read_exact(&mut head))
+        .is_ok_and(|_| head == POINTER_PREFIX)
+}
+
+/// Downloads and checks out the LFS objects for the current checkout
+pub fn lfs_pull(path: &Path) -> Result<String, String> {
+    if git_output(path, &["lfs", "version"]).is_err() {
+        return Err("Git LFS is not installed".to_string());
+    }
+    git_output(path, &["lfs", "pull"]).map_err(|e| format!("Failed to pull LFS objects: {}", e))?;
+    Ok("LFS objects downloaded".to_string())
+}
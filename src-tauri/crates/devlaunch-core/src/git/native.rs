@@ -0,0 +1,182 @@
+use std::path::Path;
+
+use git2::{Commit, ErrorCode, Repository, Sort, Status, StatusOptions};
+
+use super::GitBackend;
+use crate::model::{CommitInfo, GitStatus};
+
+/// Answers queries in-process through libgit2, without forking `git`
+pub struct Git2Backend;
+
+fn open(repo: &Path) -> Result<Repository, String> {
+    Repository::open(repo).map_err(|e| format!("Failed to open repository: {}", e.message()))
+}
+
+/// Upstream name as git prints it (`origin/main`) and the ahead/behind counts, when it exists
+fn upstream(repo: &Repository, branch: &str) -> (Option<String>, Option<(u32, u32)>) {
+    let refname = format!("refs/heads/{}", branch);
+    let upstream_ref = match repo.branch_upstream_name(&refname) {
+        Ok(name) => name.as_str().unwrap_or_default().to_string(),
+        Err(_) => return (None, None),
+    };
+    let name = upstream_ref
+        .strip_prefix("refs/remotes/")
+        .or_else(|| upstream_ref.strip_prefix("refs/heads/"))
+        .unwrap_or(&upstream_ref)
+        .to_string();
+
+    // A configured upstream can be gone, e.g. after the remote branch was deleted
+    let local = repo.refname_to_id(&refname);
+    let remote = repo.refname_to_id(&upstream_ref);
+    let counts = match (local, remote) {
+        (Ok(local), Ok(remote)) => repo
+            .graph_ahead_behind(local, remote)
+            .ok()
+            .map(|(ahead, behind)| (ahead as u32, behind as u32)),
+        _ => None,
+    };
+    (Some(name), counts)
+}
+
+/// Files, insertions and deletions against the first parent, with renames detected like
+/// `git log --shortstat`. Merges have no stats there, so none here either.
+fn diff_stats(repo: &Repository, commit: &Commit) -> Option<(u32, u32, u32)> {
+    if commit.parent_count() > 1 {
+        return Some((0, 0, 0));
+    }
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree().ok()?),
+        Err(_) => None,
+    };
+    let mut diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree().ok()?), None)
+        .ok()?;
+    diff.find_similar(None).ok()?;
+    let stats = diff.stats().ok()?;
+    Some((stats.files_changed() as u32, stats.insertions() as u32, stats.deletions() as u32))
+}
+
+impl GitBackend for Git2Backend {
+    fn name(&self) -> &'static str {
+        "git2"
+    }
+
+    fn status(&self, repo_path: &Path) -> Result<GitStatus, String> {
+        let repo = open(repo_path)?;
+        let mut status = GitStatus {
+            detached: repo.head_detached().unwrap_or(false),
+            ..Default::default()
+        };
+
+        match repo.head() {
+            Ok(head) if status.detached => {
+                status.branch = head.target().map(|id| id.to_string()[..7].to_string()).unwrap_or_default();
+            }
+            Ok(head) => status.branch = head.shorthand().unwrap_or_default().to_string(),
+            // Fresh repository: HEAD names a branch that has no commits yet
+            Err(e) if e.code() == ErrorCode::UnbornBranch => {
+                let head = repo.find_reference("HEAD").map_err(|e| e.message().to_string())?;
+                let target = head.symbolic_target().unwrap_or_default();
+                status.branch = target.trim_start_matches("refs/heads/").to_string();
+            }
+            Err(e) => return Err(format!("Failed to read HEAD: {}", e.message())),
+        }
+
+        if !status.detached {
+            let (name, counts) = upstream(&repo, &status.branch);
+            status.upstream = name;
+            if let Some((ahead, behind)) = counts {
+                status.ahead = Some(ahead);
+                status.behind = Some(behind);
+            }
+        }
+
+        // Same view as `git status`: untracked directories count once, renames are paired up
+        let mut options = StatusOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(false)
+            .renames_head_to_index(true);
+        let entries = repo
+            .statuses(Some(&mut options))
+            .map_err(|e| format!("Failed to read status: {}", e.message()))?;
+
+        let index_changes = Status::INDEX_NEW
+            | Status::INDEX_MODIFIED
+            | Status::INDEX_DELETED
+            | Status::INDEX_RENAMED
+            | Status::INDEX_TYPECHANGE;
+        let worktree_changes = Status::WT_MODIFIED | Status::WT_DELETED | Status::WT_RENAMED | Status::WT_TYPECHANGE;
+
+        let mut changed = 0;
+        for entry in entries.iter() {
+            let flags = entry.status();
+            if flags.is_ignored() {
+                continue;
+            }
+            changed += 1;
+            if flags.is_conflicted() {
+                status.conflicted += 1;
+                continue;
+            }
+            if flags == Status::WT_NEW {
+                status.untracked += 1;
+                continue;
+            }
+            if flags.intersects(index_changes) {
+                status.staged += 1;
+            }
+            if flags.intersects(worktree_changes) {
+                status.unstaged += 1;
+            }
+        }
+        status.count = (changed > 0).then_some(changed);
+        Ok(status)
+    }
+
+    fn log(&self, repo_path: &Path, rev: Option<&str>, limit: usize) -> Result<Vec<CommitInfo>, String> {
+        let repo = open(repo_path)?;
+        if rev.is_none() && repo.head().is_err_and(|e| e.code() == ErrorCode::UnbornBranch) {
+            return Ok(Vec::new());
+        }
+        let mut walk = repo.revwalk().map_err(|e| e.message().to_string())?;
+        walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME).map_err(|e| e.message().to_string())?;
+        match rev {
+            Some(rev) => {
+                let start = repo
+                    .revparse_single(rev)
+                    .and_then(|object| object.peel_to_commit())
+                    .map_err(|e| format!("Failed to read history of {}: {}", rev, e.message()))?;
+                walk.push(start.id())
+            }
+            None => walk.push_head(),
+        }
+        .map_err(|e| format!("Failed to read history: {}", e.message()))?;
+
+        walk.take(limit)
+            .map(|id| {
+                let id = id.map_err(|e| e.message().to_string())?;
+                let commit = repo.find_commit(id).map_err(|e| e.message().to_string())?;
+                let author = commit.author();
+                let time = author.when();
+                let date = chrono::FixedOffset::east_opt(time.offset_minutes() * 60)
+                    .and_then(|offset| chrono::DateTime::from_timestamp(time.seconds(), 0).map(|d| d.with_timezone(&offset)))
+                    .map(|d| d.to_rfc3339())
+                    .unwrap_or_default();
+                let (files_changed, insertions, deletions) = diff_stats(&repo, &commit).unwrap_or_default();
+                let id = id.to_string();
+                Ok(CommitInfo {
+                    short_id: id[..7].to_string(),
+                    id,
+                    summary: commit.summary().unwrap_or_default().to_string(),
+                    author: author.name().unwrap_or_default().to_string(),
+                    author_email: author.email().unwrap_or_default().to_string(),
+                    date,
+                    files_changed,
+                    insertions,
+                    deletions,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use super::branches::default_branch;
+use super::cli::git_output;
+use crate::model::{GitHost, RemoteInfo};
+use crate::remote::web_links;
+
+/// Name and URL of every configured remote
+pub(super) fn remote_urls(path: &Path) -> Vec<(String, String)> {
+    let Ok(config) = git_output(path, &["config", "--get-regexp", r"^remote\..*\.url$"]) else {
+        return Vec::new();
+    };
+    config
+        .lines()
+        .filter_map(|line| {
+            let (key, url) = line.split_once(' ')?;
+            // Remote names may contain dots themselves
+            let name = key.strip_prefix("remote.")?.strip_suffix(".url")?;
+            Some((name.to_string(), url.to_string()))
+        })
+        .collect()
+}
+
+/// The repository's remotes with their web links, origin first. Reads only local config.
+pub fn remote_links(path: &Path, hosts: &[GitHost]) -> Vec<RemoteInfo> {
+    let branch = git_output(path, &["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .ok()
+        .map(|b| b.trim().to_string());
+    let base = default_branch(path);
+
+    let mut remotes: Vec<RemoteInfo> = remote_urls(path)
+        .iter()
+        .filter_map(|(name, url)| web_links(name, url, branch.as_deref(), base.as_deref(), hosts))
+        .collect();
+    remotes.sort_by_key(|r| r.name != "origin");
+    remotes
+}
@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use super::cli::git_output;
+use crate::model::StashEntry;
+
+/// Stashes, newest first
+pub fn list_stashes(path: &Path) -> Result<Vec<StashEntry>, String> {
+    let output = git_output(path, &["stash", "list", "--format=%gd%x1f%gs%x1f%cI"])?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\x1f');
+            let index = fields.next()?.strip_prefix("stash@{")?.strip_suffix('}')?.parse().ok()?;
+            let (branch, message) = parse_subject(fields.next()?);
+            Some(StashEntry {
+                index,
+                message,
+                branch,
+                date: fields.next()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Splits a stash subject, "On main: message" or "WIP on main: 1a2b3c4 commit subject"
+fn parse_subject(subject: &str) -> (Option<String>, String) {
+    let rest = subject
+        .strip_prefix("On ")
+        .or_else(|| subject.strip_prefix("WIP on "));
+    match rest.and_then(|rest| rest.split_once(": ")) {
+        Some((branch, message)) => (Some(branch.to_string()), message.to_string()),
+        None => (None, subject.to_string()),
+    }
+}
+
+/// Stashes tracked changes, and untracked files too when `include_untracked` is set
+pub fn create_stash(path: &Path, message: Option<&str>, include_untracked: bool) -> Result<String, String> {
+    let mut args = vec!["stash", "push"];
+    if include_untracked {
+        args.push("--include-untracked");
+    }
+    if let Some(message) = message.filter(|m| !m.is_empty()) {
+        args.extend(["-m", message]);
+    }
+    let output = git_output(path, &args).map_err(|e| format!("Failed to stash changes: {}", e))?;
+    // Nothing to stash still exits successfully
+    if output.contains("No local changes to save") {
+        return Err("No local changes to stash".to_string());
+    }
+    Ok("Changes stashed".to_string())
+}
+
+/// Applies stash `index`, dropping it afterwards when `pop` is set. Returns the files left
+/// conflicted; a stash that conflicts is kept even when popping.
+pub fn apply_stash(path: &Path, index: u32, pop: bool) -> Result<Vec<String>, String> {
+    let stash = format!("stash@{{{}}}", index);
+    let command = if pop { "pop" } else { "apply" };
+    match git_output(path, &["stash", command, &stash]) {
+        Ok(_) => Ok(Vec::new()),
+        Err(e) => match conflicted_files(path) {
+            conflicts if !conflicts.is_empty() => Ok(conflicts),
+            _ => Err(format!("Failed to apply {}: {}", stash, e)),
+        },
+    }
+}
+
+pub fn drop_stash(path: &Path, index: u32) -> Result<String, String> {
+    let stash = format!("stash@{{{}}}", index);
+    git_output(path, &["stash", "drop", &stash]).map_err(|e| format!("Failed to drop {}: {}", stash, e))?;
+    Ok(format!("Dropped {}", stash))
+}
+
+/// Files with unresolved conflicts in the working tree
+pub(super) fn conflicted_files(path: &Path) -> Vec<String> {
+    git_output(path, &["diff", "--name-only", "--diff-filter=U"])
+        .map(|output| output.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use super::cli::git_output;
+use crate::model::SubmoduleStatus;
+
+/// Submodule states for a repository with a `.gitmodules` file, counted across nested submodules
+pub(super) fn submodule_status(path: &Path) -> Option<SubmoduleStatus> {
+    if !path.join(".gitmodules").is_file() {
+        return None;
+    }
+    let mut status = SubmoduleStatus::default();
+
+    // Each line starts with ' ', '-' (not initialized), '+' (checked out at another commit
+    // than the one recorded) or 'U' (conflicted)
+    let output = git_output(path, &["submodule", "status", "--recursive"]).ok()?;
+    for line in output.lines() {
+        status.total += 1;
+        match line.chars().next() {
+            Some('-') => status.uninitialized += 1,
+            Some('+') | Some('U') => status.out_of_date += 1,
+            _ => {}
+        }
+    }
+
+    // Porcelain v2 marks submodules with an "S<c><m><u>" field on changed ("1") and renamed
+    // ("2") entries; m and u flag changes inside them. Other lines end in arbitrary file names.
+    if let Ok(output) = git_output(path, &["status", "--porcelain=v2"]) {
+        status.modified = output
+            .lines()
+            .filter(|line| line.starts_with("1 ") || line.starts_with("2 "))
+            .filter_map(|line| line.split(' ').nth(2))
+            .filter(|sub| sub.starts_with('S') && sub.get(2..).is_some_and(|m| m.contains('M') || m.contains('U')))
+            .count() as u32;
+    }
+    Some(status)
+}
+
+/// Initializes and checks out every submodule, recursively, at the recorded commits
+pub fn update_submodules(path: &Path) -> Result<String, String> {
+    git_output(path, &["submodule", "update", "--init", "--recursive"])
+        .map_err(|e| format!("Failed to update submodules: {}", e))?;
+    Ok("Submodules updated".to_string())
+}
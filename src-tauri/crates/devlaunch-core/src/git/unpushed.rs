@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use super::cli::git_output;
+use crate::model::{UnpushedBranch, UnpushedWork};
+
+/// Everything in the repository at `path` that hasn't reached a remote: uncommitted changes,
+/// unpushed commits on any local branch, stashes, and branches with no upstream
+pub fn unpushed_work(path: &Path) -> Result<UnpushedWork, String> {
+    let status = git_output(path, &["status", "--porcelain"])?;
+    let stashes = git_output(path, &["stash", "list"])?;
+    let remotes = git_output(path, &["remote"])?;
+    let refs = git_output(path, &["for-each-ref", "--format=%(refname:short)%1f%(upstream:short)", "refs/heads"])?;
+
+    let mut branches = Vec::new();
+    for line in refs.lines() {
+        let Some((branch, upstream)) = line.split_once('\x1f') else { continue };
+        let local = format!("refs/heads/{}", branch);
+        // An upstream whose remote branch was deleted counts as no upstream
+        let upstream = Some(upstream)
+            .filter(|u| !u.is_empty())
+            .filter(|u| git_output(path, &["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", u)]).is_ok());
+        let commits = match upstream {
+            Some(upstream) => git_output(path, &["rev-list", "--count", &format!("{}..{}", upstream, local)])?,
+            None => git_output(path, &["rev-list", "--count", &local, "--not", "--remotes"])?,
+        };
+        let commits = commits.trim().parse().unwrap_or(0);
+
+        if commits > 0 || upstream.is_none() {
+            branches.push(UnpushedBranch {
+                branch: branch.to_string(),
+                upstream: upstream.map(str::to_string),
+                commits,
+            });
+        }
+    }
+
+    Ok(UnpushedWork {
+        path: path.to_string_lossy().to_string(),
+        uncommitted: status.lines().count() as u32,
+        branches,
+        stashes: stashes.lines().count() as u32,
+        has_remote: !remotes.trim().is_empty(),
+    })
+}
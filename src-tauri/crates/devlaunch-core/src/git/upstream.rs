@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use super::cli::git_output;
+use super::fetch_repo;
+use crate::model::UpstreamChange;
+
+/// Local branch, full upstream ref and its short name, for branches tracking a remote
+fn tracked_branches(path: &Path) -> Result<Vec<(String, String, String)>, String> {
+    let output = git_output(
+        path,
+        &["for-each-ref", "--format=%(refname:short)%1f%(upstream)%1f%(upstream:short)", "refs/heads"],
+    )?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\x1f');
+            let branch = fields.next()?.to_string();
+            let upstream = fields.next()?.to_string();
+            let short = fields.next()?.to_string();
+            upstream.starts_with("refs/remotes/").then_some((branch, upstream, short))
+        })
+        .collect())
+}
+
+fn tip(path: &Path, refname: &str) -> Option<String> {
+    git_output(path, &["rev-parse", "--verify", "--quiet", refname])
+        .ok()
+        .map(|id| id.trim().to_string())
+}
+
+/// Fetches every remote and reports the tracked upstreams that moved: with the local branch
+/// now behind, or rewritten by a force push
+pub fn fetch_tracked(path: &Path) -> Result<Vec<UpstreamChange>, String> {
+    let tracked = tracked_branches(path)?;
+    let before: Vec<Option<String>> = tracked.iter().map(|(_, upstream, _)| tip(path, upstream)).collect();
+
+    fetch_repo(&path.to_string_lossy())?;
+
+    let mut changes = Vec::new();
+    for ((branch, upstream, short), old) in tracked.into_iter().zip(before) {
+        let Some(new) = tip(path, &upstream) else { continue };
+        if old.as_deref() == Some(new.as_str()) {
+            continue;
+        }
+        let force_pushed = old.is_some_and(|old| {
+            git_output(path, &["merge-base", "--is-ancestor", &old, &new]).is_err()
+        });
+        let behind = git_output(path, &["rev-list", "--count", &format!("refs/heads/{}..{}", branch, upstream)])
+            .ok()
+            .and_then(|count| count.trim().parse().ok())
+            .unwrap_or(0);
+        if behind > 0 || force_pushed {
+            changes.push(UpstreamChange { branch, upstream: short, behind, force_pushed });
+        }
+    }
+    Ok(changes)
+}
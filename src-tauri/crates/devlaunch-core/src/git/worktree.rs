@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::cli::git_output;
+use crate::model::WorktreeInfo;
+
+/// The main working tree of the repository a linked worktree belongs to, given the
+/// worktree's git directory. `None` for main working trees and submodules.
+pub(super) fn main_worktree(git_dir: &Path) -> Option<PathBuf> {
+    // Only linked worktrees point back to a shared ("common") git directory
+    let common = fs::read_to_string(git_dir.join("commondir")).ok()?;
+    let common = fs::canonicalize(git_dir.join(common.trim())).ok()?;
+    match common.file_name() {
+        Some(name) if name == ".git" => common.parent().map(Path::to_path_buf),
+        // A bare repository has no working tree of its own
+        _ => Some(common),
+    }
+}
+
+/// Every working tree of the repository at `path`, the main one first
+pub fn list_worktrees(path: &Path) -> Result<Vec<WorktreeInfo>, String> {
+    let output = git_output(path, &["worktree", "list", "--porcelain"])?;
+    let mut worktrees = Vec::new();
+    for block in output.split("\n\n").filter(|b| !b.trim().is_empty()) {
+        let mut worktree = WorktreeInfo::default();
+        for line in block.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "worktree" => worktree.path = value.to_string(),
+                "HEAD" => worktree.head = Some(value.to_string()),
+                "branch" => worktree.branch = Some(value.trim_start_matches("refs/heads/").to_string()),
+                "detached" => worktree.detached = true,
+                "bare" => worktree.bare = true,
+                "locked" => worktree.locked = true,
+                "prunable" => worktree.prunable = true,
+                _ => {}
+            }
+        }
+        worktree.is_main = worktrees.is_empty();
+        worktrees.push(worktree);
+    }
+    Ok(worktrees)
+}
+
+/// Checks out `branch` in a new worktree at `target`, creating the branch from HEAD when `create` is set
+pub fn add_worktree(path: &Path, target: &Path, branch: &str, create: bool) -> Result<String, String> {
+    let target = target.to_string_lossy();
+    // Anything starting with a dash would be read as an option
+    if branch.is_empty() || branch.starts_with('-') {
+        return Err(format!("Invalid branch name: {}", branch));
+    }
+    if target.starts_with('-') {
+        return Err(format!("Invalid worktree path: {}", target));
+    }
+    let args = if create {
+        git_output(path, &["check-ref-format", "--branch", branch])
+            .map_err(|_| format!("\"{}\" is not a valid branch name", branch))?;
+        vec!["worktree", "add", "-b", branch, &target]
+    } else {
+        vec!["worktree", "add", &target, branch]
+    };
+    git_output(path, &args).map_err(|e| format!("Failed to add worktree: {}", e))?;
+    Ok(format!("Checked out {} in {}", branch, target))
+}
+
+/// Removes the worktree at `worktree`; without `force`, git refuses when it has local changes
+pub fn remove_worktree(path: &Path, worktree: &Path, force: bool) -> Result<String, String> {
+    let worktree = worktree.to_string_lossy();
+    if worktree.starts_with('-') {
+        return Err(format!("Invalid worktree path: {}", worktree));
+    }
+    let mut args = vec!["worktree", "remove"];
+    if force {
+        args.push("--force");
+    }
+    args.push(&worktree);
+    git_output(path, &args).map_err(|e| format!("Failed to remove worktree: {}", e))?;
+    Ok(format!("Removed worktree {}", worktree))
+}
+
+/// Forgets worktrees whose directories were deleted, returning their paths
+pub fn prune_worktrees(path: &Path) -> Result<Vec<String>, String> {
+    let stale: Vec<String> = list_worktrees(path)?
+        .into_iter()
+        .filter(|w| w.prunable)
+        .map(|w| w.path)
+        .collect();
+    git_output(path, &["worktree", "prune"]).map_err(|e| format!("Failed to prune worktrees: {}", e))?;
+    Ok(stale)
+}
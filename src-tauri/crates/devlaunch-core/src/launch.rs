@@ -0,0 +1,373 @@
+//! Launching IDEs, terminals, file managers and package scripts.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+use crate::detect::detect_package_manager;
+use crate::model::ProjectScript;
+
+// Windows-specific imports for hiding console windows
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+/// Creates a Command that won't show a console window on Windows
+pub fn silent_command(program: &str) -> Command {
+    #[cfg_attr(not(target_os = "windows"), allow(unused_mut))]
+    let mut cmd = Command::new(program);
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(CREATE_NO_WINDOW);
+    cmd
+}
+
+/// Opens `project_path` in the given IDE, trying the known install locations for the platform
+pub fn launch_ide(project_path: &str, ide: &str) -> Result<(), String> {
+    // Get potential IDE executables/paths as owned Strings
+    #[cfg(target_os = "windows")]
+    let ide_options: Vec<String> = match ide {
+        "vscode" | "code" => {
+            let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_default();
+            vec![
+                "code".to_string(),
+                "code.cmd".to_string(),
+                format!("{}\\Microsoft VS Code\\Code.exe", local_app_data),
+                format!("{}\\Programs\\Microsoft VS Code\\Code.exe", local_app_data),
+                "C:\\Program Files\\Microsoft VS Code\\Code.exe".to_string(),
+                "C:\\Program Files (x86)\\Microsoft VS Code\\Code.exe".to_string(),
+            ]
+        },
+        "cursor" => {
+            let local_app_data = std::env::var("LOCALAPPDATA").unwrap_or_default();
+            vec![
+                "cursor".to_string(),
+                "cursor.cmd".to_string(),
+                format!("{}\\Programs\\cursor\\Cursor.exe", local_app_data),
+                format!("{}\\cursor\\Cursor.exe", local_app_data),
+            ]
+        },
+        "webstorm" => vec!["webstorm".to_string(), "webstorm64.exe".to_string()],
+        "idea" => vec!["idea".to_string(), "idea64.exe".to_string()],
+        "zed" => vec!["zed".to_string()],
+        "android-studio" => vec![
+            "C:\\Program Files\\Android\\Android Studio\\bin\\studio64.exe".to_string(),
+            "C:\\Program Files (x86)\\Android\\Android Studio\\bin\\studio.exe".to_string(),
+        ],
+        "xcode" => vec![], // Not available on Windows
+        _ => vec!["code".to_string()],
+    };
+
+    #[cfg(target_os = "macos")]
+    let ide_options: Vec<String> = match ide {
+        "vscode" | "code" => {
+            vec![
+                "code".to_string(),
+                "/Applications/Visual Studio Code.app/Contents/Resources/app/bin/code".to_string(),
+                "/usr/local/bin/code".to_string(),
+            ]
+        },
+        "cursor" => {
+            vec![
+                "cursor".to_string(),
+                "/Applications/Cursor.app/Contents/Resources/app/bin/cursor".to_string(),
+                "/usr/local/bin/cursor".to_string(),
+            ]
+        },
+        "webstorm" => vec![
+            "webstorm".to_string(),
+            "/Applications/WebStorm.app/Contents/MacOS/webstorm".to_string(),
+        ],
+        "idea" => vec![
+            "idea".to_string(),
+            "/Applications/IntelliJ IDEA.app/Contents/MacOS/idea".to_string(),
+            "/Applications/IntelliJ IDEA CE.app/Contents/MacOS/idea".to_string(),
+        ],
+        "zed" => vec![
+            "zed".to_string(),
+            "/Applications/Zed.app/Contents/MacOS/zed".to_string(),
+        ],
+        "android-studio" => vec![
+            "/Applications/Android Studio.app/Contents/MacOS/studio".to_string(),
+        ],
+        "xcode" => vec![
+            "xed".to_string(), // Xcode command line tool
+        ],
+        _ => vec!["code".to_string()],
+    };
+
+    #[cfg(target_os = "linux")]
+    let ide_options: Vec<String> = match ide {
+        "vscode" | "code" => vec!["code".to_string()],
+        "cursor" => vec!["cursor".to_string()],
+        "webstorm" => vec!["webstorm".to_string()],
+        "idea" => vec!["idea".to_string()],
+        "zed" => vec!["zed".to_string()],
+        "android-studio" => vec!["android-studio".to_string(), "studio.sh".to_string()],
+        _ => vec!["code".to_string()],
+    };
+
+    // Try each possible path/command
+    let mut last_error = String::new();
+    
+    for ide_cmd in &ide_options {
+        let is_full_path = if cfg!(target_os = "windows") {
+            ide_cmd.contains('\\') || ide_cmd.contains('/')
+        } else {
+            ide_cmd.starts_with('/')
+        };
+        
+        let result = if is_full_path {
+            // It's a full path - check if it exists first
+            let path_buf = std::path::PathBuf::from(ide_cmd);
+            if path_buf.exists() {
+                Command::new(ide_cmd)
+                    .arg(project_path)
+                    .spawn()
+            } else {
+                continue; // Skip non-existent paths
+            }
+        } else {
+            // It's a command name, try to run it
+            Command::new(ide_cmd)
+                .arg(project_path)
+                .spawn()
+        };
+
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                last_error = format!("{}", e);
+                continue;
+            }
+        }
+    }
+
+    Err(format!("Failed to open IDE: {}. Make sure the IDE is installed.", last_error))
+}
+
+/// Shows `path` in the platform file manager
+pub fn reveal_path(path: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("explorer")
+            .arg(path)
+            .spawn()
+            .map_err(|e| format!("Failed to open explorer: {}", e))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open")
+            .arg(path)
+            .spawn()
+            .map_err(|e| format!("Failed to open Finder: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Command::new("xdg-open")
+            .arg(path)
+            .spawn()
+            .map_err(|e| format!("Failed to open file manager: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Opens a terminal window in `path`
+pub fn launch_terminal(path: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        // Try Windows Terminal first
+        let wt_result = Command::new("cmd")
+            .args(["/c", "wt", "-d", path])
+            .spawn();
+        
+        if wt_result.is_ok() {
+            return Ok(());
+        }
+        
+        // Fall back to cmd
+        Command::new("cmd")
+            .args(["/c", "start", "cmd", "/k", &format!("cd /d \"{}\"", path)])
+            .spawn()
+            .map_err(|e| format!("Failed to open terminal: {}", e))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open")
+            .arg("-a")
+            .arg("Terminal")
+            .arg(path)
+            .spawn()
+            .map_err(|e| format!("Failed to open terminal: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Try common terminal emulators
+        let terminals = ["gnome-terminal", "konsole", "xterm"];
+        let opened = terminals.iter().any(|term| {
+            Command::new(term)
+                .arg("--working-directory")
+                .arg(path)
+                .spawn()
+                .is_ok()
+        });
+        if !opened {
+            return Err("No terminal emulator found".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+/// Opens a terminal window in `dir` running `command`, left open afterwards so the output stays visible
+pub fn run_in_terminal(dir: &str, command: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        // Try Windows Terminal first
+        let wt_result = Command::new("cmd")
+            .args(["/c", "wt", "-d", dir, "cmd", "/k", command])
+            .spawn();
+        
+        if wt_result.is_ok() {
+            return Ok(());
+        }
+        
+        // Fall back to cmd
+        Command::new("cmd")
+            .args(["/c", "start", "cmd", "/k", &format!("cd /d \"{}\" && {}", dir, command)])
+            .spawn()
+            .map_err(|e| format!("Failed to open terminal: {}", e))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let script = format!("cd '{}' && {}", dir, command);
+        Command::new("osascript")
+            .args(["-e", &format!("tell app \"Terminal\" to do script \"{}\"", script)])
+            .spawn()
+            .map_err(|e| format!("Failed to open terminal: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let full_cmd = format!("cd '{}' && {}; exec bash", dir, command);
+        let terminals = [
+            ("gnome-terminal", vec!["--", "bash", "-c"]),
+            ("konsole", vec!["-e", "bash", "-c"]),
+            ("xterm", vec!["-e", "bash", "-c"]),
+        ];
+        
+        let mut success = false;
+        for (term, args) in terminals {
+            let mut cmd = Command::new(term);
+            for arg in &args {
+                cmd.arg(arg);
+            }
+            if cmd.arg(&full_cmd).spawn().is_ok() {
+                success = true;
+                break;
+            }
+        }
+        
+        if !success {
+            return Err("No terminal emulator found".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+/// Scripts declared in the project's `package.json`, empty when there is none
+pub fn read_scripts(project_path: &Path) -> Result<Vec<ProjectScript>, String> {
+    let package_json_path = project_path.join("package.json");
+    
+    if !package_json_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&package_json_path)
+        .map_err(|e| format!("Failed to read package.json: {}", e))?;
+    
+    let json: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse package.json: {}", e))?;
+
+    let scripts = json.get("scripts")
+        .and_then(|s| s.as_object())
+        .map(|s| {
+            s.iter()
+                .map(|(name, cmd)| ProjectScript {
+                    name: name.clone(),
+                    command: cmd.as_str().unwrap_or("").to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(scripts)
+}
+
+fn resolve_package_manager(path: &str, package_manager: Option<String>) -> String {
+    package_manager.unwrap_or_else(|| detect_package_manager(Path::new(path)).unwrap_or_else(|| "npm".to_string()))
+}
+
+/// Runs the package manager's install in a new terminal window so progress is visible
+pub fn install_dependencies(path: &str, package_manager: Option<String>) -> Result<String, String> {
+    let pm = resolve_package_manager(path, package_manager);
+    let install_cmd = match pm.as_str() {
+        "yarn" => "yarn",
+        "pnpm" => "pnpm",
+        "bun" => "bun",
+        _ => "npm",
+    };
+
+    run_in_terminal(path, &format!("{} install", install_cmd))?;
+    Ok(format!("Installing dependencies using {}...", pm))
+}
+
+/// Arguments that run a package.json script with the given package manager, e.g. `["run", "dev"]`
+pub fn script_args(package_manager: &str, script_name: &str) -> Vec<String> {
+    match package_manager {
+        "yarn" => vec![script_name.to_string()],
+        _ => vec!["run".to_string(), script_name.to_string()],
+    }
+}
+
+/// Runs a package.json script in a new terminal window
+pub fn launch_script(path: &str, script_name: &str, package_manager: Option<String>) -> Result<String, String> {
+    let pm = resolve_package_manager(path, package_manager);
+    let full_script_cmd = format!("{} {}", pm, script_args(&pm, script_name).join(" "));
+
+    run_in_terminal(path, &full_script_cmd)?;
+    Ok(format!("Running {}...", full_script_cmd))
+}
+
+/// Command that runs a package.json script in `path` without a terminal window of its own
+pub fn script_command(path: &str, script_name: &str, package_manager: Option<String>) -> Command {
+    let pm = resolve_package_manager(path, package_manager);
+    let mut args = script_args(&pm, script_name);
+
+    // Package managers are .cmd shims on Windows, which only cmd can launch
+    let program = if cfg!(target_os = "windows") {
+        args.splice(0..0, ["/C".to_string(), pm.clone()]);
+        "cmd".to_string()
+    } else {
+        pm
+    };
+
+    let mut cmd = Command::new(program);
+    cmd.args(&args).current_dir(path);
+    cmd
+}
+
+/// Runs a package.json script attached to the current terminal and waits for it to exit
+pub fn run_script(path: &str, script_name: &str, package_manager: Option<String>) -> Result<ExitStatus, String> {
+    let mut cmd = script_command(path, script_name, package_manager);
+    cmd.status()
+        .map_err(|e| format!("Failed to run {}: {}", cmd.get_program().to_string_lossy(), e))
+}
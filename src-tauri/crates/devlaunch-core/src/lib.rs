@@ -0,0 +1,30 @@
+//! GUI-independent core of DevLaunch: project detection and scanning, git inspection,
+//! cleanup, process launching and the on-disk project library.
+//!
+//! The Tauri app and the `devlaunch` CLI are thin front ends over this crate.
+
+#[cfg(unix)]
+pub mod api;
+pub mod cleanup;
+pub mod deeplink;
+pub mod desktop;
+pub mod detect;
+pub mod git;
+pub mod launch;
+pub mod model;
+pub mod remote;
+pub mod runner;
+pub mod scan;
+pub mod search;
+pub mod size;
+pub mod store;
+pub mod team;
+pub mod tools;
+
+pub use model::{
+    ActivityCount, AppSettings, AuthorActivity, BranchInfo, BulkGitResult, CloneOptions, CloneProgress,
+    CommitActivity, CommitInfo, DiffSummary, DuplicateClone, DuplicateGroup, FileActivity, FileChange, GitHost,
+    GitStatus, LfsStatus, LibraryActivity, Project, ProjectScanResult, ProjectScript, PullResult, RemoteInfo,
+    StashEntry, SubmoduleStatus, TeamReport, TeamStep, TechStack, ToolVersion, UnpushedBranch, UnpushedWork,
+    UpstreamChange, WorktreeInfo,
+};
@@ -0,0 +1,560 @@
+//! Data types shared with the frontend. Field names follow the TypeScript types in `types.ts`.
+
+use serde::{Deserialize, Serialize};
+
+/// A framework, language or tool badge shown on a project card
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TechStack {
+    pub name: String,
+    /// Badge color
+    #[serde(rename = "type")]
+    pub tech_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GitStatus {
+    /// Current branch, or the abbreviated commit when HEAD is detached
+    pub branch: String,
+    /// Summary shown on the card: `Clean`, `Modified`, `Ahead`, `Behind`, `Diverged`,
+    /// `Conflicted`, `Rebasing`, `Merging`, `Detached`, ...
+    pub status: String,
+    /// Number of changed files, when the tree is dirty
+    pub count: Option<i32>,
+    /// `success`, `warning`, `error`, `info` or `neutral`
+    #[serde(rename = "type")]
+    pub status_type: String,
+    /// Upstream branch such as `origin/main`, if one is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<String>,
+    /// Commits not on the upstream; `None` when there is no upstream to compare with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ahead: Option<u32>,
+    /// Upstream commits not merged yet; `None` when there is no upstream to compare with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub behind: Option<u32>,
+    #[serde(default)]
+    pub staged: u32,
+    #[serde(default)]
+    pub unstaged: u32,
+    #[serde(default)]
+    pub untracked: u32,
+    #[serde(default)]
+    pub conflicted: u32,
+    #[serde(default)]
+    pub detached: bool,
+    /// `rebase`, `merge`, `cherry-pick` or `revert` while one is in progress
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    /// For a linked worktree, the main working tree of its repository
+    #[serde(rename = "worktreeOf", default, skip_serializing_if = "Option::is_none")]
+    pub worktree_of: Option<String>,
+    /// Present when the repository has submodules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<SubmoduleStatus>,
+    /// Present when the repository stores files in Git LFS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lfs: Option<LfsStatus>,
+}
+
+/// Submodule counts, including nested submodules
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmoduleStatus {
+    pub total: u32,
+    pub uninitialized: u32,
+    /// Checked out at a different commit than the one the repository records
+    pub out_of_date: u32,
+    /// With uncommitted or untracked changes inside
+    pub modified: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LfsStatus {
+    /// Files tracked through LFS
+    pub files: u32,
+    /// Files still holding a pointer instead of their content
+    pub missing: u32,
+    /// Whether `git lfs` is available to download them
+    pub installed: bool,
+}
+
+/// One commit of a project's history
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitInfo {
+    pub id: String,
+    pub short_id: String,
+    pub summary: String,
+    pub author: String,
+    pub author_email: String,
+    /// Author date, RFC 3339
+    pub date: String,
+    /// Diff against the first parent; merges report no changes, like `git log`
+    #[serde(default)]
+    pub files_changed: u32,
+    #[serde(default)]
+    pub insertions: u32,
+    #[serde(default)]
+    pub deletions: u32,
+}
+
+/// Commits on one day, or in the week starting on that Monday
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ActivityCount {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub commits: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorActivity {
+    pub name: String,
+    pub email: String,
+    pub commits: u32,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileActivity {
+    /// Relative to the repository; prefixed with the project folder in library-wide totals
+    pub path: String,
+    /// Commits that changed the file
+    pub commits: u32,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+/// Commit statistics of the last `days` days of HEAD's history (see `git::commit_activity`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitActivity {
+    /// The repository, empty for library-wide totals
+    pub path: String,
+    pub days: u32,
+    pub commits: u32,
+    pub insertions: u32,
+    pub deletions: u32,
+    /// Every day of the window, oldest first, days without commits included
+    pub daily: Vec<ActivityCount>,
+    /// Every week of the window by its Monday, oldest first
+    pub weekly: Vec<ActivityCount>,
+    /// Most commits first
+    pub authors: Vec<AuthorActivity>,
+    /// Most changed files first
+    pub files: Vec<FileActivity>,
+    /// Latest commit on HEAD by the configured `user.email`, inside the window or not
+    pub last_own_commit: Option<CommitInfo>,
+}
+
+/// Commit statistics per project and summed over the library
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryActivity {
+    pub total: CommitActivity,
+    /// Most commits first
+    pub projects: Vec<CommitActivity>,
+}
+
+/// A changed file in the working tree or index
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileChange {
+    pub path: String,
+    /// Previous path of a renamed or copied file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_path: Option<String>,
+    /// `added`, `modified`, `deleted`, `renamed`, `copied`, `typechange`, `untracked` or `conflicted`
+    pub status: String,
+    /// Has changes in the index
+    pub staged: bool,
+    /// Has changes in the working tree that aren't staged
+    pub unstaged: bool,
+    /// Lines changed against HEAD; binary files count none
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+/// Uncommitted changes of a repository
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffSummary {
+    pub files: Vec<FileChange>,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+/// A local or remote-tracking branch
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchInfo {
+    /// `main` for local branches, `origin/main` for remote-tracking ones
+    pub name: String,
+    pub is_remote: bool,
+    pub is_current: bool,
+    /// Abbreviated id of the tip commit
+    pub commit: String,
+    pub summary: String,
+    /// Committer date of the tip, RFC 3339
+    pub last_commit_date: String,
+    /// Upstream of a local branch, if one is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<String>,
+    #[serde(default)]
+    pub ahead: u32,
+    #[serde(default)]
+    pub behind: u32,
+}
+
+/// An entry of `git stash list`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StashEntry {
+    /// `n` in `stash@{n}`
+    pub index: u32,
+    pub message: String,
+    /// Branch the changes were stashed from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// When the stash was created, RFC 3339
+    pub date: String,
+}
+
+/// Outcome of a pull, with the local changes set aside around it when asked to
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PullResult {
+    /// Output of `git pull`
+    pub message: String,
+    /// Local changes were stashed before pulling
+    pub stashed: bool,
+    /// The stashed changes were re-applied; when `stashed` but not `restored` they are
+    /// still in the stash
+    pub restored: bool,
+    /// Files left with conflict markers by the pull or by re-applying the stash
+    pub conflicts: Vec<String>,
+}
+
+/// A tracked upstream branch that moved during a fetch
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UpstreamChange {
+    pub branch: String,
+    /// Short name, such as `origin/main`
+    pub upstream: String,
+    /// Upstream commits the local branch doesn't have
+    pub behind: u32,
+    /// The upstream was rewritten rather than moved forward
+    pub force_pushed: bool,
+}
+
+/// How to clone a repository into the library
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CloneOptions {
+    /// Branch or tag to check out instead of the remote's default branch
+    pub branch: Option<String>,
+    /// Number of commits of history to fetch; the full history when unset
+    pub depth: Option<u32>,
+    /// Also clone submodules, recursively
+    pub recursive: bool,
+    /// Folder name under the parent directory; derived from the URL when unset
+    pub directory: Option<String>,
+}
+
+/// One line of `git clone --progress` output
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CloneProgress {
+    /// `Receiving objects`, `Resolving deltas` and so on, or the whole line for messages
+    pub phase: String,
+    pub percent: Option<u8>,
+}
+
+/// One of several clones of the same repository
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DuplicateClone {
+    pub path: String,
+    pub branch: String,
+    /// Uncommitted or untracked files
+    pub dirty: bool,
+    /// Disk size in bytes, history included
+    pub size: u64,
+}
+
+/// Clones that share a remote or a root commit
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DuplicateGroup {
+    /// A normalized remote such as `github.com/owner/repo`, or the folder name for local-only repositories
+    pub repository: String,
+    pub clones: Vec<DuplicateClone>,
+}
+
+/// One step of bootstrapping a team manifest for one repository
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStep {
+    pub repo: String,
+    /// `clone`, `install` or `library`
+    pub step: String,
+    /// `ok`, `failed` or `skipped`
+    pub status: String,
+    pub message: String,
+    /// Output of an install command
+    pub log_file: Option<String>,
+}
+
+/// Everything a team bootstrap did, in order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamReport {
+    pub manifest: String,
+    pub steps: Vec<TeamStep>,
+    /// Launch profiles the manifest defines
+    pub profiles: Vec<String>,
+}
+
+impl TeamReport {
+    pub fn failed(&self) -> Vec<&TeamStep> {
+        self.steps.iter().filter(|s| s.status == "failed").collect()
+    }
+}
+
+/// A git remote with the web pages derived from its URL (see `remote`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteInfo {
+    pub name: String,
+    pub url: String,
+    pub host: String,
+    /// Repository path on the host, such as `owner/repo` or `group/subgroup/repo`
+    pub path: String,
+    /// `github`, `gitlab`, `bitbucket`, `gitea` or `unknown`
+    pub provider: String,
+    pub web_url: String,
+    /// The current branch on the web, for recognized providers
+    pub branch_url: Option<String>,
+    /// A page to open a pull or merge request from the current branch into the default one
+    pub compare_url: Option<String>,
+}
+
+/// A self-hosted git server, recognized by host name
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct GitHost {
+    /// `git.example.com`, or `*.example.com` for every subdomain
+    pub pattern: String,
+    /// `github`, `gitlab`, `bitbucket` or `gitea`
+    pub provider: String,
+}
+
+/// One working tree of a repository, from `git worktree list`
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeInfo {
+    pub path: String,
+    pub head: Option<String>,
+    /// `None` when HEAD is detached
+    pub branch: Option<String>,
+    pub is_main: bool,
+    pub bare: bool,
+    pub detached: bool,
+    pub locked: bool,
+    /// Its directory is gone; `prune_worktrees` would forget it
+    pub prunable: bool,
+}
+
+/// A local branch with commits that exist on no remote, or with nowhere to push to
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpushedBranch {
+    pub branch: String,
+    /// `None` when the branch tracks nothing, or its upstream was deleted
+    pub upstream: Option<String>,
+    /// Commits not pushed to the upstream, or to any remote when there is none
+    pub commits: u32,
+}
+
+/// Work in one repository that exists only on this machine
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UnpushedWork {
+    pub path: String,
+    /// Changed and untracked files
+    pub uncommitted: u32,
+    pub branches: Vec<UnpushedBranch>,
+    pub stashes: u32,
+    pub has_remote: bool,
+}
+
+impl UnpushedWork {
+    /// Whether anything here would be lost with the machine
+    pub fn is_at_risk(&self) -> bool {
+        self.uncommitted > 0 || !self.branches.is_empty() || self.stashes > 0 || !self.has_remote
+    }
+}
+
+/// Outcome of a bulk git operation on one repository
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkGitResult {
+    pub path: String,
+    pub ok: bool,
+    /// Left alone on purpose, e.g. a pull over uncommitted changes
+    pub skipped: bool,
+    pub message: String,
+    /// Status after the operation, for repositories that could be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<GitStatus>,
+}
+
+/// A project in the library
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub icon: String,
+    #[serde(rename = "techStack")]
+    pub tech_stack: Vec<TechStack>,
+    #[serde(rename = "gitStatus")]
+    pub git_status: GitStatus,
+    #[serde(rename = "lastActive")]
+    pub last_active: String,
+    pub storage: String,
+    #[serde(rename = "buildStorage")]
+    pub build_storage: Option<String>,
+    #[serde(rename = "isArchived")]
+    pub is_archived: Option<bool>,
+    #[serde(rename = "hasNodeModules")]
+    pub has_node_modules: bool,
+    #[serde(rename = "hasBuildFolder")]
+    pub has_build_folder: bool,
+    #[serde(rename = "buildFolderName")]
+    pub build_folder_name: Option<String>,
+    #[serde(rename = "packageManager")]
+    pub package_manager: Option<String>,
+    #[serde(rename = "projectType")]
+    pub project_type: Option<String>,
+    #[serde(rename = "hasGit", default)]
+    pub has_git: bool,
+    #[serde(rename = "lastOpened", default, skip_serializing_if = "Option::is_none")]
+    pub last_opened: Option<String>,
+    #[serde(rename = "openCount", default, skip_serializing_if = "Option::is_none")]
+    pub open_count: Option<u32>,
+    #[serde(rename = "isPinned", default, skip_serializing_if = "Option::is_none")]
+    pub is_pinned: Option<bool>,
+    #[serde(rename = "favoriteScript", default, skip_serializing_if = "Option::is_none")]
+    pub favorite_script: Option<String>,
+    /// Listed in the desktop's application search (Linux)
+    #[serde(rename = "hasLauncher", default, skip_serializing_if = "Option::is_none")]
+    pub has_launcher: Option<bool>,
+    /// Git remotes with their web links, origin first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remotes: Vec<RemoteInfo>,
+    // Frontend-only fields (tags, notes, health, ...) round-trip untouched
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Project {
+    /// Tags set in the UI, stored with the frontend-only fields
+    pub fn tags(&self) -> Vec<&str> {
+        self.extra
+            .get("tags")
+            .and_then(|tags| tags.as_array())
+            .map(|tags| tags.iter().filter_map(|t| t.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct AppSettings {
+    #[serde(rename = "defaultIde")]
+    pub default_ide: String,
+    #[serde(rename = "idePath")]
+    pub ide_path: String,
+    #[serde(rename = "trayBadge")]
+    pub tray_badge: bool,
+    #[serde(rename = "statusPollMinutes")]
+    pub status_poll_minutes: u64,
+    /// Closing the window hides it to the tray instead of quitting
+    #[serde(rename = "runInBackground")]
+    pub run_in_background: bool,
+    /// Serve the local JSON-RPC API (see `api`)
+    #[serde(rename = "apiEnabled")]
+    pub api_enabled: bool,
+    /// Periodically fetch recently opened repositories while running
+    #[serde(rename = "backgroundFetch")]
+    pub background_fetch: bool,
+    #[serde(rename = "fetchIntervalMinutes")]
+    pub fetch_interval_minutes: u64,
+    /// Only projects opened within this many days are fetched; 0 fetches all of them
+    #[serde(rename = "fetchRecentDays")]
+    pub fetch_recent_days: u64,
+    /// Notify once a day, at `unpushed_reminder_time`, about work that hasn't been pushed
+    #[serde(rename = "unpushedReminder")]
+    pub unpushed_reminder: bool,
+    /// Local time as `HH:MM`
+    #[serde(rename = "unpushedReminderTime")]
+    pub unpushed_reminder_time: String,
+    /// Once a day, remove the build output of projects not opened for `auto_clean_days`
+    #[serde(rename = "autoClean")]
+    pub auto_clean: bool,
+    #[serde(rename = "autoCleanDays")]
+    pub auto_clean_days: u64,
+    /// Rescan library projects whose folders change outside DevLaunch
+    #[serde(rename = "watchLibrary")]
+    pub watch_library: bool,
+    /// Self-hosted servers to recognize in remote URLs, checked before the well-known hosts
+    #[serde(rename = "gitHosts")]
+    pub git_hosts: Vec<GitHost>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            default_ide: "code".to_string(),
+            ide_path: String::new(),
+            tray_badge: true,
+            status_poll_minutes: 5,
+            run_in_background: true,
+            api_enabled: false,
+            background_fetch: false,
+            fetch_interval_minutes: 30,
+            fetch_recent_days: 14,
+            unpushed_reminder: false,
+            unpushed_reminder_time: "18:00".to_string(),
+            auto_clean: false,
+            auto_clean_days: 30,
+            watch_library: true,
+            git_hosts: Vec::new(),
+            extra: serde_json::Map::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectScanResult {
+    pub project: Project,
+    pub detected: bool,
+}
+
+/// An entry of `scripts` in package.json
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectScript {
+    pub name: String,
+    pub command: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ToolVersion {
+    pub name: String,
+    pub version: Option<String>,
+    pub installed: bool,
+    pub icon: String,
+}
@@ -0,0 +1,177 @@
+//! Git remote URLs: normalizing the SSH and HTTPS forms, recognizing the hosting provider
+//! and building links to the repository's web pages. Nothing here touches the network.
+
+use url::Url;
+
+use crate::model::{GitHost, RemoteInfo};
+
+pub const GITHUB: &str = "github";
+pub const GITLAB: &str = "gitlab";
+pub const BITBUCKET: &str = "bitbucket";
+pub const GITEA: &str = "gitea";
+pub const UNKNOWN: &str = "unknown";
+
+/// Host and repository path of a remote, e.g. `github.com` and `owner/repo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteUrl {
+    pub host: String,
+    pub path: String,
+    /// Non-default port of an HTTP(S) remote, which its web pages share. SSH ports say
+    /// nothing about the web server and are dropped.
+    pub port: Option<u16>,
+}
+
+/// Parses `git@host:owner/repo.git`, `ssh://git@host:22/owner/repo`, `https://host/owner/repo.git`
+/// and `git://` URLs. Local paths and `file://` remotes have no web page and give `None`.
+pub fn parse_remote_url(url: &str) -> Option<RemoteUrl> {
+    let url = url.trim();
+    let (host, path, port) = if url.contains("://") {
+        let parsed = Url::parse(url).ok()?;
+        if !matches!(parsed.scheme(), "ssh" | "git+ssh" | "https" | "http" | "git") {
+            return None;
+        }
+        let port = parsed.port().filter(|_| matches!(parsed.scheme(), "https" | "http"));
+        (parsed.host_str()?.to_string(), parsed.path().to_string(), port)
+    } else {
+        // scp-like syntax: [user@]host:path, where the host has no slash before the colon
+        let (host, path) = url.split_once(':')?;
+        let host = host.rsplit('@').next()?;
+        // A single letter is a Windows drive, not a host
+        if host.is_empty() || host.contains('/') || host.len() == 1 {
+            return None;
+        }
+        (host.to_string(), path.to_string(), None)
+    };
+
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path).trim_end_matches('/');
+    if !path.contains('/') {
+        return None;
+    }
+    Some(RemoteUrl {
+        host: host.to_lowercase(),
+        path: path.to_string(),
+        port,
+    })
+}
+
+/// `url` without the password of its user info, and for HTTP(S) without the user name
+/// either, since that is where access tokens go. `git@` and other SSH users are kept.
+pub fn redact_credentials(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url.trim()) else {
+        return url.to_string();
+    };
+    if parsed.password().is_none() && (parsed.username().is_empty() || !matches!(parsed.scheme(), "https" | "http")) {
+        return url.to_string();
+    }
+    let _ = parsed.set_password(None);
+    if matches!(parsed.scheme(), "https" | "http") {
+        let _ = parsed.set_username("");
+    }
+    parsed.to_string()
+}
+
+/// Whether `host` matches `pattern`, either exactly or as `*.example.com`
+fn host_matches(pattern: &str, host: &str) -> bool {
+    let pattern = pattern.trim().to_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => host.ends_with(&format!(".{}", suffix)),
+        None => host == pattern,
+    }
+}
+
+/// The hosting provider for `host`, checking the configured hosts before the well-known ones
+pub fn detect_provider(host: &str, hosts: &[GitHost]) -> &'static str {
+    if let Some(custom) = hosts.iter().find(|h| host_matches(&h.pattern, host)) {
+        return match custom.provider.as_str() {
+            GITHUB => GITHUB,
+            GITLAB => GITLAB,
+            BITBUCKET => BITBUCKET,
+            GITEA => GITEA,
+            _ => UNKNOWN,
+        };
+    }
+    match host {
+        "github.com" => GITHUB,
+        "gitlab.com" => GITLAB,
+        "bitbucket.org" => BITBUCKET,
+        "codeberg.org" | "gitea.com" => GITEA,
+        // Self-hosted instances usually keep the product in the name
+        _ if host.starts_with("github.") => GITHUB,
+        _ if host.starts_with("gitlab.") => GITLAB,
+        _ if host.starts_with("bitbucket.") => BITBUCKET,
+        _ if host.starts_with("gitea.") || host.starts_with("forgejo.") => GITEA,
+        _ => UNKNOWN,
+    }
+}
+
+/// `web` with `segments` appended, each percent-encoded
+fn page(web: &Url, segments: &[&str]) -> Url {
+    let mut url = web.clone();
+    if let Ok(mut path) = url.path_segments_mut() {
+        path.extend(segments);
+    }
+    url
+}
+
+/// Links for remote `name`: the repository, `branch` on it and a page to open a pull request from
+/// `branch` into `base`. Only the repository link is known for unrecognized providers. The
+/// remote URL is kept without credentials.
+pub fn web_links(name: &str, url: &str, branch: Option<&str>, base: Option<&str>, hosts: &[GitHost]) -> Option<RemoteInfo> {
+    let remote = parse_remote_url(url)?;
+    let provider = detect_provider(&remote.host, hosts);
+    let authority = match remote.port {
+        Some(port) => format!("{}:{}", remote.host, port),
+        None => remote.host.clone(),
+    };
+    let web = Url::parse(&format!("https://{}/{}", authority, remote.path)).ok()?;
+
+    let branch_url = branch.and_then(|branch| {
+        let prefix: &[&str] = match provider {
+            GITHUB => &["tree"],
+            GITLAB => &["-", "tree"],
+            BITBUCKET => &["src"],
+            // Gitea needs to be told the ref is a branch
+            GITEA => &["src", "branch"],
+            _ => return None,
+        };
+        let segments: Vec<&str> = prefix.iter().copied().chain(branch.split('/')).collect();
+        Some(page(&web, &segments).to_string())
+    });
+
+    let compare_url = match (branch, base) {
+        (Some(branch), Some(base)) if branch != base => match provider {
+            GITHUB => {
+                let mut url = page(&web, &["compare", &format!("{}...{}", base, branch)]);
+                url.set_query(Some("expand=1"));
+                Some(url.to_string())
+            }
+            GITEA => Some(page(&web, &["compare", &format!("{}...{}", base, branch)]).to_string()),
+            GITLAB => {
+                let mut url = page(&web, &["-", "merge_requests", "new"]);
+                url.query_pairs_mut()
+                    .append_pair("merge_request[source_branch]", branch)
+                    .append_pair("merge_request[target_branch]", base);
+                Some(url.to_string())
+            }
+            BITBUCKET => {
+                let mut url = page(&web, &["pull-requests", "new"]);
+                url.query_pairs_mut().append_pair("source", branch).append_pair("dest", base);
+                Some(url.to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+
+    Some(RemoteInfo {
+        name: name.to_string(),
+        url: redact_credentials(url),
+        host: remote.host,
+        path: remote.path,
+        provider: provider.to_string(),
+        web_url: web.to_string(),
+        branch_url,
+        compare_url,
+    })
+}
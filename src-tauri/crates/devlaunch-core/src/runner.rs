@@ -0,0 +1,164 @@
+//! Package scripts and shell commands running in the background, with their output written to log files.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
+use serde::Serialize;
+
+use crate::launch::{script_command, silent_command};
+use crate::store;
+
+/// A script started by the runner
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptRun {
+    pub id: String,
+    pub path: String,
+    pub script: String,
+    pub pid: u32,
+    pub started_at: String,
+    /// Combined stdout and stderr
+    pub log_file: String,
+    pub running: bool,
+    pub exit_code: Option<i32>,
+}
+
+/// Keeps track of scripts it started so they can be listed and stopped later
+#[derive(Default)]
+pub struct ProcessRunner {
+    runs: Mutex<HashMap<String, (ScriptRun, Child)>>,
+}
+
+fn logs_dir() -> PathBuf {
+    store::runtime_dir().join("runs")
+}
+
+// Updates `running`/`exit_code` from the child without blocking
+fn refresh(run: &mut ScriptRun, child: &mut Child) {
+    if let Ok(Some(status)) = child.try_wait() {
+        run.running = false;
+        run.exit_code = status.code();
+    }
+}
+
+impl ProcessRunner {
+    /// Starts `script` in `path` detached from any terminal
+    pub fn start(&self, path: &str, script: &str, package_manager: Option<String>) -> Result<ScriptRun, String> {
+        self.spawn(path, script, script_command(path, script, package_manager))
+    }
+
+    /// Starts a shell command line in `path`, such as an install step from a team manifest
+    pub fn start_command(&self, path: &str, command: &str) -> Result<ScriptRun, String> {
+        let mut cmd = if cfg!(target_os = "windows") {
+            let mut cmd = silent_command("cmd");
+            cmd.args(["/C", command]);
+            cmd
+        } else {
+            let mut cmd = silent_command("sh");
+            cmd.args(["-c", command]);
+            cmd
+        };
+        cmd.current_dir(path);
+        self.spawn(path, command, cmd)
+    }
+
+    fn spawn(&self, path: &str, script: &str, mut cmd: Command) -> Result<ScriptRun, String> {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        fs::create_dir_all(logs_dir()).map_err(|e| format!("Failed to create log directory: {}", e))?;
+        let log_path = logs_dir().join(format!("{}.log", id));
+        let log = File::create(&log_path).map_err(|e| format!("Failed to create {}: {}", log_path.display(), e))?;
+        let log_err = log.try_clone().map_err(|e| format!("Failed to create {}: {}", log_path.display(), e))?;
+
+        cmd.stdin(Stdio::null()).stdout(log).stderr(log_err);
+        // Own process group, so stopping also reaches the dev server the package manager spawned
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+        let child = cmd
+            .spawn()
+            .map_err(|e| format!("Failed to run {}: {}", cmd.get_program().to_string_lossy(), e))?;
+
+        let run = ScriptRun {
+            id: id.clone(),
+            path: path.to_string(),
+            script: script.to_string(),
+            pid: child.id(),
+            started_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            log_file: log_path.to_string_lossy().to_string(),
+            running: true,
+            exit_code: None,
+        };
+        self.runs.lock().unwrap().insert(id, (run.clone(), child));
+        Ok(run)
+    }
+
+    /// Stops a run and everything it spawned, waiting briefly for a clean exit before killing it
+    pub fn stop(&self, id: &str) -> Result<ScriptRun, String> {
+        let mut runs = self.runs.lock().unwrap();
+        let (run, child) = runs.get_mut(id).ok_or_else(|| format!("No script run with id {}", id))?;
+        refresh(run, child);
+        if !run.running {
+            return Ok(run.clone());
+        }
+
+        #[cfg(unix)]
+        {
+            use std::time::{Duration, Instant};
+
+            let group = format!("-{}", run.pid);
+            let _ = silent_command("kill").args(["-TERM", "--", &group]).status();
+            let deadline = Instant::now() + Duration::from_secs(3);
+            while Instant::now() < deadline && matches!(child.try_wait(), Ok(None)) {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            if matches!(child.try_wait(), Ok(None)) {
+                let _ = silent_command("kill").args(["-KILL", "--", &group]).status();
+            }
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = silent_command("taskkill")
+                .args(["/PID", &run.pid.to_string(), "/T", "/F"])
+                .status();
+        }
+
+        let status = child.wait().map_err(|e| format!("Failed to stop script: {}", e))?;
+        run.running = false;
+        run.exit_code = status.code();
+        Ok(run.clone())
+    }
+
+    /// Blocks until a run exits on its own
+    pub fn wait(&self, id: &str) -> Result<ScriptRun, String> {
+        loop {
+            {
+                let mut runs = self.runs.lock().unwrap();
+                let (run, child) = runs.get_mut(id).ok_or_else(|| format!("No script run with id {}", id))?;
+                refresh(run, child);
+                if !run.running {
+                    return Ok(run.clone());
+                }
+            }
+            // Not holding the lock, so the run can still be listed or stopped meanwhile
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+    }
+
+    /// All runs started by this runner, finished ones included
+    pub fn list(&self) -> Vec<ScriptRun> {
+        let mut runs = self.runs.lock().unwrap();
+        let mut list: Vec<ScriptRun> = runs
+            .values_mut()
+            .map(|(run, child)| {
+                refresh(run, child);
+                run.clone()
+            })
+            .collect();
+        list.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+        list
+    }
+}
@@ -0,0 +1,160 @@
+//! Turning directories into library projects.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cleanup::{detect_build_folder, get_cleanable_size};
+use crate::detect::{detect_package_manager, detect_project_type, detect_tech_stack, get_project_icon};
+use crate::git::{get_git_info, remote_links};
+use crate::model::{Project, ProjectScanResult};
+use crate::size::{format_size, get_directory_size};
+use crate::store;
+
+/// Scans a directory into a new, unsaved library [`Project`]
+pub fn scan_path(path: &str) -> Result<ProjectScanResult, String> {
+    let project_path = PathBuf::from(path);
+
+    if !project_path.exists() {
+        return Err("Path does not exist".to_string());
+    }
+
+    if !project_path.is_dir() {
+        return Err("Path is not a directory".to_string());
+    }
+
+    let tech_stack = detect_tech_stack(&project_path);
+    let git_status = get_git_info(&project_path);
+    let has_node_modules = project_path.join("node_modules").exists();
+    let package_manager = detect_package_manager(&project_path);
+    let project_type = detect_project_type(&project_path);
+    let has_git = project_path.join(".git").exists();
+    let remotes = if has_git {
+        remote_links(&project_path, &store::load_settings().git_hosts)
+    } else {
+        Vec::new()
+    };
+
+    // Detect build folder
+    let (has_build_folder, build_folder_name, build_storage) = 
+        if let Some((folder_name, size)) = detect_build_folder(&project_path, &project_type) {
+            (true, Some(folder_name), Some(format_size(size)))
+        } else {
+            (false, None, None)
+        };
+
+    // Calculate total project storage (cleanable items)
+    let storage = {
+        let mut total_size = 0u64;
+        
+        // node_modules
+        if has_node_modules {
+            total_size += get_directory_size(&project_path.join("node_modules"));
+        }
+        
+        // Build folders based on project type
+        total_size += get_cleanable_size(&project_path, &project_type);
+        
+        if total_size > 0 {
+            format_size(total_size)
+        } else {
+            "< 1 MB".to_string()
+        }
+    };
+
+    let project_name = project_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Unknown")
+        .to_string();
+
+    let icon = get_project_icon(&tech_stack, &project_path);
+
+    let project = Project {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: project_name,
+        path: path.to_string(),
+        icon,
+        tech_stack,
+        git_status,
+        last_active: "Just added".to_string(),
+        storage,
+        build_storage,
+        is_archived: Some(false),
+        has_node_modules,
+        has_build_folder,
+        build_folder_name,
+        package_manager,
+        project_type: Some(project_type),
+        has_git,
+        last_opened: None,
+        open_count: None,
+        is_pinned: None,
+        favorite_script: None,
+        has_launcher: None,
+        remotes,
+        extra: serde_json::Map::new(),
+    };
+
+    Ok(ProjectScanResult {
+        project,
+        detected: true,
+    })
+}
+
+/// Copies what a fresh scan of a library project's directory found (stack, git state, folders,
+/// sizes, remotes) onto it, keeping its identity and everything set by the user
+pub fn apply_scan(project: &mut Project, scanned: Project) {
+    project.tech_stack = scanned.tech_stack;
+    project.git_status = scanned.git_status;
+    project.storage = scanned.storage;
+    project.build_storage = scanned.build_storage;
+    project.has_node_modules = scanned.has_node_modules;
+    project.has_build_folder = scanned.has_build_folder;
+    project.build_folder_name = scanned.build_folder_name;
+    project.package_manager = scanned.package_manager;
+    project.project_type = scanned.project_type;
+    project.has_git = scanned.has_git;
+    project.remotes = scanned.remotes;
+}
+
+/// Files that mark a directory as a project root when scanning for projects
+pub const PROJECT_MARKERS: &[&str] = &[
+    ".git",
+    "package.json",
+    "Cargo.toml",
+    "go.mod",
+    "pubspec.yaml",
+    "pyproject.toml",
+    "requirements.txt",
+    "build.gradle",
+    "build.gradle.kts",
+];
+
+/// Finds project roots under `root`, without descending into projects or hidden/dependency folders
+pub fn discover_projects(root: &Path, max_depth: usize) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if PROJECT_MARKERS.iter().any(|m| root.join(m).exists()) {
+        found.push(root.to_path_buf());
+        return found;
+    }
+    if max_depth == 0 {
+        return found;
+    }
+
+    if let Ok(entries) = fs::read_dir(root) {
+        let mut dirs: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .filter(|p| {
+                let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                !name.starts_with('.') && name != "node_modules"
+            })
+            .collect();
+        dirs.sort();
+        for dir in dirs {
+            found.extend(discover_projects(&dir, max_depth - 1));
+        }
+    }
+    found
+}
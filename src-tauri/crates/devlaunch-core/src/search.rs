@@ -0,0 +1,42 @@
+//! Project lookup by free text, using the same rule as the app's search box.
+
+use crate::model::Project;
+
+/// Case-insensitive substring match on the name, path or any tag, like the search box
+pub fn matches(project: &Project, query: &str) -> bool {
+    rank(project, &query.to_lowercase()).is_some()
+}
+
+// Lower is better: exact name, name prefix, name substring, then path or tag
+fn rank(project: &Project, query: &str) -> Option<u8> {
+    let name = project.name.to_lowercase();
+    if name == query {
+        Some(0)
+    } else if name.starts_with(query) {
+        Some(1)
+    } else if name.contains(query) {
+        Some(2)
+    } else if project.path.to_lowercase().contains(query)
+        || project.tags().iter().any(|t| t.to_lowercase().contains(query))
+    {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+/// Projects matching `query`, best first; equally good matches keep their order in `projects`
+pub fn search<'a>(projects: &'a [Project], query: &str) -> Vec<&'a Project> {
+    let query = query.to_lowercase();
+    let mut ranked: Vec<(u8, &Project)> = projects
+        .iter()
+        .filter_map(|p| rank(p, &query).map(|r| (r, p)))
+        .collect();
+    ranked.sort_by_key(|(r, _)| *r);
+    ranked.into_iter().map(|(_, p)| p).collect()
+}
+
+/// The single best match for `query`, as used by `dl cd`
+pub fn best_match<'a>(projects: &'a [Project], query: &str) -> Option<&'a Project> {
+    search(projects, query).into_iter().next()
+}
@@ -0,0 +1,39 @@
+//! Disk usage helpers.
+
+use std::fs;
+use std::path::Path;
+
+/// Recursive size of all files under `path`, in bytes
+pub fn get_directory_size(path: &Path) -> u64 {
+    let mut size = 0u64;
+    if path.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                let entry_path = entry.path();
+                if entry_path.is_file() {
+                    size += entry.metadata().map(|m| m.len()).unwrap_or(0);
+                } else if entry_path.is_dir() {
+                    size += get_directory_size(&entry_path);
+                }
+            }
+        }
+    }
+    size
+}
+
+/// Human-readable size such as `1.2 GB` or `340 MB`
+pub fn format_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.0} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.0} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
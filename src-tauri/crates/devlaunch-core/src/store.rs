@@ -0,0 +1,149 @@
+//! The on-disk project library and settings shared by the app and the CLI.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::model::{AppSettings, Project};
+
+/// Directory where DevLaunch keeps its on-disk state (matches the bundle identifier)
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|p| p.join("com.devlaunch.app"))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Per-session directory for sockets and other runtime files
+pub fn runtime_dir() -> PathBuf {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(|dir| PathBuf::from(dir).join("devlaunch"))
+        .unwrap_or_else(data_dir)
+}
+
+/// Data that can be rebuilt at any time, such as commit statistics
+pub fn cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .map(|p| p.join("com.devlaunch.app"))
+        .unwrap_or_else(data_dir)
+}
+
+fn projects_path() -> PathBuf {
+    data_dir().join("projects.json")
+}
+
+fn settings_path() -> PathBuf {
+    data_dir().join("settings.json")
+}
+
+fn write_json<T: serde::Serialize + ?Sized>(path: &Path, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create data directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize: {}", e))?;
+
+    // Write to a temp file first so a crash never leaves a half-written library behind
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, content).map_err(|e| format!("Failed to write {}: {}", tmp_path.display(), e))?;
+    fs::rename(&tmp_path, path).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// The saved library, empty if nothing was saved yet or the file is unreadable. Only for
+/// reading: anything that saves a modified library must start from [`read_projects`].
+pub fn load_projects() -> Vec<Project> {
+    read_projects().unwrap_or_default()
+}
+
+/// The saved library, empty if nothing was saved yet
+pub fn read_projects() -> Result<Vec<Project>, String> {
+    let path = projects_path();
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// Replaces the saved library. A library file that can't be parsed is kept next to it as
+/// `projects.corrupt-<time>.json` instead of being overwritten.
+pub fn save_projects(projects: &[Project]) -> Result<(), String> {
+    let path = projects_path();
+    if path.exists() && read_projects().is_err() {
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let backup = path.with_file_name(format!("projects.corrupt-{}.json", stamp));
+        fs::rename(&path, &backup).map_err(|e| format!("Failed to back up {}: {}", path.display(), e))?;
+    }
+    write_json(&path, projects)
+}
+
+/// Saved settings, falling back to defaults for anything missing
+pub fn load_settings() -> AppSettings {
+    fs::read_to_string(settings_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Replaces the saved settings
+pub fn save_settings(settings: &AppSettings) -> Result<(), String> {
+    write_json(&settings_path(), settings)
+}
+
+/// Adds a freshly scanned project to the front of the library, as the UI does
+pub fn add_project(mut project: Project) -> Result<(Project, Vec<Project>), String> {
+    let mut projects = read_projects()?;
+    if projects.iter().any(|p| Path::new(&p.path) == Path::new(&project.path)) {
+        return Err("Project already exists in library".to_string());
+    }
+
+    project.last_opened = Some(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+    project.open_count = Some(0);
+    project.extra.insert("tags".to_string(), serde_json::json!([]));
+    project.extra.insert("notes".to_string(), serde_json::json!(""));
+
+    projects.insert(0, project.clone());
+    save_projects(&projects)?;
+    Ok((project, projects))
+}
+
+/// Applies `update` to the project with `id` and returns the updated library
+pub fn update_project(id: &str, update: impl FnOnce(&mut Project)) -> Result<Vec<Project>, String> {
+    let mut projects = read_projects()?;
+    let project = projects
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| "Project not found in library".to_string())?;
+    update(project);
+
+    save_projects(&projects)?;
+    Ok(projects)
+}
+
+/// Records that a project was opened outside the UI (tray, CLI, ...) and returns the updated library
+pub fn touch_project(id: &str) -> Result<Vec<Project>, String> {
+    update_project(id, |project| {
+        project.last_active = "Just now".to_string();
+        project.last_opened = Some(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+        project.open_count = Some(project.open_count.unwrap_or(0) + 1);
+    })
+}
+
+/// Adds `tags` the project at `path` doesn't have yet and returns the updated library
+pub fn tag_project(path: &Path, tags: &[String]) -> Result<Vec<Project>, String> {
+    let mut projects = read_projects()?;
+    let project = projects
+        .iter_mut()
+        .find(|p| Path::new(&p.path) == path)
+        .ok_or_else(|| "Project not found in library".to_string())?;
+
+    let mut merged: Vec<String> = project.tags().into_iter().map(str::to_string).collect();
+    for tag in tags {
+        if !merged.contains(tag) {
+            merged.push(tag.clone());
+        }
+    }
+    project.extra.insert("tags".to_string(), serde_json::json!(merged));
+
+    save_projects(&projects)?;
+    Ok(projects)
+}
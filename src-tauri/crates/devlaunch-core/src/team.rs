@@ -0,0 +1,292 @@
+//! Team manifests: a checked-in `devlaunch.team.toml` listing the repositories a team works
+//! on, where they go, how to install them and which ones to launch together.
+//!
+//! ```toml
+//! [workspace]
+//! root = "~/work/acme"      # relative to the manifest when not absolute
+//! tags = ["acme"]
+//!
+//! [[repo]]
+//! name = "api"
+//! url = "git@github.com:acme/api.git"
+//! path = "services/api"     # inside the root, the name by default
+//! branch = "main"
+//! tags = ["backend"]
+//! install = ["npm ci"]
+//!
+//! [[profile]]
+//! name = "backend"
+//! repos = ["api"]
+//! script = "dev"
+//! ```
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::git::clone_repo;
+use crate::launch::{launch_ide, launch_script, read_scripts};
+use crate::model::{CloneOptions, TeamReport, TeamStep};
+use crate::runner::ProcessRunner;
+use crate::scan::scan_path;
+use crate::store;
+
+pub const MANIFEST_FILE: &str = "devlaunch.team.toml";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Workspace {
+    pub root: Option<String>,
+    /// Added to every repository
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TeamRepo {
+    pub name: String,
+    pub url: String,
+    /// Where the repository goes; absolute once the manifest is loaded
+    pub path: Option<String>,
+    pub branch: Option<String>,
+    pub depth: Option<u32>,
+    #[serde(default)]
+    pub recursive: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Shell commands run in order in a fresh clone
+    #[serde(default)]
+    pub install: Vec<String>,
+}
+
+/// Repositories to open together, optionally starting a package script in each
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LaunchProfile {
+    pub name: String,
+    pub repos: Vec<String>,
+    /// IDE to open them in instead of the default one
+    pub ide: Option<String>,
+    pub script: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TeamManifest {
+    #[serde(default)]
+    pub workspace: Workspace,
+    #[serde(default, rename(deserialize = "repo"))]
+    pub repos: Vec<TeamRepo>,
+    #[serde(default, rename(deserialize = "profile"))]
+    pub profiles: Vec<LaunchProfile>,
+}
+
+/// Manifests come from shared repositories, so repos must stay strictly inside the workspace
+fn inside_workspace(relative: &str) -> bool {
+    let path = Path::new(relative);
+    path.components().next().is_some() && path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Reads and checks a manifest, resolving every repository path to an absolute one
+pub fn load_manifest(path: &Path) -> Result<TeamManifest, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut manifest: TeamManifest =
+        toml::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+    let manifest_dir = fs::canonicalize(path)
+        .ok()
+        .and_then(|p| p.parent().map(Path::to_path_buf))
+        .unwrap_or_default();
+    let root = match manifest.workspace.root.as_deref() {
+        Some(root) => manifest_dir.join(expand_home(root)),
+        None => manifest_dir,
+    };
+
+    for repo in &mut manifest.repos {
+        if repo.name.trim().is_empty() || repo.url.trim().is_empty() {
+            return Err("Every repo needs a name and a url".to_string());
+        }
+        let relative = repo.path.clone().unwrap_or_else(|| repo.name.clone());
+        if !inside_workspace(&repo.name) || !inside_workspace(&relative) {
+            return Err(format!("Repo \"{}\" must stay inside the workspace: {}", repo.name, relative));
+        }
+        repo.path = Some(root.join(&relative).to_string_lossy().to_string());
+    }
+    let mut names: Vec<&str> = Vec::new();
+    for repo in &manifest.repos {
+        if names.contains(&repo.name.as_str()) {
+            return Err(format!("Repo \"{}\" is listed twice", repo.name));
+        }
+        names.push(&repo.name);
+    }
+    for profile in &manifest.profiles {
+        if let Some(unknown) = profile.repos.iter().find(|r| !names.contains(&r.as_str())) {
+            return Err(format!("Profile \"{}\" refers to unknown repo \"{}\"", profile.name, unknown));
+        }
+    }
+    Ok(manifest)
+}
+
+fn step(repo: &str, kind: &str, status: &str, message: impl Into<String>) -> TeamStep {
+    TeamStep {
+        repo: repo.to_string(),
+        step: kind.to_string(),
+        status: status.to_string(),
+        message: message.into(),
+        log_file: None,
+    }
+}
+
+/// Clones the manifest's missing repositories, runs their install steps through `runner` and
+/// adds them to the library with their tags. Repositories already on disk are only added and
+/// tagged, unless `reinstall` is set. Every step is reported to `on_step` as it finishes; a
+/// failure skips the rest of that repository but not the others.
+pub fn bootstrap(
+    manifest_path: &Path,
+    runner: &ProcessRunner,
+    reinstall: bool,
+    mut on_step: impl FnMut(&TeamStep),
+) -> Result<TeamReport, String> {
+    let manifest = load_manifest(manifest_path)?;
+    let mut steps = Vec::new();
+    let mut record = |s: TeamStep| {
+        on_step(&s);
+        steps.push(s);
+    };
+
+    for repo in &manifest.repos {
+        let target = PathBuf::from(repo.path.as_deref().unwrap_or_default());
+
+        // Clone
+        let cloned = if target.exists() {
+            if !target.join(".git").exists() {
+                let message = format!("{} exists but is not a git repository", target.display());
+                record(step(&repo.name, "clone", "failed", message));
+                continue;
+            }
+            record(step(&repo.name, "clone", "skipped", "Already cloned"));
+            false
+        } else {
+            let parent = target.parent().unwrap_or(Path::new("."));
+            let options = CloneOptions {
+                branch: repo.branch.clone(),
+                depth: repo.depth,
+                recursive: repo.recursive,
+                directory: target.file_name().map(|n| n.to_string_lossy().to_string()),
+            };
+            let result = fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))
+                .and_then(|_| clone_repo(&repo.url, parent, &options, |_| {}));
+            match result {
+                Ok(_) => record(step(&repo.name, "clone", "ok", format!("Cloned into {}", target.display()))),
+                Err(e) => {
+                    record(step(&repo.name, "clone", "failed", e));
+                    continue;
+                }
+            }
+            true
+        };
+
+        // Install, stopping at the first failing command
+        let path = target.to_string_lossy().to_string();
+        let mut installed = true;
+        if cloned || reinstall {
+            for command in &repo.install {
+                let result = runner.start_command(&path, command).and_then(|run| runner.wait(&run.id));
+                let mut s = match &result {
+                    Ok(run) if run.exit_code == Some(0) => step(&repo.name, "install", "ok", command.as_str()),
+                    Ok(run) => {
+                        let message = match run.exit_code {
+                            Some(code) => format!("{} exited with code {}", command, code),
+                            None => format!("{} was killed", command),
+                        };
+                        step(&repo.name, "install", "failed", message)
+                    }
+                    Err(e) => step(&repo.name, "install", "failed", format!("{}: {}", command, e)),
+                };
+                s.log_file = result.ok().map(|run| run.log_file);
+                let failed = s.status == "failed";
+                record(s);
+                if failed {
+                    installed = false;
+                    break;
+                }
+            }
+        } else if !repo.install.is_empty() {
+            record(step(&repo.name, "install", "skipped", "Already cloned, install steps not run"));
+        }
+
+        // Library
+        let mut tags = manifest.workspace.tags.clone();
+        tags.extend(repo.tags.iter().cloned());
+        let in_library = store::load_projects().iter().any(|p| Path::new(&p.path) == target);
+        let added = if in_library {
+            Ok("Already in library".to_string())
+        } else {
+            scan_path(&path).and_then(|result| {
+                let mut project = result.project;
+                project.name = repo.name.clone();
+                store::add_project(project).map(|_| "Added to library".to_string())
+            })
+        };
+        match added.and_then(|message| store::tag_project(&target, &tags).map(|_| message)) {
+            Ok(message) if installed => record(step(&repo.name, "library", "ok", message)),
+            Ok(message) => record(step(&repo.name, "library", "ok", format!("{}, install incomplete", message))),
+            Err(e) => record(step(&repo.name, "library", "failed", e)),
+        }
+    }
+
+    Ok(TeamReport {
+        manifest: manifest_path.to_string_lossy().to_string(),
+        steps,
+        profiles: manifest.profiles.iter().map(|p| p.name.clone()).collect(),
+    })
+}
+
+/// Opens every repository of a profile in its IDE (`default_ide` unless the profile names one)
+/// and starts the profile's script in those that have it
+pub fn launch_profile(manifest_path: &Path, name: &str, default_ide: &str) -> Result<String, String> {
+    let manifest = load_manifest(manifest_path)?;
+    let profile = manifest
+        .profiles
+        .iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| format!("No profile named \"{}\"", name))?;
+    let ide = profile.ide.as_deref().unwrap_or(default_ide);
+
+    let mut launched = 0;
+    let mut errors = Vec::new();
+    for repo in manifest.repos.iter().filter(|r| profile.repos.contains(&r.name)) {
+        let path = repo.path.as_deref().unwrap_or_default();
+        if !Path::new(path).is_dir() {
+            errors.push(format!("{} is not cloned", repo.name));
+            continue;
+        }
+        if let Err(e) = launch_ide(path, ide) {
+            errors.push(format!("{}: {}", repo.name, e));
+            continue;
+        }
+        if let Some(script) = &profile.script {
+            let scripts = read_scripts(Path::new(path)).unwrap_or_default();
+            if scripts.iter().any(|s| &s.name == script) {
+                if let Err(e) = launch_script(path, script, None) {
+                    errors.push(format!("{}: {}", repo.name, e));
+                }
+            }
+        }
+        launched += 1;
+    }
+
+    if errors.is_empty() {
+        Ok(format!("Launched {} repo(s)", launched))
+    } else if launched > 0 {
+        Ok(format!("Launched {} repo(s). Errors: {}", launched, errors.join(", ")))
+    } else {
+        Err(format!("Failed to launch profile: {}", errors.join(", ")))
+    }
+}
@@ -0,0 +1,304 @@
+//! Detection of installed development tools.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::launch::silent_command;
+use crate::model::ToolVersion;
+
+/// First version-like token printed by `cmd args`, if the command runs
+pub fn get_command_version(cmd: &str, args: &[&str]) -> Option<String> {
+    silent_command(cmd)
+        .args(args)
+        .output()
+        .ok()
+        .and_then(|output| {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                // Some tools output version to stderr
+                let version_str = if stdout.trim().is_empty() { stderr } else { stdout };
+                // Extract version number (common patterns)
+                let version = version_str
+                    .lines()
+                    .next()
+                    .map(|line| {
+                        // Remove common prefixes and extract version
+                        line.replace("node ", "")
+                            .replace("v", "")
+                            .replace("Python ", "")
+                            .replace("java ", "")
+                            .replace("Flutter ", "")
+                            .replace("Dart SDK version: ", "")
+                            .replace("rustc ", "")
+                            .replace("cargo ", "")
+                            .split_whitespace()
+                            .next()
+                            .unwrap_or("")
+                            .to_string()
+                    })
+                    .filter(|v| !v.is_empty());
+                version
+            } else {
+                None
+            }
+        })
+}
+
+/// Installed versions of the development tools shown in the app
+// The Flutter fallbacks are Windows-only and reduce to `None` elsewhere
+#[allow(clippy::unnecessary_lazy_evaluations)]
+pub fn collect_tool_versions() -> Vec<ToolVersion> {
+    let mut tools = Vec::new();
+    
+    // Node.js
+    let node_version = get_command_version("node", &["--version"]);
+    tools.push(ToolVersion {
+        name: "Node.js".to_string(),
+        version: node_version.clone(),
+        installed: node_version.is_some(),
+        icon: "javascript".to_string(),
+    });
+    
+    // Python
+    let python_version = get_command_version("python", &["--version"])
+        .or_else(|| get_command_version("python3", &["--version"]));
+    tools.push(ToolVersion {
+        name: "Python".to_string(),
+        version: python_version.clone(),
+        installed: python_version.is_some(),
+        icon: "code".to_string(),
+    });
+    
+    // Java
+    let java_version = silent_command("java")
+        .args(["-version"])
+        .output()
+        .ok()
+        .and_then(|output| {
+            // Java outputs version to stderr
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            stderr.lines()
+                .next()
+                .and_then(|line| {
+                    // Extract version from "java version "X.X.X"" or "openjdk version "X.X.X""
+                    line.split('"').nth(1).map(|s| s.to_string())
+                })
+        });
+    tools.push(ToolVersion {
+        name: "Java".to_string(),
+        version: java_version.clone(),
+        installed: java_version.is_some(),
+        icon: "coffee".to_string(),
+    });
+    
+    // Flutter - try multiple detection methods for Windows compatibility
+    let flutter_version = {
+        // Helper function to extract Flutter version from output
+        fn extract_flutter_version(output: &std::process::Output) -> Option<String> {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                stdout.lines()
+                    .find(|line| line.starts_with("Flutter"))
+                    .and_then(|line| line.split_whitespace().nth(1).map(|s| s.to_string()))
+            } else {
+                None
+            }
+        }
+        
+        // Try flutter command
+        silent_command("flutter")
+            .args(["--version"])
+            .output()
+            .ok()
+            .and_then(|output| extract_flutter_version(&output))
+            // Try flutter.bat on Windows
+            .or_else(|| {
+                #[cfg(target_os = "windows")]
+                {
+                    silent_command("flutter.bat")
+                        .args(["--version"])
+                        .output()
+                        .ok()
+                        .and_then(|output| extract_flutter_version(&output))
+                }
+                #[cfg(not(target_os = "windows"))]
+                {
+                    None
+                }
+            })
+            // Check common installation paths
+            .or_else(|| {
+                #[cfg(target_os = "windows")]
+                {
+                    let home_dir = dirs::home_dir().unwrap_or_default();
+                    let common_paths = [
+                        home_dir.join("flutter").join("bin").join("flutter.bat"),
+                        home_dir.join("development").join("flutter").join("bin").join("flutter.bat"),
+                        PathBuf::from("C:\\flutter\\bin\\flutter.bat"),
+                        PathBuf::from("C:\\src\\flutter\\bin\\flutter.bat"),
+                    ];
+                    for path in common_paths {
+                        if path.exists() {
+                            if let Ok(output) = silent_command(path.to_str().unwrap_or(""))
+                                .args(["--version"])
+                                .output()
+                            {
+                                if let Some(version) = extract_flutter_version(&output) {
+                                    return Some(version);
+                                }
+                            }
+                        }
+                    }
+                    None
+                }
+                #[cfg(not(target_os = "windows"))]
+                {
+                    None
+                }
+            })
+    };
+    tools.push(ToolVersion {
+        name: "Flutter".to_string(),
+        version: flutter_version.clone(),
+        installed: flutter_version.is_some(),
+        icon: "phone_iphone".to_string(),
+    });
+    
+    // Rust/Cargo
+    let rust_version = get_command_version("rustc", &["--version"]);
+    tools.push(ToolVersion {
+        name: "Rust".to_string(),
+        version: rust_version.clone(),
+        installed: rust_version.is_some(),
+        icon: "memory".to_string(),
+    });
+    
+    // Git
+    let git_version = get_command_version("git", &["--version"])
+        .map(|v| v.replace("git version ", "").split_whitespace().next().unwrap_or("").to_string());
+    tools.push(ToolVersion {
+        name: "Git".to_string(),
+        version: git_version.clone(),
+        installed: git_version.is_some(),
+        icon: "git".to_string(),
+    });
+    
+    tools
+}
+
+/// Whether a tool or IDE from the setup checklist is available on this machine
+pub fn is_tool_installed(tool: &str) -> bool {
+    let (cmd, args): (&str, &[&str]) = match tool {
+        "node" => ("node", &["--version"]),
+        "python" => ("python", &["--version"]),
+        "flutter" => {
+            // Flutter needs special handling - try multiple approaches
+            // First, try flutter.bat on Windows (common issue)
+            #[cfg(target_os = "windows")]
+            {
+                // Try flutter.bat first (Windows)
+                if silent_command("flutter.bat")
+                    .args(["--version"])
+                    .output()
+                    .map(|o| o.status.success())
+                    .unwrap_or(false)
+                {
+                    return true;
+                }
+                // Try flutter without .bat
+                if silent_command("flutter")
+                    .args(["--version"])
+                    .output()
+                    .map(|o| o.status.success())
+                    .unwrap_or(false)
+                {
+                    return true;
+                }
+                // Check common Flutter installation paths on Windows
+                let home_dir = dirs::home_dir().unwrap_or_default();
+                let common_paths = [
+                    home_dir.join("flutter").join("bin").join("flutter.bat"),
+                    home_dir.join("development").join("flutter").join("bin").join("flutter.bat"),
+                    home_dir.join("dev").join("flutter").join("bin").join("flutter.bat"),
+                    PathBuf::from("C:\\flutter\\bin\\flutter.bat"),
+                    PathBuf::from("C:\\src\\flutter\\bin\\flutter.bat"),
+                    PathBuf::from("C:\\development\\flutter\\bin\\flutter.bat"),
+                ];
+                for path in common_paths {
+                    if path.exists() {
+                        return true;
+                    }
+                }
+                return false;
+            }
+            #[cfg(not(target_os = "windows"))]
+            {
+                ("flutter", &["--version"])
+            }
+        }
+        "java" => ("java", &["-version"]),
+        "rust" | "cargo" => ("cargo", &["--version"]),
+        "git" => ("git", &["--version"]),
+        "android-studio" => {
+            #[cfg(target_os = "windows")]
+            {
+                let paths = [
+                    "C:\\Program Files\\Android\\Android Studio\\bin\\studio64.exe",
+                    "C:\\Program Files (x86)\\Android\\Android Studio\\bin\\studio.exe",
+                ];
+                for path in paths {
+                    if PathBuf::from(path).exists() {
+                        return true;
+                    }
+                }
+                return false;
+            }
+            #[cfg(target_os = "macos")]
+            {
+                return PathBuf::from("/Applications/Android Studio.app").exists();
+            }
+            #[cfg(target_os = "linux")]
+            {
+                // Check common Linux paths for Android Studio
+                let paths = [
+                    "/opt/android-studio/bin/studio.sh",
+                    "/usr/local/android-studio/bin/studio.sh",
+                ];
+                for path in paths {
+                    if PathBuf::from(path).exists() {
+                        return true;
+                    }
+                }
+                // Also try the command
+                return Command::new("android-studio")
+                    .arg("--version")
+                    .output()
+                    .map(|o| o.status.success())
+                    .unwrap_or(false);
+            }
+        }
+        "xcode" => {
+            #[cfg(target_os = "macos")]
+            {
+                // Check if Xcode is installed via xcode-select
+                return silent_command("xcode-select")
+                    .args(["-p"])
+                    .output()
+                    .map(|o| o.status.success())
+                    .unwrap_or(false);
+            }
+            #[cfg(not(target_os = "macos"))]
+            {
+                return false;
+            }
+        }
+        _ => return false,
+    };
+    
+    silent_command(cmd)
+        .args(args)
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
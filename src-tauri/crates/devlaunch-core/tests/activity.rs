@@ -0,0 +1,86 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{Duration, Local};
+use common::{git, git_with_env};
+use devlaunch_core::git::{commit_activity, library_activity};
+
+/// Commits `file` as `email`, `days_ago` days back at noon
+fn commit(dir: &Path, file: &str, content: &str, email: &str, days_ago: i64) {
+    fs::write(dir.join(file), content).unwrap();
+    let date = (Local::now().date_naive() - Duration::days(days_ago)).format("%Y-%m-%dT12:00:00").to_string();
+    let env = [
+        ("GIT_AUTHOR_NAME", email.split('@').next().unwrap()),
+        ("GIT_AUTHOR_EMAIL", email),
+        ("GIT_AUTHOR_DATE", &date),
+        ("GIT_COMMITTER_DATE", &date),
+    ];
+    git_with_env(dir, &["add", "."], &env);
+    git_with_env(dir, &["commit", "-q", "-m", file], &env);
+}
+
+fn init(dir: &Path, user: &str) {
+    fs::create_dir_all(dir).unwrap();
+    git(dir, &["init", "-q"]);
+    git(dir, &["config", "user.email", user]);
+}
+
+// Single test so the cache directory override doesn't race with anything else
+#[test]
+fn counts_activity_per_repository_and_library() {
+    let root = tempfile::tempdir().unwrap();
+    std::env::set_var("XDG_CACHE_HOME", root.path().join("cache"));
+
+    let api = root.path().join("api");
+    init(&api, "me@example.com");
+    commit(&api, "old.txt", "old\n", "me@example.com", 40);
+    commit(&api, "main.rs", "a\nb\n", "ann@example.com", 2);
+    commit(&api, "main.rs", "a\nc\nd\n", "me@example.com", 2);
+    commit(&api, "lib.rs", "x\n", "ann@example.com", 0);
+
+    let activity = commit_activity(&api, 7).unwrap();
+    assert_eq!(activity.days, 7);
+    assert_eq!(activity.daily.len(), 7);
+    assert_eq!(activity.commits, 3, "the commit 40 days ago is outside the window");
+    assert_eq!(activity.daily[4].commits, 2);
+    assert_eq!(activity.daily[6].commits, 1);
+    assert_eq!(activity.weekly.iter().map(|w| w.commits).sum::<u32>(), 3);
+    assert_eq!((activity.insertions, activity.deletions), (5, 1));
+
+    let authors: Vec<(&str, u32)> = activity.authors.iter().map(|a| (a.email.as_str(), a.commits)).collect();
+    assert_eq!(authors, [("ann@example.com", 2), ("me@example.com", 1)]);
+    let files: Vec<(&str, u32)> = activity.files.iter().map(|f| (f.path.as_str(), f.commits)).collect();
+    assert_eq!(files, [("main.rs", 2), ("lib.rs", 1)]);
+    assert_eq!(activity.last_own_commit.as_ref().unwrap().summary, "main.rs");
+
+    // Served from the cache until HEAD moves
+    assert_eq!(fs::read_dir(root.path().join("cache/com.devlaunch.app/activity")).unwrap().count(), 1);
+    assert_eq!(commit_activity(&api, 7).unwrap(), activity);
+    commit(&api, "new.txt", "new\n", "me@example.com", 0);
+    let activity = commit_activity(&api, 7).unwrap();
+    assert_eq!(activity.commits, 4);
+    assert_eq!(activity.last_own_commit.unwrap().summary, "new.txt");
+
+    let web = root.path().join("web");
+    init(&web, "me@example.com");
+    commit(&web, "index.html", "<p>\n", "ann@example.com", 1);
+    let empty = root.path().join("empty");
+    init(&empty, "me@example.com");
+    assert_eq!(commit_activity(&empty, 7).unwrap().commits, 0);
+
+    let paths: Vec<String> = [&api, &web, &empty, &root.path().join("missing")]
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    let library = library_activity(&paths, 7);
+    assert_eq!(library.projects.len(), 3, "unreadable repositories are left out");
+    assert_eq!(library.projects[0].path, paths[0]);
+    assert_eq!(library.total.commits, 5);
+    assert_eq!(library.total.daily[5].commits, 1);
+    let authors: Vec<(&str, u32)> = library.total.authors.iter().map(|a| (a.email.as_str(), a.commits)).collect();
+    assert_eq!(authors, [("ann@example.com", 3), ("me@example.com", 2)]);
+    assert!(library.total.files.iter().any(|f| f.path == "web/index.html"));
+    assert_eq!(library.total.last_own_commit.unwrap().summary, "new.txt");
+}
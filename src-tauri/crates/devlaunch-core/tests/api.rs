@@ -0,0 +1,80 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::sync::Arc;
+
+use devlaunch_core::api::ApiServer;
+use devlaunch_core::runner::ProcessRunner;
+use serde_json::{json, Value};
+
+struct Client {
+    writer: UnixStream,
+    reader: BufReader<UnixStream>,
+    next_id: u64,
+}
+
+impl Client {
+    fn connect(socket: &Path) -> Self {
+        let writer = UnixStream::connect(socket).unwrap();
+        let reader = BufReader::new(writer.try_clone().unwrap());
+        Client { writer, reader, next_id: 1 }
+    }
+
+    fn call(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        writeln!(self.writer, "{}", request).unwrap();
+        let mut line = String::new();
+        self.reader.read_line(&mut line).unwrap();
+        let response: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(response["id"], id);
+        response
+    }
+}
+
+// Single test so the environment overrides don't race with anything else
+#[test]
+fn serves_authorized_clients_only() {
+    let home = tempfile::tempdir().unwrap();
+    std::env::set_var("XDG_DATA_HOME", home.path().join("data"));
+    std::env::set_var("XDG_RUNTIME_DIR", home.path().join("run"));
+
+    let project = home.path().join("web");
+    fs::create_dir_all(project.join("node_modules/.vite")).unwrap();
+    fs::create_dir_all(project.join("dist")).unwrap();
+    fs::write(project.join("dist/index.js"), "console.log(1)").unwrap();
+    fs::write(project.join("package.json"), r#"{"scripts":{"build":"vite build"}}"#).unwrap();
+
+    let socket = home.path().join("api.sock");
+    let token_file = home.path().join("api.token");
+    let server = ApiServer::start_at(socket.clone(), token_file.clone(), Arc::new(ProcessRunner::default())).unwrap();
+
+    assert_eq!(fs::metadata(&token_file).unwrap().permissions().mode() & 0o777, 0o600);
+    let token = fs::read_to_string(&token_file).unwrap();
+
+    let mut client = Client::connect(&socket);
+    assert_eq!(client.call("library.list", json!({}))["error"]["code"], -32001);
+    assert_eq!(client.call("auth", json!({ "token": "wrong" }))["error"]["code"], -32001);
+    assert_eq!(client.call("library.list", json!({}))["error"]["code"], -32001);
+
+    assert_eq!(client.call("auth", json!({ "token": token }))["result"], true);
+    assert_eq!(client.call("library.list", json!({}))["result"], json!([]));
+    assert_eq!(client.call("no.such", json!({}))["error"]["code"], -32601);
+    assert_eq!(client.call("git.status", json!({}))["error"]["code"], -32602);
+
+    let plan = client.call("cleanup.plan", json!({ "path": project, "projectType": "node" }));
+    let folders = plan["result"]["folders"].as_array().unwrap();
+    assert!(folders.iter().any(|f| f["folder"] == "dist" && f["bytes"].as_u64() > Some(0)));
+
+    let run = client.call("script.run", json!({ "path": project, "script": "missing" }));
+    assert_eq!(run["error"]["code"], -32602);
+
+    drop(server);
+    assert!(!socket.exists());
+    assert!(!token_file.exists());
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::Path;
+
+use devlaunch_core::cleanup::{clean_build_folders, detect_build_folder, plan_build_cleanup, remove_node_modules};
+use devlaunch_core::size::{format_size, get_directory_size};
+
+fn write_bytes(path: &Path, len: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, vec![0u8; len]).unwrap();
+}
+
+#[test]
+fn directory_size_is_recursive() {
+    let dir = tempfile::tempdir().unwrap();
+    write_bytes(&dir.path().join("a.bin"), 100);
+    write_bytes(&dir.path().join("nested/deeper/b.bin"), 250);
+
+    assert_eq!(get_directory_size(dir.path()), 350);
+    assert_eq!(get_directory_size(&dir.path().join("missing")), 0);
+}
+
+#[test]
+fn sizes_are_human_readable() {
+    assert_eq!(format_size(512), "512 B");
+    assert_eq!(format_size(2048), "2 KB");
+    assert_eq!(format_size(5 * 1024 * 1024), "5 MB");
+    assert_eq!(format_size(3 * 1024 * 1024 * 1024 / 2), "1.5 GB");
+}
+
+#[test]
+fn plans_and_cleans_rust_target() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"demo\"\n").unwrap();
+    write_bytes(&dir.path().join("target/debug/demo"), 4096);
+
+    assert_eq!(detect_build_folder(dir.path(), "rust"), Some(("target".to_string(), 4096)));
+    assert_eq!(plan_build_cleanup(dir.path(), "rust"), [("target".to_string(), 4096)]);
+
+    // Project type is detected from Cargo.toml when not given
+    let message = clean_build_folders(&dir.path().to_string_lossy(), None).unwrap();
+    assert_eq!(message, "Cleaned 1 folder(s), freed 4 KB");
+    assert!(!dir.path().join("target").exists());
+    assert!(dir.path().join("Cargo.toml").exists());
+
+    let message = clean_build_folders(&dir.path().to_string_lossy(), None).unwrap();
+    assert_eq!(message, "No build folders to clean");
+}
+
+#[test]
+fn cleans_only_listed_node_folders() {
+    let dir = tempfile::tempdir().unwrap();
+    write_bytes(&dir.path().join("dist/index.js"), 10);
+    write_bytes(&dir.path().join(".next/cache/x"), 10);
+    write_bytes(&dir.path().join("src/index.ts"), 10);
+
+    let planned: Vec<String> = plan_build_cleanup(dir.path(), "node").into_iter().map(|(f, _)| f).collect();
+    assert_eq!(planned, ["dist", ".next"]);
+
+    clean_build_folders(&dir.path().to_string_lossy(), Some("node".to_string())).unwrap();
+    assert!(!dir.path().join("dist").exists());
+    assert!(!dir.path().join(".next").exists());
+    assert!(dir.path().join("src/index.ts").exists());
+}
+
+#[test]
+fn removes_node_modules() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().to_string_lossy().to_string();
+    assert!(remove_node_modules(&path).is_err());
+
+    write_bytes(&dir.path().join("node_modules/left-pad/index.js"), 10);
+    remove_node_modules(&path).unwrap();
+    assert!(!dir.path().join("node_modules").exists());
+}
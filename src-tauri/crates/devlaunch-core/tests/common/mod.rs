@@ -0,0 +1,58 @@
+//! Helpers shared by the integration tests: library projects and driving a real `git`
+
+// Every test crate compiles this module but only uses some of it
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use devlaunch_core::Project;
+
+/// A library project as the frontend saves it, with `tags` among the frontend-only fields
+pub fn project(id: &str, name: &str, path: &str, tags: &[&str]) -> Project {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "name": name,
+        "path": path,
+        "icon": "code",
+        "techStack": [],
+        "gitStatus": { "branch": "main", "status": "Clean", "count": null, "type": "success" },
+        "lastActive": "Just now",
+        "storage": "< 1 MB",
+        "buildStorage": null,
+        "isArchived": false,
+        "hasNodeModules": false,
+        "hasBuildFolder": false,
+        "buildFolderName": null,
+        "packageManager": "npm",
+        "projectType": "node",
+        "tags": tags,
+    }))
+    .unwrap()
+}
+
+pub fn git(dir: &Path, args: &[&str]) {
+    git_with_env(dir, args, &[]);
+}
+
+/// Runs git as the test identity, with `env` overriding it or setting anything else
+pub fn git_with_env(dir: &Path, args: &[&str], env: &[(&str, &str)]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .envs(env.iter().copied())
+        .status()
+        .expect("git must be installed to run these tests");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+pub fn commit(dir: &Path, file: &str, content: &str) {
+    fs::write(dir.join(file), content).unwrap();
+    git(dir, &["add", "."]);
+    git(dir, &["commit", "-q", "-m", file]);
+}
@@ -0,0 +1,61 @@
+use devlaunch_core::deeplink::{is_deep_link, parse, DeepLink, LinkTarget};
+
+#[test]
+fn parses_each_action() {
+    assert_eq!(
+        parse("devlaunch://open?path=%2Fhome%2Fme%2Fapi&ide=cursor").unwrap(),
+        DeepLink::Open { target: LinkTarget::Path("/home/me/api".to_string()), ide: Some("cursor".to_string()) }
+    );
+    assert_eq!(
+        parse("devlaunch://open?project=api").unwrap(),
+        DeepLink::Open { target: LinkTarget::Project("api".to_string()), ide: None }
+    );
+    assert_eq!(
+        parse("devlaunch://terminal?project=web%20admin").unwrap(),
+        DeepLink::Terminal { target: LinkTarget::Project("web admin".to_string()) }
+    );
+    assert_eq!(
+        parse("devlaunch://run?project=api&script=test:unit").unwrap(),
+        DeepLink::Run { target: LinkTarget::Project("api".to_string()), script: "test:unit".to_string() }
+    );
+    assert_eq!(parse("devlaunch:add?path=/srv/app").unwrap(), DeepLink::Add { path: "/srv/app".to_string() });
+}
+
+#[test]
+fn only_run_needs_confirmation() {
+    assert!(parse("devlaunch://run?project=api&script=dev").unwrap().needs_confirmation());
+    assert!(!parse("devlaunch://open?project=api").unwrap().needs_confirmation());
+    assert!(!parse("devlaunch://add?path=/srv/app").unwrap().needs_confirmation());
+}
+
+#[test]
+fn rejects_invalid_links() {
+    let invalid = [
+        "https://open?project=api",
+        "devlaunch://delete?project=api",
+        "devlaunch://open",
+        "devlaunch://open?project=",
+        "devlaunch://open?path=/a&project=api",
+        "devlaunch://open?project=api&project=web",
+        "devlaunch://open?project=api&ide=rm%20-rf",
+        "devlaunch://open?path=relative/dir",
+        "devlaunch://open?path=/home/me/../../etc",
+        "devlaunch://terminal?project=api&script=dev",
+        "devlaunch://run?project=api",
+        "devlaunch://run?project=api&script=dev%3B%20rm%20-rf%20~",
+        "devlaunch://run?project=api&script=--help",
+        "devlaunch://add?project=api",
+    ];
+    for link in invalid {
+        assert!(parse(link).is_err(), "{} should be rejected", link);
+    }
+}
+
+#[test]
+fn recognizes_link_arguments() {
+    assert!(is_deep_link("devlaunch://open?project=api"));
+    assert!(is_deep_link("DevLaunch:open?project=api"));
+    assert!(!is_deep_link("/home/me/devlaunch"));
+    assert!(!is_deep_link("devlaunch"));
+    assert!(!is_deep_link("ü"));
+}
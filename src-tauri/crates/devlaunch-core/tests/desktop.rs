@@ -0,0 +1,61 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use devlaunch_core::desktop::{exec_arg, launcher_entry, launcher_file_name, sync_launchers, write_launcher};
+use devlaunch_core::Project;
+
+fn project(id: &str, path: &Path) -> Project {
+    let mut project = common::project(id, "Web App", &path.to_string_lossy(), &["work"]);
+    project.has_launcher = Some(true);
+    project
+}
+
+#[test]
+fn exec_arguments_are_quoted_and_escaped() {
+    assert_eq!(exec_arg("/opt/dev launch"), "\"/opt/dev launch\"");
+    assert_eq!(exec_arg("/home/me/100%"), "\"/home/me/100%%\"");
+    assert_eq!(exec_arg("/tmp/$HOME\"x"), "\"/tmp/\\\\$HOME\\\\\"x\"");
+}
+
+#[test]
+fn entry_opens_project_and_offers_actions() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("package.json"), r#"{"scripts":{"dev":"vite","build":"vite build"}}"#).unwrap();
+    let p = project("abc-123", dir.path());
+
+    let entry = launcher_entry(&p, Path::new("/usr/bin/devlaunch"));
+    let path = exec_arg(&dir.path().to_string_lossy());
+    assert!(entry.starts_with("[Desktop Entry]\n"));
+    assert!(entry.contains("Name=Web App\n"));
+    assert!(entry.contains("Keywords=devlaunch;work;\n"));
+    assert!(entry.contains(&format!("Exec=\"/usr/bin/devlaunch\" {}\n", path)));
+    assert!(entry.contains("Actions=terminal;run;\n"));
+    assert!(entry.contains(&format!("Exec=\"/usr/bin/devlaunch\" --terminal {}\n", path)));
+    assert!(entry.contains(&format!("Exec=\"/usr/bin/devlaunch\" \"--run=dev\" {}\n", path)));
+}
+
+#[test]
+fn sync_refreshes_and_removes_launchers() {
+    let apps = tempfile::tempdir().unwrap();
+    let project_dir = tempfile::tempdir().unwrap();
+    let exe = Path::new("/usr/bin/devlaunch");
+
+    let kept = project("kept", project_dir.path());
+    let dropped = project("dropped", project_dir.path());
+    write_launcher(apps.path(), &kept, exe).unwrap();
+    write_launcher(apps.path(), &dropped, exe).unwrap();
+    fs::write(apps.path().join("other.desktop"), "[Desktop Entry]\n").unwrap();
+
+    let mut renamed = kept.clone();
+    renamed.name = "Renamed".to_string();
+    let mut disabled = dropped.clone();
+    disabled.has_launcher = Some(false);
+    sync_launchers(apps.path(), &[renamed, disabled], exe).unwrap();
+
+    let kept_entry = fs::read_to_string(apps.path().join(launcher_file_name("kept"))).unwrap();
+    assert!(kept_entry.contains("Name=Renamed\n"));
+    assert!(!apps.path().join(launcher_file_name("dropped")).exists());
+    assert!(apps.path().join("other.desktop").exists());
+}
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use devlaunch_core::detect::{detect_package_manager, detect_project_type, detect_tech_stack, get_project_icon};
+use devlaunch_core::scan::{apply_scan, discover_projects, scan_path};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn stack_names(name: &str) -> Vec<String> {
+    detect_tech_stack(&fixture(name)).into_iter().map(|t| t.name).collect()
+}
+
+#[test]
+fn package_manager_follows_lockfile() {
+    assert_eq!(detect_package_manager(&fixture("react-vite")).as_deref(), Some("pnpm"));
+    assert_eq!(detect_package_manager(&fixture("expo-app")).as_deref(), Some("yarn"));
+    assert_eq!(detect_package_manager(&fixture("tauri-app")).as_deref(), Some("bun"));
+    assert_eq!(detect_package_manager(&fixture("next-app")).as_deref(), Some("npm"));
+    assert_eq!(detect_package_manager(&fixture("rust-crate")), None);
+}
+
+#[test]
+fn project_type_per_ecosystem() {
+    let cases = [
+        ("react-vite", "node"),
+        ("flutter-app", "flutter"),
+        ("android-app", "android"),
+        ("rust-crate", "rust"),
+        ("django-api", "python"),
+        ("fastapi-service", "python"),
+        ("go-service", "go"),
+        ("plain-folder", "other"),
+    ];
+    for (name, expected) in cases {
+        assert_eq!(detect_project_type(&fixture(name)), expected, "{}", name);
+    }
+}
+
+#[test]
+fn tech_stack_from_package_json() {
+    assert_eq!(stack_names("react-vite"), ["React", "Vite", "TypeScript", "pnpm"]);
+    // React Native wins over plain React
+    assert_eq!(stack_names("expo-app"), ["React Native", "Expo", "yarn"]);
+    assert_eq!(stack_names("next-app"), ["React", "Next.js", "npm"]);
+    assert_eq!(stack_names("tauri-app"), ["Tauri", "bun"]);
+}
+
+#[test]
+fn tech_stack_from_other_manifests() {
+    assert_eq!(stack_names("flutter-app"), ["Flutter", "Dart"]);
+    assert_eq!(stack_names("android-app"), ["Android", "Kotlin", "Compose"]);
+    assert_eq!(stack_names("rust-crate"), ["Rust"]);
+    assert_eq!(stack_names("django-api"), ["Python", "Django"]);
+    assert_eq!(stack_names("fastapi-service"), ["Python", "FastAPI"]);
+    assert_eq!(stack_names("go-service"), ["Go"]);
+    assert!(stack_names("plain-folder").is_empty());
+}
+
+#[test]
+fn icon_prefers_mobile_desktop_and_backend() {
+    let icon = |name: &str| get_project_icon(&detect_tech_stack(&fixture(name)), &fixture(name));
+    assert_eq!(icon("expo-app"), "phone_iphone");
+    assert_eq!(icon("android-app"), "phone_android");
+    assert_eq!(icon("tauri-app"), "desktop_windows");
+    assert_eq!(icon("django-api"), "dns");
+    assert_eq!(icon("react-vite"), "code");
+    assert_eq!(icon("rust-crate"), "memory");
+    assert_eq!(icon("go-service"), "speed");
+    assert_eq!(icon("plain-folder"), "folder");
+}
+
+#[test]
+fn discover_finds_fixture_projects() {
+    let found = discover_projects(&fixture(""), 1);
+    let names: Vec<String> = found
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "android-app",
+            "django-api",
+            "expo-app",
+            "fastapi-service",
+            "flutter-app",
+            "go-service",
+            "next-app",
+            "react-vite",
+            "rust-crate",
+            "tauri-app",
+        ]
+    );
+    assert!(discover_projects(&fixture(""), 0).is_empty());
+}
+
+#[test]
+fn scan_fills_in_project() {
+    let path = fixture("react-vite");
+    let result = scan_path(&path.to_string_lossy()).unwrap();
+    let project = result.project;
+
+    assert_eq!(project.name, "react-vite");
+    assert_eq!(project.project_type.as_deref(), Some("node"));
+    assert_eq!(project.package_manager.as_deref(), Some("pnpm"));
+    assert_eq!(project.icon, "code");
+    assert!(!project.has_node_modules);
+    assert!(!project.has_build_folder);
+
+    assert!(scan_path(&fixture("missing").to_string_lossy()).is_err());
+    assert!(scan_path(&path.join("package.json").to_string_lossy()).is_err());
+}
+
+#[test]
+fn rescans_keep_identity_and_user_fields() {
+    let mut project = scan_path(&fixture("react-vite").to_string_lossy()).unwrap().project;
+    project.name = "Storefront".to_string();
+    project.is_pinned = Some(true);
+    project.extra.insert("tags".to_string(), serde_json::json!(["web"]));
+    let id = project.id.clone();
+
+    let mut scanned = scan_path(&fixture("rust-crate").to_string_lossy()).unwrap().project;
+    scanned.has_build_folder = true;
+    apply_scan(&mut project, scanned);
+    assert_eq!((project.id.as_str(), project.name.as_str()), (id.as_str(), "Storefront"));
+    assert_eq!(project.is_pinned, Some(true));
+    assert_eq!(project.tags(), ["web"]);
+    assert_eq!(project.project_type.as_deref(), Some("rust"));
+    assert!(project.has_build_folder);
+}
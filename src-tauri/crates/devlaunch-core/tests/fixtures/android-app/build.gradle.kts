@@ -0,0 +1,6 @@
+plugins {
+    id("com.android.application") version "8.5.0" apply false
+    id("org.jetbrains.kotlin.android") version "1.9.0" apply false
+}
+
+// Jetpack compose is configured in app/build.gradle.kts
@@ -0,0 +1,678 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::{commit, git};
+use devlaunch_core::cleanup::remove_duplicate_clone;
+use devlaunch_core::CloneOptions;
+use devlaunch_core::git::{
+    add_worktree, apply_stash, clone_folder_name, clone_repo, create_branch, create_stash, delete_merged_branches, diff_summary, drop_stash,
+    fetch_tracked, find_duplicates, get_git_info, list_branches, list_stashes, list_worktrees, prune_worktrees, pull_repo,
+    recent_commits, remote_links, remove_worktree, run_bulk, switch_branch, unpushed_work, update_submodules, BulkOp,
+};
+
+#[test]
+fn no_git_outside_repository() {
+    let dir = tempfile::tempdir().unwrap();
+    let status = get_git_info(dir.path());
+    assert_eq!(status.branch, "No Git");
+    assert_eq!(status.status_type, "neutral");
+}
+
+#[test]
+fn reports_branch_and_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    fs::write(dir.path().join("README.md"), "hello\n").unwrap();
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "-q", "-m", "init"]);
+
+    let clean = get_git_info(dir.path());
+    assert_eq!(clean.branch, "main");
+    assert_eq!(clean.status, "Clean");
+    assert_eq!(clean.count, None);
+
+    fs::write(dir.path().join("README.md"), "changed\n").unwrap();
+    fs::write(dir.path().join("new.txt"), "new\n").unwrap();
+    let dirty = get_git_info(dir.path());
+    assert_eq!(dirty.status, "Modified");
+    assert_eq!(dirty.count, Some(2));
+    assert_eq!(dirty.status_type, "warning");
+}
+
+#[test]
+fn counts_staged_unstaged_and_untracked() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    commit(dir.path(), "a.txt", "a\n");
+    commit(dir.path(), "b.txt", "b\n");
+
+    fs::write(dir.path().join("a.txt"), "staged\n").unwrap();
+    git(dir.path(), &["add", "a.txt"]);
+    fs::write(dir.path().join("a.txt"), "staged, then changed again\n").unwrap();
+    fs::write(dir.path().join("b.txt"), "changed\n").unwrap();
+    fs::write(dir.path().join("c.txt"), "new\n").unwrap();
+
+    let status = get_git_info(dir.path());
+    assert_eq!((status.staged, status.unstaged, status.untracked), (1, 2, 1));
+    assert_eq!(status.count, Some(3));
+    assert_eq!(status.upstream, None);
+    assert_eq!(status.ahead, None);
+}
+
+#[test]
+fn tracks_ahead_and_behind_upstream() {
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let mine = root.path().join("mine");
+    let theirs = root.path().join("theirs");
+
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), mine.to_str().unwrap()]);
+    commit(&mine, "a.txt", "a\n");
+    git(&mine, &["push", "-q", "-u", "origin", "main"]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), theirs.to_str().unwrap()]);
+
+    let synced = get_git_info(&mine);
+    assert_eq!(synced.upstream.as_deref(), Some("origin/main"));
+    assert_eq!((synced.ahead, synced.behind), (Some(0), Some(0)));
+    assert_eq!(synced.status, "Clean");
+
+    commit(&mine, "b.txt", "b\n");
+    let ahead = get_git_info(&mine);
+    assert_eq!((ahead.ahead, ahead.behind), (Some(1), Some(0)));
+    assert_eq!(ahead.status, "Ahead");
+
+    commit(&theirs, "c.txt", "c\n");
+    commit(&theirs, "d.txt", "d\n");
+    git(&theirs, &["push", "-q"]);
+    git(&mine, &["fetch", "-q"]);
+    let diverged = get_git_info(&mine);
+    assert_eq!((diverged.ahead, diverged.behind), (Some(1), Some(2)));
+    assert_eq!(diverged.status, "Diverged");
+}
+
+#[test]
+fn detects_detached_head_and_merge_in_progress() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    commit(dir.path(), "a.txt", "base\n");
+    git(dir.path(), &["checkout", "-q", "-b", "feature"]);
+    commit(dir.path(), "a.txt", "feature\n");
+    git(dir.path(), &["checkout", "-q", "main"]);
+    commit(dir.path(), "a.txt", "main\n");
+
+    git(dir.path(), &["checkout", "-q", "--detach", "HEAD~1"]);
+    let detached = get_git_info(dir.path());
+    assert!(detached.detached);
+    assert_eq!(detached.status, "Detached");
+    assert_eq!(detached.branch.len(), 7);
+
+    git(dir.path(), &["checkout", "-q", "main"]);
+    let merge = Command::new("git")
+        .args(["merge", "-q", "feature"])
+        .current_dir(dir.path())
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .output()
+        .unwrap();
+    assert!(!merge.status.success(), "merge should conflict");
+
+    let merging = get_git_info(dir.path());
+    assert_eq!(merging.operation.as_deref(), Some("merge"));
+    assert_eq!(merging.conflicted, 1);
+    assert_eq!(merging.status, "Merging");
+    assert_eq!(merging.status_type, "error");
+}
+
+#[cfg(feature = "git2")]
+#[test]
+fn in_process_backend_matches_cli() {
+    use devlaunch_core::git::{CliBackend, Git2Backend, GitBackend};
+
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let repo = root.path().join("repo");
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["init", "-q", "-b", "main", repo.to_str().unwrap()]);
+
+    let empty = |b: &dyn GitBackend| (b.status(&repo).unwrap().branch, b.log(&repo, None, 5).unwrap());
+    assert_eq!(empty(&CliBackend), empty(&Git2Backend));
+    assert_eq!(empty(&Git2Backend), ("main".to_string(), Vec::new()));
+
+    commit(&repo, "a.txt", "a\n");
+    commit(&repo, "b.txt", "b\n");
+    git(&repo, &["remote", "add", "origin", remote.to_str().unwrap()]);
+    git(&repo, &["push", "-q", "-u", "origin", "main"]);
+    commit(&repo, "c.txt", "c\n");
+
+    fs::write(repo.join("a.txt"), "staged\n").unwrap();
+    git(&repo, &["add", "a.txt"]);
+    fs::write(repo.join("a.txt"), "staged and changed\n").unwrap();
+    git(&repo, &["mv", "b.txt", "renamed.txt"]);
+    fs::create_dir(repo.join("new-dir")).unwrap();
+    fs::write(repo.join("new-dir/one.txt"), "1\n").unwrap();
+    fs::write(repo.join("new-dir/two.txt"), "2\n").unwrap();
+
+    let summary = |b: &dyn GitBackend| {
+        let s = b.status(&repo).unwrap();
+        (s.branch, s.upstream, s.ahead, s.behind, s.staged, s.unstaged, s.untracked, s.count)
+    };
+    assert_eq!(summary(&CliBackend), summary(&Git2Backend));
+    assert_eq!(summary(&Git2Backend).2, Some(1));
+
+    let cli_log = CliBackend.log(&repo, None, 2).unwrap();
+    assert_eq!(cli_log.len(), 2);
+    assert_eq!(cli_log, Git2Backend.log(&repo, None, 2).unwrap());
+
+    git(&repo, &["checkout", "-q", "--detach", "HEAD~1"]);
+    let detached = |b: &dyn GitBackend| {
+        let s = b.status(&repo).unwrap();
+        (s.branch, s.detached, s.upstream)
+    };
+    assert_eq!(detached(&CliBackend), detached(&Git2Backend));
+}
+
+#[test]
+fn lists_local_and_remote_branches() {
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let repo = root.path().join("repo");
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), repo.to_str().unwrap()]);
+    commit(&repo, "a.txt", "a\n");
+    git(&repo, &["push", "-q", "-u", "origin", "main"]);
+    commit(&repo, "b.txt", "b\n");
+    create_branch(&repo, "feature", Some("HEAD~1"), false).unwrap();
+
+    let branches = list_branches(&repo).unwrap();
+    let names: Vec<_> = branches.iter().map(|b| (b.name.as_str(), b.is_remote)).collect();
+    assert_eq!(names, [("main", false), ("feature", false), ("origin/main", true)]);
+
+    let main = &branches[0];
+    assert!(main.is_current);
+    assert_eq!(main.upstream.as_deref(), Some("origin/main"));
+    assert_eq!((main.ahead, main.behind), (1, 0));
+    assert_eq!(main.summary, "b.txt");
+    assert!(!main.last_commit_date.is_empty());
+    assert_eq!(branches[1].summary, "a.txt");
+
+    assert!(create_branch(&repo, "bad..name", None, false).is_err());
+    assert!(create_branch(&repo, "feature2", Some("--orphan"), false).is_err());
+    assert!(switch_branch(&repo, "--detach", false).is_err());
+}
+
+#[test]
+fn switching_refuses_a_dirty_tree_unless_stashing() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    commit(dir.path(), "a.txt", "a\n");
+    create_branch(dir.path(), "feature", None, false).unwrap();
+
+    fs::write(dir.path().join("a.txt"), "changed\n").unwrap();
+    assert!(switch_branch(dir.path(), "feature", false).is_err());
+    assert_eq!(get_git_info(dir.path()).branch, "main");
+
+    // A failed switch hands the stashed changes back
+    assert!(switch_branch(dir.path(), "missing", true).is_err());
+    assert_eq!(fs::read_to_string(dir.path().join("a.txt")).unwrap(), "changed\n");
+    assert!(list_stashes(dir.path()).unwrap().is_empty());
+
+    let message = switch_branch(dir.path(), "feature", true).unwrap();
+    assert!(message.contains("stashed"), "{}", message);
+    let status = get_git_info(dir.path());
+    assert_eq!((status.branch.as_str(), status.status.as_str()), ("feature", "Clean"));
+}
+
+#[test]
+fn deletes_only_branches_merged_into_the_default_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    commit(dir.path(), "a.txt", "a\n");
+    create_branch(dir.path(), "merged", None, true).unwrap();
+    commit(dir.path(), "b.txt", "b\n");
+    git(dir.path(), &["checkout", "-q", "main"]);
+    git(dir.path(), &["merge", "-q", "--ff-only", "merged"]);
+    create_branch(dir.path(), "unmerged", None, true).unwrap();
+    commit(dir.path(), "c.txt", "c\n");
+    create_branch(dir.path(), "current", Some("main"), true).unwrap();
+
+    assert_eq!(delete_merged_branches(dir.path(), true).unwrap(), ["merged"]);
+    assert_eq!(delete_merged_branches(dir.path(), false).unwrap(), ["merged"]);
+    let left: Vec<_> = list_branches(dir.path()).unwrap().into_iter().map(|b| b.name).collect();
+    assert_eq!(left.len(), 3);
+    assert!(!left.contains(&"merged".to_string()));
+}
+
+#[test]
+fn stashes_can_be_listed_applied_and_dropped() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    git(dir.path(), &["config", "user.name", "Test"]);
+    git(dir.path(), &["config", "user.email", "test@example.com"]);
+    commit(dir.path(), "a.txt", "a\n");
+    assert!(create_stash(dir.path(), None, false).is_err(), "nothing to stash");
+
+    fs::write(dir.path().join("a.txt"), "changed\n").unwrap();
+    create_stash(dir.path(), Some("first"), false).unwrap();
+    fs::write(dir.path().join("new.txt"), "untracked\n").unwrap();
+    create_stash(dir.path(), Some("second"), true).unwrap();
+    assert_eq!(get_git_info(dir.path()).status, "Clean");
+
+    let stashes = list_stashes(dir.path()).unwrap();
+    let summary: Vec<_> = stashes.iter().map(|s| (s.index, s.message.as_str(), s.branch.as_deref())).collect();
+    assert_eq!(summary, [(0, "second", Some("main")), (1, "first", Some("main"))]);
+    assert!(!stashes[0].date.is_empty());
+
+    assert_eq!(apply_stash(dir.path(), 1, true).unwrap(), Vec::<String>::new());
+    assert_eq!(fs::read_to_string(dir.path().join("a.txt")).unwrap(), "changed\n");
+    drop_stash(dir.path(), 0).unwrap();
+    assert!(list_stashes(dir.path()).unwrap().is_empty());
+}
+
+#[test]
+fn pull_can_stash_local_changes_around_it() {
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let mine = root.path().join("mine");
+    let theirs = root.path().join("theirs");
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), mine.to_str().unwrap()]);
+    git(&mine, &["config", "user.name", "Test"]);
+    git(&mine, &["config", "user.email", "test@example.com"]);
+    git(&mine, &["config", "pull.rebase", "false"]);
+    commit(&mine, "a.txt", "a\n");
+    commit(&mine, "b.txt", "b\n");
+    git(&mine, &["push", "-q", "-u", "origin", "main"]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), theirs.to_str().unwrap()]);
+    commit(&theirs, "a.txt", "theirs\n");
+    git(&theirs, &["push", "-q"]);
+
+    // Without stashing, changes to a file the pull touches block it
+    fs::write(mine.join("a.txt"), "mine\n").unwrap();
+    assert!(pull_repo(mine.to_str().unwrap(), false).is_err());
+
+    // Stashed and restored, but the local edit conflicts with the incoming one
+    let result = pull_repo(mine.to_str().unwrap(), true).unwrap();
+    assert!(result.stashed && !result.restored);
+    assert_eq!(result.conflicts, ["a.txt"]);
+    assert_eq!(list_stashes(&mine).unwrap().len(), 1, "the stash is kept until resolved");
+
+    git(&mine, &["reset", "-q", "--hard"]);
+    git(&mine, &["stash", "drop", "-q"]);
+    commit(&theirs, "c.txt", "c\n");
+    git(&theirs, &["push", "-q"]);
+    fs::write(mine.join("b.txt"), "mine\n").unwrap();
+    let result = pull_repo(mine.to_str().unwrap(), true).unwrap();
+    assert!(result.stashed && result.restored && result.conflicts.is_empty());
+    assert_eq!(fs::read_to_string(mine.join("b.txt")).unwrap(), "mine\n");
+    assert!(mine.join("c.txt").exists());
+}
+
+#[test]
+fn log_reports_change_stats_from_any_branch() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    commit(dir.path(), "a.txt", "one\ntwo\nthree\n");
+    create_branch(dir.path(), "feature", None, true).unwrap();
+    fs::write(dir.path().join("a.txt"), "one\n2\nthree\nfour\n").unwrap();
+    fs::write(dir.path().join("b.txt"), "b\n").unwrap();
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "-q", "-m", "feature work"]);
+    git(dir.path(), &["checkout", "-q", "main"]);
+
+    let main = recent_commits(dir.path(), None, 10).unwrap();
+    assert_eq!(main.len(), 1);
+    assert_eq!((main[0].files_changed, main[0].insertions, main[0].deletions), (1, 3, 0));
+
+    let feature = recent_commits(dir.path(), Some("feature"), 1).unwrap();
+    assert_eq!(feature[0].summary, "feature work");
+    assert_eq!((feature[0].files_changed, feature[0].insertions, feature[0].deletions), (2, 3, 1));
+
+    assert!(recent_commits(dir.path(), Some("missing"), 1).is_err());
+    assert!(recent_commits(dir.path(), Some("--all"), 1).is_err());
+}
+
+#[test]
+fn diff_summary_lists_each_changed_file() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    commit(dir.path(), "a.txt", "one\ntwo\n");
+    commit(dir.path(), "old name.txt", "same\ncontent\nhere\n");
+    commit(dir.path(), "gone.txt", "bye\n");
+
+    fs::write(dir.path().join("a.txt"), "one\nTWO\nthree\n").unwrap();
+    git(dir.path(), &["mv", "old name.txt", "new name.txt"]);
+    fs::remove_file(dir.path().join("gone.txt")).unwrap();
+    fs::write(dir.path().join("notes.txt"), "a\nb").unwrap();
+
+    let summary = diff_summary(dir.path()).unwrap();
+    let files: Vec<_> = summary
+        .files
+        .iter()
+        .map(|f| (f.path.as_str(), f.status.as_str(), f.staged, f.unstaged, f.insertions, f.deletions))
+        .collect();
+    assert_eq!(
+        files,
+        [
+            ("a.txt", "modified", false, true, 2, 1),
+            ("gone.txt", "deleted", false, true, 0, 1),
+            ("new name.txt", "renamed", true, false, 0, 0),
+            ("notes.txt", "untracked", false, true, 2, 0),
+        ]
+    );
+    assert_eq!(summary.files[2].old_path.as_deref(), Some("old name.txt"));
+    assert_eq!((summary.insertions, summary.deletions), (4, 2));
+}
+
+#[test]
+fn bulk_pull_fast_forwards_clean_repositories_and_skips_dirty_ones() {
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let upstream = root.path().join("upstream");
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), upstream.to_str().unwrap()]);
+    commit(&upstream, "a.txt", "a\n");
+    git(&upstream, &["push", "-q", "-u", "origin", "main"]);
+
+    let clones: Vec<_> = (0..3)
+        .map(|i| {
+            let clone = root.path().join(format!("clone-{}", i));
+            git(root.path(), &["clone", "-q", remote.to_str().unwrap(), clone.to_str().unwrap()]);
+            clone
+        })
+        .collect();
+    commit(&upstream, "b.txt", "b\n");
+    git(&upstream, &["push", "-q"]);
+    fs::write(clones[1].join("a.txt"), "local edit\n").unwrap();
+
+    let mut paths: Vec<String> = clones.iter().map(|c| c.to_string_lossy().to_string()).collect();
+    paths.push(root.path().join("not-a-repo").to_string_lossy().to_string());
+
+    let fetched = AtomicUsize::new(0);
+    let results = run_bulk(BulkOp::Fetch, &paths, 2, |_| {
+        fetched.fetch_add(1, Ordering::Relaxed);
+    });
+    assert_eq!(fetched.load(Ordering::Relaxed), 4, "progress is reported for every repository");
+    assert_eq!(results.iter().map(|r| r.ok).collect::<Vec<_>>(), [true, true, true, false]);
+    assert_eq!(results[0].status.as_ref().unwrap().behind, Some(1));
+
+    let results = run_bulk(BulkOp::Pull, &paths, 3, |_| {});
+    let summary: Vec<_> = results.iter().map(|r| (r.path.as_str(), r.ok, r.skipped)).collect();
+    assert_eq!(summary[0], (paths[0].as_str(), true, false));
+    assert_eq!(summary[1], (paths[1].as_str(), false, true));
+    assert_eq!(summary[2], (paths[2].as_str(), true, false));
+    assert!(clones[0].join("b.txt").exists());
+    assert!(!clones[1].join("b.txt").exists());
+
+    let results = run_bulk(BulkOp::Status, &paths[..2], 8, |_| {});
+    assert_eq!(results[0].message, "Clean");
+    assert_eq!(results[1].message, "Modified");
+}
+
+#[test]
+fn fetch_reports_upstreams_that_moved_or_were_rewritten() {
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let mine = root.path().join("mine");
+    let theirs = root.path().join("theirs");
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), mine.to_str().unwrap()]);
+    commit(&mine, "a.txt", "a\n");
+    git(&mine, &["push", "-q", "-u", "origin", "main"]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), theirs.to_str().unwrap()]);
+
+    assert!(fetch_tracked(&mine).unwrap().is_empty());
+
+    commit(&theirs, "b.txt", "b\n");
+    commit(&theirs, "c.txt", "c\n");
+    git(&theirs, &["push", "-q"]);
+    let changes = fetch_tracked(&mine).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!((changes[0].branch.as_str(), changes[0].upstream.as_str()), ("main", "origin/main"));
+    assert_eq!((changes[0].behind, changes[0].force_pushed), (2, false));
+    assert!(fetch_tracked(&mine).unwrap().is_empty(), "nothing new since the last fetch");
+
+    git(&theirs, &["reset", "-q", "--hard", "HEAD~1"]);
+    commit(&theirs, "d.txt", "d\n");
+    git(&theirs, &["push", "-q", "--force"]);
+    let changes = fetch_tracked(&mine).unwrap();
+    assert!(changes[0].force_pushed);
+    assert_eq!(changes[0].behind, 2);
+}
+
+#[test]
+fn finds_work_that_only_exists_locally() {
+    let root = tempfile::tempdir().unwrap();
+    let remote = root.path().join("remote.git");
+    let repo = root.path().join("repo");
+    git(root.path(), &["init", "-q", "--bare", "-b", "main", remote.to_str().unwrap()]);
+    git(root.path(), &["clone", "-q", remote.to_str().unwrap(), repo.to_str().unwrap()]);
+    commit(&repo, "a.txt", "a\n");
+    git(&repo, &["push", "-q", "-u", "origin", "main"]);
+
+    let clean = unpushed_work(&repo).unwrap();
+    assert!(!clean.is_at_risk(), "{:?}", clean);
+
+    commit(&repo, "b.txt", "b\n");
+    git(&repo, &["switch", "-q", "-c", "spike"]);
+    commit(&repo, "c.txt", "c\n");
+    fs::write(repo.join("c.txt"), "changed\n").unwrap();
+    git(&repo, &["stash", "-q"]);
+    fs::write(repo.join("new.txt"), "new\n").unwrap();
+
+    let work = unpushed_work(&repo).unwrap();
+    assert!(work.has_remote);
+    assert_eq!((work.uncommitted, work.stashes), (1, 1));
+    let branches: Vec<_> = work.branches.iter().map(|b| (b.branch.as_str(), b.upstream.as_deref(), b.commits)).collect();
+    assert_eq!(branches, [("main", Some("origin/main"), 1), ("spike", None, 2)]);
+
+    let standalone = root.path().join("standalone");
+    fs::create_dir(&standalone).unwrap();
+    git(&standalone, &["init", "-q", "-b", "main"]);
+    commit(&standalone, "a.txt", "a\n");
+    let work = unpushed_work(&standalone).unwrap();
+    assert!(!work.has_remote && work.is_at_risk());
+}
+
+#[test]
+fn linked_worktrees_belong_to_their_repository() {
+    let root = tempfile::tempdir().unwrap();
+    let repo = root.path().join("repo");
+    fs::create_dir(&repo).unwrap();
+    git(&repo, &["init", "-q", "-b", "main"]);
+    commit(&repo, "a.txt", "a\n");
+    let linked = root.path().join("repo-feature");
+
+    assert!(add_worktree(&repo, &linked, "--detach", false).is_err());
+    add_worktree(&repo, &linked, "feature", true).unwrap();
+    let status = get_git_info(&linked);
+    assert_eq!((status.branch.as_str(), status.status.as_str()), ("feature", "Clean"));
+    let main = fs::canonicalize(&repo).unwrap();
+    assert_eq!(status.worktree_of.as_deref(), Some(main.to_str().unwrap()));
+    assert_eq!(get_git_info(&repo).worktree_of, None);
+
+    let worktrees = list_worktrees(&repo).unwrap();
+    let summary: Vec<_> = worktrees.iter().map(|w| (w.is_main, w.branch.as_deref())).collect();
+    assert_eq!(summary, [(true, Some("main")), (false, Some("feature"))]);
+
+    assert!(remove_worktree(&repo, Path::new("--force"), false).is_err());
+    assert_eq!(list_worktrees(&repo).unwrap().len(), 2);
+    fs::remove_dir_all(&linked).unwrap();
+    assert_eq!(prune_worktrees(&repo).unwrap().len(), 1);
+    assert_eq!(list_worktrees(&repo).unwrap().len(), 1);
+}
+
+#[test]
+fn reports_submodules_and_lfs_files_left_behind() {
+    let root = tempfile::tempdir().unwrap();
+    let lib = root.path().join("lib");
+    let app = root.path().join("app");
+    fs::create_dir(&lib).unwrap();
+    git(&lib, &["init", "-q", "-b", "main"]);
+    commit(&lib, "lib.txt", "lib\n");
+    fs::create_dir(&app).unwrap();
+    git(&app, &["init", "-q", "-b", "main"]);
+    git(&app, &["-c", "protocol.file.allow=always", "submodule", "add", "-q", lib.to_str().unwrap(), "lib"]);
+    // Committed as-is since git-lfs may not be installed: the file stays a pointer
+    fs::write(app.join(".gitattributes"), "*.bin filter=lfs diff=lfs merge=lfs -text\n").unwrap();
+    commit(&app, "model.bin", "version https://git-lfs.github.com/spec/v1\noid sha256:0000\nsize 4\n");
+
+    let clone = root.path().join("clone");
+    git(root.path(), &["clone", "-q", app.to_str().unwrap(), clone.to_str().unwrap()]);
+    let status = get_git_info(&clone);
+    assert_eq!(status.status, "Out of sync");
+    let submodules = status.submodules.unwrap();
+    assert_eq!((submodules.total, submodules.uninitialized), (1, 1));
+    let lfs = status.lfs.unwrap();
+    assert_eq!((lfs.files, lfs.missing), (1, 1));
+
+    git(&clone, &["-c", "protocol.file.allow=always", "submodule", "update", "-q", "--init"]);
+    commit(&clone.join("lib"), "moved.txt", "moved\n");
+    let submodules = get_git_info(&clone).submodules.unwrap();
+    assert_eq!((submodules.uninitialized, submodules.out_of_date), (0, 1));
+
+    update_submodules(&clone).unwrap();
+    let status = get_git_info(&clone);
+    assert_eq!(status.submodules.unwrap().out_of_date, 0);
+    assert_eq!(status.status, "Out of sync", "the LFS file is still a pointer");
+
+    // Untracked names that look like porcelain fields are not submodules
+    fs::write(clone.join("foo S"), "").unwrap();
+    fs::write(clone.join("My SUMMARY.md"), "").unwrap();
+    assert_eq!(get_git_info(&clone).submodules.unwrap().modified, 0);
+
+    fs::write(clone.join("lib/lib.txt"), "changed\n").unwrap();
+    assert_eq!(get_git_info(&clone).submodules.unwrap().modified, 1);
+}
+
+#[test]
+fn lists_remotes_with_origin_first() {
+    let dir = tempfile::tempdir().unwrap();
+    git(dir.path(), &["init", "-q", "-b", "main"]);
+    git(dir.path(), &["remote", "add", "upstream", "https://github.com/team/app.git"]);
+    git(dir.path(), &["remote", "add", "origin", "git@github.com:me/app.git"]);
+    git(dir.path(), &["remote", "add", "local", "/srv/git/app.git"]);
+    git(dir.path(), &["checkout", "-q", "-b", "feature"]);
+
+    let remotes = remote_links(dir.path(), &[]);
+    let names: Vec<&str> = remotes.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["origin", "upstream"]);
+    assert_eq!(remotes[0].web_url, "https://github.com/me/app");
+    assert_eq!(remotes[0].branch_url.as_deref(), Some("https://github.com/me/app/tree/feature"));
+}
+
+#[test]
+fn groups_clones_and_only_removes_redundant_ones() {
+    let root = tempfile::tempdir().unwrap();
+    let origin = root.path().join("origin");
+    fs::create_dir(&origin).unwrap();
+    git(&origin, &["init", "-q", "-b", "main"]);
+    commit(&origin, "a.txt", "a\n");
+    for name in ["a", "b"] {
+        git(root.path(), &["clone", "-q", "origin", name]);
+    }
+    let (a, b) = (root.path().join("a"), root.path().join("b"));
+    add_worktree(&a, &root.path().join("a-feature"), "feature", true).unwrap();
+
+    // Never cloned from each other, but pointing at the same repository over SSH and HTTPS
+    let (ssh, https, other) = (root.path().join("ssh"), root.path().join("https"), root.path().join("other"));
+    for (dir, url) in [(&ssh, "git@github.com:Team/App.git"), (&https, "https://github.com/Team/App"), (&other, "git@github.com:team/other.git")] {
+        fs::create_dir(dir).unwrap();
+        git(dir, &["init", "-q", "-b", "main"]);
+        git(dir, &["remote", "add", "origin", url]);
+    }
+
+    let paths: Vec<_> = ["a", "b", "a-feature", "ssh", "https", "other"].iter().map(|n| root.path().join(n)).collect();
+    let groups = find_duplicates(&paths);
+    let mut summary: Vec<(String, usize)> = groups.iter().map(|g| (g.repository.clone(), g.clones.len())).collect();
+    summary.sort();
+    assert_eq!(summary[0].1, 2);
+    assert_eq!(summary[1], ("github.com/team/app".to_string(), 2));
+    assert_eq!(summary.len(), 2);
+
+    // Work that only exists in this clone keeps it from being deleted
+    git(&a, &["worktree", "remove", "../a-feature"]);
+    commit(&a, "b.txt", "b\n");
+    fs::write(a.join("notes.txt"), "draft\n").unwrap();
+    fs::write(a.join(".git/info/exclude"), ".env\nnode_modules/\n").unwrap();
+    fs::write(a.join(".env"), "TOKEN=secret\n").unwrap();
+    fs::create_dir(a.join("node_modules")).unwrap();
+    fs::write(a.join("node_modules/dep.js"), "\n").unwrap();
+    let keep = [b.clone()];
+    let error = remove_duplicate_clone(&a, &keep).unwrap_err();
+    assert!(error.contains("1 uncommitted file(s)"), "{}", error);
+    assert!(error.contains("1 ignored file(s) such as .env"), "{}", error);
+    assert!(error.contains("main: 1 commit(s)"), "{}", error);
+    assert!(!error.contains("feature"), "{}", error);
+
+    // Fetched objects no branch points at don't count
+    fs::remove_file(a.join("notes.txt")).unwrap();
+    fs::remove_file(a.join(".env")).unwrap();
+    git(&b, &["fetch", "-q", "../a", "main"]);
+    let error = remove_duplicate_clone(&a, &keep).unwrap_err();
+    assert!(error.contains("main: 1 commit(s)"), "{}", error);
+
+    // Once the other clone has the commits, nothing is lost
+    git(&b, &["merge", "-q", "--ff-only", "FETCH_HEAD"]);
+    assert!(remove_duplicate_clone(&a, &[ssh]).is_err());
+    remove_duplicate_clone(&a, &keep).unwrap();
+    assert!(!a.exists());
+    assert!(remove_duplicate_clone(&b, &[a]).is_err());
+}
+
+#[test]
+fn names_clone_folders_after_the_url() {
+    assert_eq!(clone_folder_name("git@github.com:owner/app.git").as_deref(), Some("app"));
+    assert_eq!(clone_folder_name("https://gitlab.com/group/sub/tool/").as_deref(), Some("tool"));
+    assert_eq!(clone_folder_name("/srv/git/lib.git").as_deref(), Some("lib"));
+    assert_eq!(clone_folder_name("https://example.com/"), Some("example.com".to_string()));
+    assert_eq!(clone_folder_name(".."), None);
+}
+
+#[test]
+fn clones_a_branch_shallowly_with_progress() {
+    let root = tempfile::tempdir().unwrap();
+    let work = root.path().join("work");
+    fs::create_dir(&work).unwrap();
+    git(&work, &["init", "-q", "-b", "main"]);
+    commit(&work, "a.txt", "a\n");
+    git(&work, &["checkout", "-q", "-b", "feature"]);
+    commit(&work, "b.txt", "b\n");
+    commit(&work, "c.txt", "c\n");
+    git(root.path(), &["clone", "-q", "--bare", "work", "app.git"]);
+    // Local paths ignore --depth, file:// URLs honor it
+    let url = format!("file://{}", root.path().join("app.git").display());
+
+    let parent = root.path().join("projects");
+    fs::create_dir(&parent).unwrap();
+    let options = CloneOptions {
+        branch: Some("feature".to_string()),
+        depth: Some(1),
+        ..Default::default()
+    };
+    let mut progress = Vec::new();
+    let target = clone_repo(&url, &parent, &options, |p| progress.push(p)).unwrap();
+
+    assert_eq!(target, parent.join("app"));
+    assert_eq!(get_git_info(&target).branch, "feature");
+    assert_eq!(recent_commits(&target, None, 10).unwrap().len(), 1);
+    assert!(progress.iter().any(|p| p.percent == Some(100)), "{:?}", progress);
+
+    // The folder is taken now, and a failed clone leaves nothing behind
+    assert!(clone_repo(&url, &parent, &options, |_| {}).unwrap_err().contains("already exists"));
+    let missing = CloneOptions {
+        directory: Some("missing".to_string()),
+        ..Default::default()
+    };
+    let error = clone_repo(&format!("{}-missing", url), &parent, &missing, |_| {}).unwrap_err();
+    assert!(error.starts_with("Failed to clone"), "{}", error);
+    assert!(!parent.join("missing").exists());
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use devlaunch_core::search::{best_match, matches, search};
+use devlaunch_core::Project;
+
+fn project(name: &str, path: &str, tags: &[&str]) -> Project {
+    common::project(name, name, path, tags)
+}
+
+#[test]
+fn matches_name_path_and_tags_ignoring_case() {
+    let p = project("Billing-API", "/home/me/work/billing", &["Backend"]);
+    assert!(matches(&p, "billing-api"));
+    assert!(matches(&p, "work/bill"));
+    assert!(matches(&p, "backend"));
+    assert!(!matches(&p, "frontend"));
+}
+
+#[test]
+fn best_match_prefers_name_matches() {
+    let projects = vec![
+        project("web-admin", "/code/api-clients/web-admin", &[]),
+        project("payments-api", "/code/payments", &[]),
+        project("api", "/code/api", &[]),
+    ];
+
+    assert_eq!(best_match(&projects, "api").unwrap().name, "api");
+    assert_eq!(best_match(&projects, "pay").unwrap().name, "payments-api");
+    assert!(best_match(&projects, "nothing").is_none());
+
+    let names: Vec<&str> = search(&projects, "ap").iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["api", "payments-api", "web-admin"]);
+}
+
+#[test]
+fn ties_keep_library_order() {
+    let projects = vec![project("shop-web", "/a", &[]), project("blog-web", "/b", &[])];
+    assert_eq!(best_match(&projects, "web").unwrap().name, "shop-web");
+}
@@ -0,0 +1,41 @@
+// Only Linux reads XDG_DATA_HOME; elsewhere this would touch the real library
+#![cfg(target_os = "linux")]
+
+use std::fs;
+
+use devlaunch_core::scan::scan_path;
+use devlaunch_core::store;
+
+// Single test so the data directory override doesn't race with anything else
+#[test]
+fn never_overwrites_a_library_it_could_not_read() {
+    let root = tempfile::tempdir().unwrap();
+    std::env::set_var("XDG_DATA_HOME", root.path().join("data"));
+    let library = store::data_dir().join("projects.json");
+
+    let app = root.path().join("app");
+    fs::create_dir(&app).unwrap();
+    let project = scan_path(&app.to_string_lossy()).unwrap().project;
+    assert!(store::read_projects().unwrap().is_empty(), "a missing library is an empty one");
+    store::add_project(project.clone()).unwrap();
+    assert_eq!(store::read_projects().unwrap().len(), 1);
+
+    fs::write(&library, "[{\"id\": ").unwrap();
+    assert!(store::load_projects().is_empty());
+    let error = store::read_projects().unwrap_err();
+    assert!(error.contains("Failed to parse"), "{}", error);
+    assert!(store::add_project(project.clone()).is_err());
+    assert!(store::touch_project(&project.id).is_err());
+    assert_eq!(fs::read_to_string(&library).unwrap(), "[{\"id\": ");
+
+    // Saving a whole library anyway keeps the unreadable file aside
+    store::save_projects(&[project]).unwrap();
+    assert_eq!(store::read_projects().unwrap().len(), 1);
+    let backups: Vec<_> = fs::read_dir(store::data_dir())
+        .unwrap()
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("projects.corrupt-"))
+        .collect();
+    assert_eq!(backups.len(), 1);
+    assert_eq!(fs::read_to_string(backups[0].path()).unwrap(), "[{\"id\": ");
+}
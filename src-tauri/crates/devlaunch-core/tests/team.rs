@@ -0,0 +1,140 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::git;
+use devlaunch_core::runner::ProcessRunner;
+use devlaunch_core::store;
+use devlaunch_core::team::{bootstrap, load_manifest};
+
+#[test]
+fn resolves_paths_and_rejects_unknown_profile_repos() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest = dir.path().join("devlaunch.team.toml");
+    fs::write(
+        &manifest,
+        r#"
+[workspace]
+root = "workspace"
+
+[[repo]]
+name = "api"
+url = "https://example.com/acme/api.git"
+
+[[repo]]
+name = "web"
+url = "https://example.com/acme/web.git"
+path = "apps/web"
+
+[[profile]]
+name = "all"
+repos = ["api", "web"]
+"#,
+    )
+    .unwrap();
+
+    let loaded = load_manifest(&manifest).unwrap();
+    let root = fs::canonicalize(dir.path()).unwrap().join("workspace");
+    assert_eq!(loaded.repos[0].path.as_deref(), Some(&*root.join("api").to_string_lossy()));
+    assert_eq!(loaded.repos[1].path.as_deref(), Some(&*root.join("apps/web").to_string_lossy()));
+    assert_eq!(loaded.profiles[0].repos, ["api", "web"]);
+
+    let content = fs::read_to_string(&manifest).unwrap().replace(r#"["api", "web"]"#, r#"["api", "docs"]"#);
+    fs::write(&manifest, content).unwrap();
+    let error = load_manifest(&manifest).unwrap_err();
+    assert!(error.contains("unknown repo \"docs\""), "{}", error);
+
+    let original = fs::read_to_string(&manifest).unwrap();
+    for path in ["../outside", "/tmp/api", "apps/../../outside", "."] {
+        fs::write(&manifest, original.replace(r#"path = "apps/web""#, &format!("path = {:?}", path))).unwrap();
+        let error = load_manifest(&manifest).unwrap_err();
+        assert!(error.contains("must stay inside the workspace"), "{}: {}", path, error);
+    }
+    fs::write(&manifest, original.replace(r#"name = "api""#, r#"name = "../api""#)).unwrap();
+    assert!(load_manifest(&manifest).is_err());
+}
+
+// Single test touching the library so the environment override doesn't race with anything else
+#[cfg(unix)]
+#[test]
+fn bootstraps_clones_installs_and_tags() {
+    let root = tempfile::tempdir().unwrap();
+    std::env::set_var("XDG_DATA_HOME", root.path().join("data"));
+
+    let work = root.path().join("work");
+    fs::create_dir(&work).unwrap();
+    git(&work, &["init", "-q"]);
+    fs::write(work.join("package.json"), r#"{"name":"app"}"#).unwrap();
+    git(&work, &["add", "."]);
+    git(&work, &["commit", "-q", "-m", "init"]);
+    git(root.path(), &["clone", "-q", "--bare", "work", "app.git"]);
+    let url = format!("file://{}", root.path().join("app.git").display());
+
+    let manifest = root.path().join("team/devlaunch.team.toml");
+    fs::create_dir(root.path().join("team")).unwrap();
+    fs::write(
+        &manifest,
+        format!(
+            r#"
+[workspace]
+tags = ["acme"]
+
+[[repo]]
+name = "api"
+url = "{url}"
+tags = ["backend"]
+install = ["touch installed", "exit 3", "touch never"]
+
+[[repo]]
+name = "web"
+url = "{url}"
+path = "apps/web"
+install = ["touch installed"]
+
+[[repo]]
+name = "missing"
+url = "file:///nowhere/missing.git"
+"#
+        ),
+    )
+    .unwrap();
+
+    let runner = ProcessRunner::default();
+    let mut seen = Vec::new();
+    let report = bootstrap(&manifest, &runner, false, |s| seen.push(s.clone())).unwrap();
+    assert_eq!(seen.len(), report.steps.len());
+
+    let api = root.path().join("team/api");
+    let web = root.path().join("team/apps/web");
+    assert!(api.join("installed").exists());
+    assert!(!api.join("never").exists(), "install stops at the first failing command");
+    assert!(web.join("installed").exists());
+
+    let failed: Vec<(&str, &str)> = report.failed().iter().map(|s| (s.repo.as_str(), s.step.as_str())).collect();
+    assert_eq!(failed, [("api", "install"), ("missing", "clone")]);
+    let exit = report.steps.iter().find(|s| s.status == "failed" && s.repo == "api").unwrap();
+    assert!(exit.message.contains("exit 3 exited with code 3"), "{}", exit.message);
+    assert!(exit.log_file.is_some());
+
+    let projects = store::load_projects();
+    assert_eq!(projects.len(), 2);
+    let tags = |path: &Path| {
+        let project = projects.iter().find(|p| Path::new(&p.path) == path).unwrap();
+        project.tags().into_iter().map(str::to_string).collect::<Vec<_>>()
+    };
+    assert_eq!(tags(&api), ["acme", "backend"]);
+    assert_eq!(tags(&web), ["acme"]);
+    assert_eq!(projects.iter().find(|p| Path::new(&p.path) == api).unwrap().name, "api");
+
+    // A second run leaves existing clones and the library alone
+    let report = bootstrap(&manifest, &runner, false, |_| {}).unwrap();
+    let api_steps: Vec<(&str, &str)> = report
+        .steps
+        .iter()
+        .filter(|s| s.repo == "api")
+        .map(|s| (s.step.as_str(), s.status.as_str()))
+        .collect();
+    assert_eq!(api_steps, [("clone", "skipped"), ("install", "skipped"), ("library", "ok")]);
+    assert_eq!(store::load_projects().len(), 2);
+}
@@ -0,0 +1,46 @@
+use tauri::AppHandle;
+
+#[cfg(unix)]
+use std::sync::{Arc, Mutex};
+
+#[cfg(unix)]
+use tauri::Manager;
+
+#[cfg(unix)]
+use devlaunch_core::{api::ApiServer, runner::ProcessRunner, store};
+
+/// The local API server while the `apiEnabled` setting is on
+#[cfg(unix)]
+#[derive(Default)]
+struct ApiState {
+    server: Mutex<Option<ApiServer>>,
+    // Shared across restarts of the server so scripts it started can still be stopped
+    runner: Arc<ProcessRunner>,
+}
+
+pub fn start(app: &AppHandle) {
+    #[cfg(unix)]
+    app.manage(ApiState::default());
+    sync(app);
+}
+
+/// Starts or stops the local API to match the saved settings
+pub fn sync(app: &AppHandle) {
+    #[cfg(unix)]
+    {
+        let state = app.state::<ApiState>();
+        let mut server = state.server.lock().unwrap();
+        if !store::load_settings().api_enabled {
+            // Dropping the server removes the socket and token file
+            *server = None;
+        } else if server.is_none() {
+            match ApiServer::start(state.runner.clone()) {
+                Ok(started) => *server = Some(started),
+                Err(e) => log::warn!("Failed to start local API: {}", e),
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    let _ = app;
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use devlaunch_core::deeplink::is_deep_link;
+
+/// Flags and targets DevLaunch understands on its own command line
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StartupArgs {
+    /// Start hidden in the tray (passed by autostart)
+    pub minimized: bool,
+    /// Bring the window to the front (`devlaunch --show`)
+    pub show: bool,
+    /// Project paths to open or add, as absolute paths
+    pub targets: Vec<String>,
+    /// `devlaunch://` links, as handed over by the desktop's URL handler
+    #[serde(default)]
+    pub links: Vec<String>,
+    /// Open a terminal in the targets instead of the IDE (`--terminal`)
+    #[serde(default)]
+    pub terminal: bool,
+    /// Run this package script in the targets instead (`--run=dev`)
+    #[serde(default)]
+    pub run: Option<String>,
+}
+
+impl StartupArgs {
+    pub fn parse<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut parsed = Self::default();
+        for arg in args {
+            match arg.as_ref() {
+                "--minimized" | "--background" => parsed.minimized = true,
+                "--show" => parsed.show = true,
+                "--terminal" => parsed.terminal = true,
+                flag if flag.starts_with("--run=") => parsed.run = Some(flag["--run=".len()..].to_string()),
+                // Ignore flags we don't know (e.g. the macOS `-psn_...` process serial number)
+                flag if flag.starts_with('-') => {}
+                link if is_deep_link(link) => parsed.links.push(link.to_string()),
+                target => parsed.targets.push(resolve_target(target)),
+            }
+        }
+        parsed
+    }
+
+    pub fn from_env() -> Self {
+        Self::parse(std::env::args().skip(1))
+    }
+}
+
+/// Makes relative paths absolute, since a forwarded argument is handled by a process with another cwd
+fn resolve_target(target: &str) -> String {
+    let path = Path::new(target);
+    if path.exists() {
+        if let Ok(absolute) = std::fs::canonicalize(path) {
+            return absolute.to_string_lossy().to_string();
+        }
+    }
+    target.to_string()
+}
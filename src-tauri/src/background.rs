@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
+
+use tauri::AppHandle;
+
+use devlaunch_core::cleanup::clean_build_folders;
+use devlaunch_core::git::fetch_tracked;
+use devlaunch_core::scan::{apply_scan, scan_path};
+use devlaunch_core::{store, Project};
+
+use crate::{notifications, publish_library, tray};
+
+// How often the scheduled jobs check whether anything is due
+const TICK: Duration = Duration::from_secs(60);
+const MAX_BACKOFF: Duration = Duration::from_secs(24 * 60 * 60);
+const WATCH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Files in a project whose changes can alter what a scan detects
+const WATCHED_FILES: &[&str] = &[
+    "package.json", "Cargo.toml", "go.mod", "pubspec.yaml", "pyproject.toml", "requirements.txt", "build.gradle",
+    "build.gradle.kts", ".git/HEAD",
+];
+
+/// Runs `job` forever on its own thread, sleeping for `interval()` between runs.
+/// The interval is re-read every time so settings changes apply without a restart.
+fn spawn_periodic<F>(name: &str, interval: fn() -> Duration, mut job: F)
+where
+    F: FnMut() + Send + 'static,
+{
+    let spawned = std::thread::Builder::new()
+        .name(format!("devlaunch-{}", name))
+        .spawn(move || loop {
+            job();
+            std::thread::sleep(interval());
+        });
+
+    if let Err(e) = spawned {
+        log::error!("Failed to start background job {}: {}", name, e);
+    }
+}
+
+fn status_poll_interval() -> Duration {
+    Duration::from_secs(store::load_settings().status_poll_minutes.max(1) * 60)
+}
+
+fn tick() -> Duration {
+    TICK
+}
+
+fn watch_interval() -> Duration {
+    WATCH_INTERVAL
+}
+
+/// When a repository is next fetched, and how many fetches in a row have failed
+#[derive(Default)]
+struct FetchSchedule {
+    due: Option<Instant>,
+    failures: u32,
+}
+
+fn recently_opened(project: &Project, days: u64) -> bool {
+    if days == 0 {
+        return true;
+    }
+    project
+        .last_opened
+        .as_deref()
+        .and_then(|opened| chrono::DateTime::parse_from_rfc3339(opened).ok())
+        .is_some_and(|opened| chrono::Utc::now().signed_duration_since(opened) < chrono::Duration::days(days as i64))
+}
+
+/// Fetches every due repository, doubling the wait after each failure
+fn fetch_due(app: &AppHandle, schedules: &mut HashMap<String, FetchSchedule>) {
+    let settings = store::load_settings();
+    if !settings.background_fetch {
+        return;
+    }
+    let interval = Duration::from_secs(settings.fetch_interval_minutes.max(1) * 60);
+    let now = Instant::now();
+    let mut fetched = false;
+
+    for project in store::load_projects() {
+        if !project.has_git || project.is_archived.unwrap_or(false) || !recently_opened(&project, settings.fetch_recent_days) {
+            continue;
+        }
+        let schedule = schedules.entry(project.id.clone()).or_default();
+        if schedule.due.is_some_and(|due| due > now) {
+            continue;
+        }
+
+        fetched = true;
+        match fetch_tracked(Path::new(&project.path)) {
+            Ok(changes) => {
+                schedule.failures = 0;
+                schedule.due = Some(now + interval);
+                for change in changes {
+                    let body = if change.force_pushed {
+                        format!("{} was force-pushed; {} no longer matches it", change.upstream, change.branch)
+                    } else {
+                        format!(
+                            "{} is {} commit{} behind {}",
+                            change.branch,
+                            change.behind,
+                            if change.behind == 1 { "" } else { "s" },
+                            change.upstream
+                        )
+                    };
+                    notifications::show(&project.name, &body);
+                }
+            }
+            Err(e) => {
+                schedule.failures += 1;
+                let backoff = interval * 2u32.pow(schedule.failures.min(4));
+                schedule.due = Some(now + backoff.min(MAX_BACKOFF));
+                log::warn!("Background fetch of {} failed ({} in a row): {}", project.path, schedule.failures, e);
+            }
+        }
+    }
+
+    if fetched {
+        tray::poll_status(app);
+    }
+}
+
+/// Once a day, after the configured time, lists the repositories with unpushed work
+fn remind_unpushed(last_reminded: &mut Option<chrono::NaiveDate>) {
+    let settings = store::load_settings();
+    if !settings.unpushed_reminder {
+        return;
+    }
+    let Ok(at) = chrono::NaiveTime::parse_from_str(&settings.unpushed_reminder_time, "%H:%M") else {
+        log::warn!("Invalid unpushed work reminder time: {}", settings.unpushed_reminder_time);
+        return;
+    };
+    let now = chrono::Local::now();
+    if now.time() < at || *last_reminded == Some(now.date_naive()) {
+        return;
+    }
+    *last_reminded = Some(now.date_naive());
+
+    let report = crate::unpushed_report();
+    let names: Vec<&str> = report.iter().map(|(p, _)| p.name.as_str()).collect();
+    let body = match names.as_slice() {
+        [] => return,
+        [name] => format!("{} has work that isn't pushed", name),
+        [first, second] => format!("{} and {} have work that isn't pushed", first, second),
+        [first, second, rest @ ..] => format!("{}, {} and {} more have work that isn't pushed", first, second, rest.len()),
+    };
+    notifications::show("Unpushed work", &body);
+}
+
+/// Rescans `project` and saves what changed, returning the updated library
+fn rescan(project: &Project) -> Result<Vec<Project>, String> {
+    let scanned = scan_path(&project.path)?.project;
+    store::update_project(&project.id, |p| apply_scan(p, scanned))
+}
+
+/// Modification times of a project folder (entries added or removed, like `node_modules` or
+/// build output) and of the files detection reads
+fn fingerprint(path: &Path) -> Vec<Option<SystemTime>> {
+    let modified = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+    std::iter::once(modified(path))
+        .chain(WATCHED_FILES.iter().map(|file| modified(&path.join(file))))
+        .collect()
+}
+
+/// Polls library folders and rescans those that changed since the last look, so installs,
+/// cleanups and branch switches made elsewhere show up without a manual refresh
+fn watch_library(app: &AppHandle, seen: &mut HashMap<String, Vec<Option<SystemTime>>>) {
+    if !store::load_settings().watch_library {
+        seen.clear();
+        return;
+    }
+
+    let mut library = None;
+    for project in store::load_projects() {
+        if project.is_archived.unwrap_or(false) {
+            continue;
+        }
+        let current = fingerprint(Path::new(&project.path));
+        // The first look only records the folder
+        let changed = seen.insert(project.id.clone(), current.clone()).is_some_and(|previous| previous != current);
+        if !changed || current[0].is_none() {
+            continue;
+        }
+        match rescan(&project) {
+            Ok(projects) => library = Some(projects),
+            Err(e) => log::warn!("Failed to rescan {}: {}", project.path, e),
+        }
+    }
+
+    if let Some(projects) = library {
+        publish_library(app, projects);
+    }
+}
+
+/// Once a day, removes the build output of projects that weren't opened for the configured
+/// number of days. Pinned projects are left alone.
+fn clean_stale(app: &AppHandle, last_cleaned: &mut Option<chrono::NaiveDate>) {
+    let settings = store::load_settings();
+    if !settings.auto_clean {
+        return;
+    }
+    let today = chrono::Local::now().date_naive();
+    if *last_cleaned == Some(today) {
+        return;
+    }
+    *last_cleaned = Some(today);
+
+    let mut cleaned = Vec::new();
+    let mut library = None;
+    for project in store::load_projects() {
+        if !project.has_build_folder
+            || project.is_pinned.unwrap_or(false)
+            || recently_opened(&project, settings.auto_clean_days.max(1))
+        {
+            continue;
+        }
+        match clean_build_folders(&project.path, project.project_type.clone()) {
+            Ok(message) => {
+                log::info!("Scheduled cleanup of {}: {}", project.path, message);
+                cleaned.push(project.name.clone());
+                match rescan(&project) {
+                    Ok(projects) => library = Some(projects),
+                    Err(e) => log::warn!("Failed to rescan {}: {}", project.path, e),
+                }
+            }
+            Err(e) => log::warn!("Scheduled cleanup of {} failed: {}", project.path, e),
+        }
+    }
+
+    if let Some(projects) = library {
+        publish_library(app, projects);
+    }
+    let body = match cleaned.as_slice() {
+        [] => return,
+        [name] => format!("Removed the build output of {}", name),
+        [first, second] => format!("Removed the build output of {} and {}", first, second),
+        [first, rest @ ..] => format!("Removed the build output of {} and {} other projects", first, rest.len()),
+    };
+    notifications::show("Cleanup", &body);
+}
+
+/// Starts the jobs that keep running while DevLaunch sits in the tray with its window hidden
+pub fn start(app: &AppHandle) {
+    let handle = app.clone();
+    spawn_periodic("git-status", status_poll_interval, move || tray::poll_status(&handle));
+
+    let handle = app.clone();
+    let mut schedules = HashMap::new();
+    spawn_periodic("git-fetch", tick, move || fetch_due(&handle, &mut schedules));
+
+    let mut last_reminded = None;
+    spawn_periodic("unpushed-reminder", tick, move || remind_unpushed(&mut last_reminded));
+
+    let handle = app.clone();
+    let mut last_cleaned = None;
+    spawn_periodic("cleanup", tick, move || clean_stale(&handle, &mut last_cleaned));
+
+    let handle = app.clone();
+    let mut seen = HashMap::new();
+    spawn_periodic("library-watch", watch_interval, move || watch_library(&handle, &mut seen));
+}
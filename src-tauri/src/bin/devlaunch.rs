@@ -0,0 +1,4 @@
+// Command-line companion to the DevLaunch app; see `devlaunch --help`
+fn main() -> std::process::ExitCode {
+  app_lib::cli::run()
+}
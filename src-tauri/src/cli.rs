@@ -0,0 +1,675 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{CommandFactory, Parser, Subcommand};
+use serde::Serialize;
+use serde_json::json;
+
+use devlaunch_core::cleanup::{clean_build_folders, plan_build_cleanup, remove_node_modules};
+use devlaunch_core::detect::detect_project_type;
+use devlaunch_core::git::{commit_activity, get_git_info, library_activity};
+use devlaunch_core::launch::{self, launch_ide, read_scripts};
+use devlaunch_core::runner::ProcessRunner;
+use devlaunch_core::scan::{discover_projects, scan_path};
+use devlaunch_core::size::{format_size, get_directory_size};
+use devlaunch_core::team::{self, MANIFEST_FILE};
+use devlaunch_core::tools::collect_tool_versions;
+use devlaunch_core::{search, store, CommitActivity, GitStatus, Project};
+
+use crate::completions::{self, Shell};
+use crate::instance::{self, Message};
+
+/// DevLaunch from the terminal, sharing the app's project library
+#[derive(Parser)]
+#[command(name = "devlaunch", version)]
+struct Cli {
+    /// Print JSON instead of human-readable text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// List projects in the library
+    List {
+        /// Include archived projects
+        #[arg(long)]
+        all: bool,
+        /// Print only project names, one per line
+        #[arg(long)]
+        names: bool,
+    },
+    /// Add a project directory to the library
+    Add { path: PathBuf },
+    /// Find projects under a directory
+    Scan {
+        dir: PathBuf,
+        /// How many directory levels to search
+        #[arg(long, default_value_t = 2)]
+        depth: usize,
+        /// Add every project found to the library
+        #[arg(long)]
+        add: bool,
+    },
+    /// Open a project in an IDE (the default IDE from settings unless given)
+    Open {
+        name: String,
+        #[arg(long)]
+        ide: Option<String>,
+    },
+    /// Remove build output from projects (all library projects if none are named)
+    Clean {
+        projects: Vec<String>,
+        /// Only show what would be removed
+        #[arg(long)]
+        dry_run: bool,
+        /// Also remove node_modules
+        #[arg(long)]
+        node_modules: bool,
+    },
+    /// Git overview across the library
+    Status,
+    /// Work that exists only on this machine; exits non-zero when there is any
+    Unpushed,
+    /// Repositories cloned more than once in the library
+    Duplicates,
+    /// Commit statistics of a project, or of the whole library when none is named
+    Activity {
+        project: Option<String>,
+        /// How many days back to count, today included
+        #[arg(long, default_value_t = 30)]
+        days: u32,
+    },
+    /// Set up or launch the repositories of a team manifest
+    Team {
+        #[command(subcommand)]
+        command: TeamCommands,
+    },
+    /// Run a package.json script in the foreground
+    Run { project: String, script: String },
+    /// Check installed tools and the library for problems
+    Doctor,
+    /// Print the path of the project best matching a name, path or tag (used by `dl cd`)
+    Path {
+        #[arg(required = true)]
+        query: Vec<String>,
+    },
+    /// Print a completion script for `devlaunch` and `dl`
+    Completions { shell: Shell },
+    /// Print the `dl` shell function and completions, for `eval "$(devlaunch shell-init bash)"`
+    ShellInit { shell: Shell },
+}
+
+#[derive(Subcommand)]
+enum TeamCommands {
+    /// Clone missing repositories, run their install steps and add them to the library;
+    /// exits non-zero when any step failed
+    Bootstrap {
+        #[arg(default_value = MANIFEST_FILE)]
+        manifest: PathBuf,
+        /// Also run install steps in repositories that are already cloned
+        #[arg(long)]
+        reinstall: bool,
+    },
+    /// Open the repositories of a launch profile
+    Launch {
+        profile: String,
+        #[arg(long, default_value = MANIFEST_FILE)]
+        manifest: PathBuf,
+    },
+}
+
+pub fn run() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Commands::List { all, names } => list(cli.json, all, names),
+        Commands::Add { path } => add(cli.json, &path),
+        Commands::Scan { dir, depth, add } => scan(cli.json, &dir, depth, add),
+        Commands::Open { name, ide } => open(cli.json, &name, ide),
+        Commands::Clean { projects, dry_run, node_modules } => clean(cli.json, &projects, dry_run, node_modules),
+        Commands::Status => status(cli.json),
+        Commands::Unpushed => unpushed(cli.json),
+        Commands::Duplicates => duplicates(cli.json),
+        Commands::Activity { project, days } => activity(cli.json, project.as_deref(), days),
+        Commands::Team { command: TeamCommands::Bootstrap { manifest, reinstall } } => {
+            team_bootstrap(cli.json, &manifest, reinstall)
+        }
+        Commands::Team { command: TeamCommands::Launch { profile, manifest } } => team_launch(cli.json, &manifest, &profile),
+        Commands::Run { project, script } => run_script(&project, &script),
+        Commands::Doctor => doctor(cli.json),
+        Commands::Path { query } => path(cli.json, &query.join(" ")),
+        Commands::Completions { shell } => print_script(completions::completions(shell, &subcommands())),
+        Commands::ShellInit { shell } => print_script(completions::shell_init(shell, &subcommands())),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            if cli.json {
+                println!("{}", json!({ "error": e }));
+            } else {
+                eprintln!("devlaunch: {}", e);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+type CliResult = Result<ExitCode, String>;
+
+fn print_json<T: Serialize + ?Sized>(value: &T) -> CliResult {
+    let output = serde_json::to_string_pretty(value).map_err(|e| format!("Failed to serialize output: {}", e))?;
+    println!("{}", output);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Library projects in the same order as the tray: pinned first, then most recently opened
+fn library(include_archived: bool) -> Vec<Project> {
+    let mut projects: Vec<Project> = store::load_projects()
+        .into_iter()
+        .filter(|p| include_archived || !p.is_archived.unwrap_or(false))
+        .collect();
+    projects.sort_by(|a, b| {
+        b.is_pinned
+            .unwrap_or(false)
+            .cmp(&a.is_pinned.unwrap_or(false))
+            .then_with(|| b.last_opened.cmp(&a.last_opened))
+    });
+    projects
+}
+
+/// Resolves a project by id, path, exact name or a unique partial name
+fn find_project<'a>(projects: &'a [Project], query: &str) -> Result<&'a Project, String> {
+    let lower = query.to_lowercase();
+    if let Some(project) = projects.iter().find(|p| {
+        p.id == query || p.name.to_lowercase() == lower || Path::new(&p.path) == Path::new(query)
+    }) {
+        return Ok(project);
+    }
+
+    let matches: Vec<&Project> = projects
+        .iter()
+        .filter(|p| p.name.to_lowercase().contains(&lower))
+        .collect();
+    match matches.as_slice() {
+        [project] => Ok(project),
+        [] => Err(format!("No project matches \"{}\"", query)),
+        _ => Err(format!(
+            "\"{}\" is ambiguous: {}",
+            query,
+            matches.iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// Lets a running DevLaunch window pick up changes we wrote to the store
+fn notify_running_app() {
+    instance::send(&Message::LibraryChanged);
+}
+
+fn list(as_json: bool, all: bool, names: bool) -> CliResult {
+    let projects = library(all);
+    if as_json {
+        return print_json(&projects);
+    }
+    if names {
+        for p in &projects {
+            println!("{}", p.name);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if projects.is_empty() {
+        println!("Library is empty. Add a project with `devlaunch add <path>`.");
+    }
+    for p in &projects {
+        let marker = if p.is_pinned.unwrap_or(false) { "*" } else { " " };
+        println!("{} {:<28} {:<16} {}", marker, p.name, p.git_status.branch, p.path);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn add(as_json: bool, path: &Path) -> CliResult {
+    let absolute = std::fs::canonicalize(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let result = scan_path(&absolute.to_string_lossy())?;
+    let (project, _) = store::add_project(result.project)?;
+    notify_running_app();
+
+    if as_json {
+        return print_json(&project);
+    }
+    println!("Added \"{}\" ({})", project.name, project.path);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn scan(as_json: bool, dir: &Path, depth: usize, add_found: bool) -> CliResult {
+    if !dir.is_dir() {
+        return Err(format!("{} is not a directory", dir.display()));
+    }
+
+    // Stored paths may be spelled differently or go through symlinks
+    let canonical = |path: &Path| std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut known: Vec<PathBuf> = store::load_projects().iter().map(|p| canonical(Path::new(&p.path))).collect();
+    let mut found = Vec::new();
+    for path in discover_projects(dir, depth) {
+        let path = canonical(&path);
+        let in_library = known.contains(&path);
+        let mut added = false;
+        let mut error = None;
+        if add_found && !in_library {
+            // One project that can't be added doesn't stop the others
+            match scan_path(&path.to_string_lossy()).and_then(|result| store::add_project(result.project)) {
+                Ok(_) => {
+                    added = true;
+                    known.push(path.clone());
+                }
+                Err(e) => error = Some(e),
+            }
+        }
+        found.push(json!({
+            "path": path.to_string_lossy(),
+            "projectType": detect_project_type(&path),
+            "inLibrary": in_library,
+            "added": added,
+            "error": error,
+        }));
+    }
+    if found.iter().any(|f| f["added"] == true) {
+        notify_running_app();
+    }
+    let failed = found.iter().filter(|f| !f["error"].is_null()).count();
+    let code = if failed == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+
+    if as_json {
+        print_json(&found)?;
+        return Ok(code);
+    }
+    for f in &found {
+        let state = if f["added"] == true {
+            "added"
+        } else if !f["error"].is_null() {
+            "failed"
+        } else if f["inLibrary"] == true {
+            "in library"
+        } else {
+            "new"
+        };
+        println!(
+            "{:<10} {:<8} {}",
+            state,
+            f["projectType"].as_str().unwrap_or(""),
+            f["path"].as_str().unwrap_or("")
+        );
+        if let Some(error) = f["error"].as_str() {
+            println!("    {}", error);
+        }
+    }
+    let added = found.iter().filter(|f| f["added"] == true).count();
+    if add_found {
+        println!("{} project(s) found, {} added, {} failed", found.len(), added, failed);
+    } else {
+        println!("{} project(s) found", found.len());
+    }
+    Ok(code)
+}
+
+fn open(as_json: bool, name: &str, ide: Option<String>) -> CliResult {
+    let projects = store::load_projects();
+    let project = find_project(&projects, name)?;
+    let ide = ide.unwrap_or_else(|| store::load_settings().default_ide);
+
+    launch_ide(&project.path, &ide)?;
+    store::touch_project(&project.id)?;
+    notify_running_app();
+
+    if as_json {
+        return print_json(&json!({ "project": project.name, "path": project.path, "ide": ide }));
+    }
+    println!("Opening {} in {}", project.name, ide);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn clean(as_json: bool, names: &[String], dry_run: bool, node_modules: bool) -> CliResult {
+    let library = store::load_projects();
+    let targets: Vec<&Project> = if names.is_empty() {
+        library.iter().filter(|p| !p.is_archived.unwrap_or(false)).collect()
+    } else {
+        names.iter().map(|n| find_project(&library, n)).collect::<Result<_, _>>()?
+    };
+
+    let mut report = Vec::new();
+    let mut total = 0u64;
+    for project in targets {
+        let path = Path::new(&project.path);
+        let project_type = project.project_type.clone().unwrap_or_else(|| detect_project_type(path));
+        let mut folders = plan_build_cleanup(path, &project_type);
+        if node_modules && path.join("node_modules").is_dir() {
+            folders.push(("node_modules".to_string(), get_directory_size(&path.join("node_modules"))));
+        }
+        if folders.is_empty() {
+            continue;
+        }
+        total += folders.iter().map(|(_, size)| size).sum::<u64>();
+
+        let mut results = Vec::new();
+        if !dry_run {
+            results.push(clean_build_folders(&project.path, Some(project_type.clone())));
+            if node_modules && path.join("node_modules").is_dir() {
+                results.push(remove_node_modules(&project.path));
+            }
+        }
+
+        if !as_json {
+            println!("{} ({})", project.name, project.path);
+            for (folder, size) in &folders {
+                println!("    {:<24} {}", folder, format_size(*size));
+            }
+            for result in &results {
+                match result {
+                    Ok(message) => println!("    ✓ {}", message),
+                    Err(e) => println!("    ✗ {}", e),
+                }
+            }
+        }
+        report.push(json!({
+            "project": project.name,
+            "path": project.path,
+            "folders": folders.iter().map(|(folder, size)| json!({ "folder": folder, "bytes": size })).collect::<Vec<_>>(),
+            "errors": results.iter().filter_map(|r| r.as_ref().err()).collect::<Vec<_>>(),
+        }));
+    }
+
+    if as_json {
+        return print_json(&json!({ "dryRun": dry_run, "totalBytes": total, "projects": report }));
+    }
+    let verb = if dry_run { "Would free" } else { "Freed" };
+    println!("{} {} across {} project(s)", verb, format_size(total), report.len());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn status(as_json: bool) -> CliResult {
+    let rows: Vec<(Project, GitStatus)> = library(false)
+        .into_iter()
+        .filter(|p| p.has_git)
+        .map(|p| {
+            let git = get_git_info(Path::new(&p.path));
+            (p, git)
+        })
+        .collect();
+
+    if as_json {
+        let output: Vec<_> = rows
+            .iter()
+            .map(|(p, git)| json!({ "project": p.name, "path": p.path, "git": git }))
+            .collect();
+        return print_json(&output);
+    }
+
+    for (p, git) in &rows {
+        let mut details = Vec::new();
+        if let Some(count) = git.count {
+            details.push(format!("{} changed", count));
+        }
+        match (git.ahead, git.behind) {
+            (Some(ahead), Some(behind)) if ahead + behind > 0 => details.push(format!("↑{} ↓{}", ahead, behind)),
+            (None, _) if git.upstream.is_none() && !git.detached => details.push("no upstream".to_string()),
+            _ => {}
+        }
+        if let Some(submodules) = &git.submodules {
+            let stale = submodules.uninitialized + submodules.out_of_date;
+            if stale > 0 {
+                details.push(format!("{} submodule(s) to update", stale));
+            }
+        }
+        if let Some(lfs) = git.lfs.as_ref().filter(|lfs| lfs.missing > 0) {
+            details.push(format!("{} LFS file(s) not downloaded", lfs.missing));
+        }
+        println!("{:<28} {:<20} {:<10} {}", p.name, git.branch, git.status, details.join(", "));
+    }
+    let dirty = rows.iter().filter(|(_, git)| git.count.unwrap_or(0) > 0).count();
+    let behind = rows.iter().filter(|(_, git)| git.behind.unwrap_or(0) > 0).count();
+    println!("{} repo(s), {} with uncommitted changes, {} behind upstream", rows.len(), dirty, behind);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn unpushed(as_json: bool) -> CliResult {
+    let report = crate::unpushed_report();
+    let code = if report.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+
+    if as_json {
+        let output: Vec<_> = report
+            .iter()
+            .map(|(p, work)| json!({ "project": p.name, "work": work }))
+            .collect();
+        print_json(&output)?;
+        return Ok(code);
+    }
+
+    for (p, work) in &report {
+        println!("{} ({})", p.name, p.path);
+        if work.uncommitted > 0 {
+            println!("    {} uncommitted file(s)", work.uncommitted);
+        }
+        for branch in &work.branches {
+            match &branch.upstream {
+                Some(upstream) => println!("    {}: {} commit(s) not pushed to {}", branch.branch, branch.commits, upstream),
+                None if work.has_remote => println!("    {}: no upstream, {} commit(s) on no remote", branch.branch, branch.commits),
+                None => println!("    {}: {} commit(s)", branch.branch, branch.commits),
+            }
+        }
+        if work.stashes > 0 {
+            println!("    {} stash(es)", work.stashes);
+        }
+        if !work.has_remote {
+            println!("    no remote configured");
+        }
+    }
+    if report.is_empty() {
+        println!("✓ Everything is pushed");
+    } else {
+        println!("{} repo(s) with unpushed work", report.len());
+    }
+    Ok(code)
+}
+
+fn duplicates(as_json: bool) -> CliResult {
+    let groups = crate::duplicate_report();
+    if as_json {
+        return print_json(&groups);
+    }
+
+    for group in &groups {
+        println!("{}", group.repository);
+        for clone in &group.clones {
+            let dirty = if clone.dirty { ", uncommitted changes" } else { "" };
+            println!("    {} [{}{}] {}", clone.path, clone.branch, dirty, format_size(clone.size));
+        }
+    }
+    if groups.is_empty() {
+        println!("✓ No repository is cloned twice");
+    } else {
+        let redundant: usize = groups.iter().map(|g| g.clones.len() - 1).sum();
+        println!("{} repo(s) cloned more than once, {} redundant clone(s)", groups.len(), redundant);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn activity(as_json: bool, name: Option<&str>, days: u32) -> CliResult {
+    let projects = store::load_projects();
+    let (activity, per_project) = match name {
+        Some(name) => {
+            let project = find_project(&projects, name)?;
+            (commit_activity(Path::new(&project.path), days)?, Vec::new())
+        }
+        None => {
+            let paths: Vec<String> = library(false).into_iter().filter(|p| p.has_git).map(|p| p.path).collect();
+            let report = library_activity(&paths, days);
+            if as_json {
+                return print_json(&report);
+            }
+            (report.total, report.projects)
+        }
+    };
+    if as_json {
+        return print_json(&activity);
+    }
+
+    println!(
+        "{} commit(s) in the last {} day(s), +{} -{}",
+        activity.commits, activity.days, activity.insertions, activity.deletions
+    );
+    let active: Vec<&CommitActivity> = per_project.iter().filter(|p| p.commits > 0).collect();
+    if !active.is_empty() {
+        println!("\nProjects");
+        for project in active.iter().take(10) {
+            let name = projects.iter().find(|p| p.path == project.path).map_or(project.path.as_str(), |p| &p.name);
+            println!("    {:>5}  {}", project.commits, name);
+        }
+    }
+    if !activity.authors.is_empty() {
+        println!("\nAuthors");
+        for author in activity.authors.iter().take(5) {
+            println!("    {:>5}  {} <{}>", author.commits, author.name, author.email);
+        }
+    }
+    if !activity.files.is_empty() {
+        println!("\nMost changed files");
+        for file in activity.files.iter().take(10) {
+            println!("    {:>5}  {}", file.commits, file.path);
+        }
+    }
+    if let Some(commit) = &activity.last_own_commit {
+        println!("\nYour last commit: {} {} ({})", commit.short_id, commit.summary, commit.date);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn team_bootstrap(as_json: bool, manifest: &Path, reinstall: bool) -> CliResult {
+    let runner = ProcessRunner::default();
+    let report = team::bootstrap(manifest, &runner, reinstall, |step| {
+        if as_json {
+            return;
+        }
+        let mark = match step.status.as_str() {
+            "ok" => "✓",
+            "failed" => "✗",
+            _ => "-",
+        };
+        println!("{} {} {}: {}", mark, step.repo, step.step, step.message);
+        if let (Some(log), "failed") = (&step.log_file, step.status.as_str()) {
+            println!("    log: {}", log);
+        }
+    })?;
+    notify_running_app();
+
+    let failed = report.failed().len();
+    if as_json {
+        print_json(&report)?;
+    } else if failed == 0 {
+        println!("✓ Workspace ready");
+    } else {
+        println!("{} step(s) failed", failed);
+    }
+    Ok(if failed == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn team_launch(as_json: bool, manifest: &Path, profile: &str) -> CliResult {
+    let message = team::launch_profile(manifest, profile, &store::load_settings().default_ide)?;
+    if as_json {
+        return print_json(&json!({ "profile": profile, "message": message }));
+    }
+    println!("{}", message);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_script(name: &str, script: &str) -> CliResult {
+    let projects = store::load_projects();
+    let project = find_project(&projects, name)?;
+    let path = Path::new(&project.path);
+
+    let scripts = read_scripts(path)?;
+    if !scripts.iter().any(|s| s.name == script) {
+        return Err(format!("{} has no \"{}\" script", project.name, script));
+    }
+
+    let status = launch::run_script(&project.path, script, project.package_manager.clone())?;
+
+    Ok(match status.code() {
+        Some(0) => ExitCode::SUCCESS,
+        Some(code) => ExitCode::from(code.clamp(1, 255) as u8),
+        None => ExitCode::FAILURE,
+    })
+}
+
+fn doctor(as_json: bool) -> CliResult {
+    let tools = collect_tool_versions();
+    let projects = store::load_projects();
+
+    let mut problems = Vec::new();
+    for p in &projects {
+        let path = Path::new(&p.path);
+        if !path.is_dir() {
+            problems.push(format!("{}: {} no longer exists", p.name, p.path));
+        } else if p.has_git && !path.join(".git").exists() {
+            problems.push(format!("{}: marked as a git repository but .git is missing", p.name));
+        }
+    }
+    for (i, p) in projects.iter().enumerate() {
+        if projects[..i].iter().any(|other| Path::new(&other.path) == Path::new(&p.path)) {
+            problems.push(format!("{}: listed more than once", p.name));
+        }
+    }
+
+    if as_json {
+        return print_json(&json!({
+            "dataDir": store::data_dir(),
+            "projects": projects.len(),
+            "tools": tools,
+            "problems": problems,
+        }));
+    }
+
+    println!("Data directory: {}", store::data_dir().display());
+    println!("Projects:       {}", projects.len());
+    println!();
+    for tool in &tools {
+        let mark = if tool.installed { "✓" } else { "✗" };
+        println!("{} {:<10} {}", mark, tool.name, tool.version.as_deref().unwrap_or("not found"));
+    }
+    println!();
+    if problems.is_empty() {
+        println!("✓ No problems found in the library");
+    } else {
+        for problem in &problems {
+            println!("✗ {}", problem);
+        }
+    }
+    Ok(if problems.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn path(as_json: bool, query: &str) -> CliResult {
+    let projects = library(false);
+    let project = search::best_match(&projects, query).ok_or_else(|| format!("No project matches \"{}\"", query))?;
+
+    if as_json {
+        return print_json(&json!({ "project": project.name, "path": project.path }));
+    }
+    println!("{}", project.path);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn subcommands() -> Vec<String> {
+    Cli::command()
+        .get_subcommands()
+        .filter(|c| !c.is_hide_set())
+        .map(|c| c.get_name().to_string())
+        .collect()
+}
+
+fn print_script(script: String) -> CliResult {
+    print!("{}", script);
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,112 @@
+use clap::ValueEnum;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+// Subcommands whose first argument is a library project
+const PROJECT_COMMANDS: &str = "open clean run path cd";
+
+const BASH_COMPLETIONS: &str = r#"_devlaunch() {
+    local cur=${COMP_WORDS[COMP_CWORD]}
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        local commands="@COMMANDS@"
+        [ "${COMP_WORDS[0]}" = dl ] && commands="cd $commands"
+        COMPREPLY=($(compgen -W "$commands" -- "$cur"))
+        return
+    fi
+    case "${COMP_WORDS[1]}" in
+        @PROJECT_PATTERN@)
+            if [ "$COMP_CWORD" -eq 2 ] || [ "${COMP_WORDS[1]}" = clean ]; then
+                # Project names may contain spaces, so split on newlines only
+                local IFS=$'\n'
+                COMPREPLY=($(compgen -W "$(devlaunch list --names 2>/dev/null)" -- "$cur"))
+            fi
+            ;;
+        add|scan) COMPREPLY=($(compgen -d -- "$cur")) ;;
+        completions|shell-init) COMPREPLY=($(compgen -W "bash zsh fish" -- "$cur")) ;;
+    esac
+}
+complete -F _devlaunch devlaunch dl
+"#;
+
+const ZSH_COMPLETIONS: &str = r#"_devlaunch() {
+    local -a commands projects
+    commands=(@COMMANDS@)
+    [[ $service == dl ]] && commands=(cd $commands)
+    if (( CURRENT == 2 )); then
+        compadd -a commands
+        return
+    fi
+    case $words[2] in
+        @PROJECT_PATTERN@)
+            if (( CURRENT == 3 )) || [[ $words[2] == clean ]]; then
+                projects=("${(@f)$(devlaunch list --names 2>/dev/null)}")
+                compadd -a projects
+            fi
+            ;;
+        add|scan) _files -/ ;;
+        completions|shell-init) compadd bash zsh fish ;;
+    esac
+}
+compdef _devlaunch devlaunch dl
+"#;
+
+const FISH_COMPLETIONS: &str = r#"function __devlaunch_projects
+    devlaunch list --names 2>/dev/null
+end
+for cmd in devlaunch dl
+    complete -c $cmd -f
+    complete -c $cmd -n __fish_use_subcommand -a "@COMMANDS@"
+    complete -c $cmd -n "__fish_seen_subcommand_from @PROJECT_COMMANDS@" -a "(__devlaunch_projects)"
+    complete -c $cmd -n "__fish_seen_subcommand_from add scan" -F
+    complete -c $cmd -n "__fish_seen_subcommand_from completions shell-init" -a "bash zsh fish"
+end
+complete -c dl -n __fish_use_subcommand -a cd -d "Jump to a project"
+"#;
+
+// `dl cd <query>` changes directory, anything else is passed to devlaunch
+const POSIX_FUNCTION: &str = r#"dl() {
+    if [ "$1" = cd ]; then
+        shift
+        local dir
+        dir="$(devlaunch path "$@")" && cd "$dir"
+    else
+        devlaunch "$@"
+    fi
+}
+"#;
+
+const FISH_FUNCTION: &str = r#"function dl --description "DevLaunch, with 'dl cd <project>'"
+    if test "$argv[1]" = cd
+        set -l dir (devlaunch path $argv[2..-1]); and cd $dir
+    else
+        devlaunch $argv
+    end
+end
+"#;
+
+/// Completion script for `devlaunch` and `dl`; project names are read from the library on each completion
+pub fn completions(shell: Shell, commands: &[String]) -> String {
+    let template = match shell {
+        Shell::Bash => BASH_COMPLETIONS,
+        Shell::Zsh => ZSH_COMPLETIONS,
+        Shell::Fish => FISH_COMPLETIONS,
+    };
+    template
+        .replace("@COMMANDS@", &commands.join(" "))
+        .replace("@PROJECT_COMMANDS@", PROJECT_COMMANDS)
+        .replace("@PROJECT_PATTERN@", &PROJECT_COMMANDS.replace(' ', "|"))
+}
+
+/// The `dl` function plus completions, meant for `eval "$(devlaunch shell-init bash)"` in a shell rc file
+pub fn shell_init(shell: Shell, commands: &[String]) -> String {
+    let function = match shell {
+        Shell::Bash | Shell::Zsh => POSIX_FUNCTION,
+        Shell::Fish => FISH_FUNCTION,
+    };
+    format!("{}\n{}", function, completions(shell, commands))
+}
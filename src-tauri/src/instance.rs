@@ -0,0 +1,218 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener as Listener, UnixStream as Stream};
+#[cfg(not(unix))]
+use std::net::{TcpListener as Listener, TcpStream as Stream};
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::args::StartupArgs;
+use devlaunch_core::launch::{launch_ide, launch_script, launch_terminal, read_scripts};
+use devlaunch_core::store;
+
+use crate::links;
+use crate::{add_to_library, mark_opened, notify, publish_library, show_main_window};
+
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What a short-lived process (second launch, CLI) can tell the running app
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Message {
+    /// Command-line arguments of a second launch
+    Args(StartupArgs),
+    /// The on-disk library was modified and should be reloaded
+    LibraryChanged,
+}
+
+#[cfg(unix)]
+fn socket_path() -> PathBuf {
+    store::runtime_dir().join("instance.sock")
+}
+
+// Windows has no Unix sockets in std, so the primary listens on a loopback port recorded here
+#[cfg(not(unix))]
+fn port_file() -> PathBuf {
+    store::runtime_dir().join("instance.port")
+}
+
+#[cfg(unix)]
+fn connect() -> io::Result<Stream> {
+    Stream::connect(socket_path())
+}
+
+#[cfg(not(unix))]
+fn connect() -> io::Result<Stream> {
+    let port = std::fs::read_to_string(port_file())?;
+    let port: u16 = port
+        .trim()
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid port file"))?;
+    Stream::connect(("127.0.0.1", port))
+}
+
+/// Sends `args` to an already running DevLaunch. Returns false when there is none.
+pub fn forward_to_running(args: &StartupArgs) -> bool {
+    send(&Message::Args(args.clone()))
+}
+
+/// Delivers a message to the running app, if any
+pub fn send(message: &Message) -> bool {
+    let mut stream = match connect() {
+        Ok(stream) => stream,
+        Err(_) => return false,
+    };
+
+    let payload = match serde_json::to_string(message) {
+        Ok(payload) => payload,
+        Err(_) => return false,
+    };
+    writeln!(stream, "{}", payload).and_then(|_| stream.flush()).is_ok()
+}
+
+fn already_running() -> io::Error {
+    io::Error::new(io::ErrorKind::AddrInUse, "another DevLaunch is already running")
+}
+
+/// Claims the instance socket so later launches forward to us. Fails with `AddrInUse` when
+/// another instance answers, for instance one started at the same time as us.
+#[cfg(unix)]
+pub fn bind() -> io::Result<Listener> {
+    let path = socket_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if connect().is_ok() {
+        return Err(already_running());
+    }
+    // Nobody answered on the socket, so whatever file is left over is stale
+    let _ = std::fs::remove_file(&path);
+    Listener::bind(&path)
+}
+
+#[cfg(not(unix))]
+pub fn bind() -> io::Result<Listener> {
+    if connect().is_ok() {
+        return Err(already_running());
+    }
+    let listener = Listener::bind(("127.0.0.1", 0))?;
+    let path = port_file();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, listener.local_addr()?.port().to_string())?;
+    Ok(listener)
+}
+
+/// Accepts forwarded arguments from later launches for the lifetime of the app
+pub fn listen(app: AppHandle, listener: Listener) {
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let app = app.clone();
+            std::thread::spawn(move || receive(&app, stream));
+        }
+    });
+}
+
+/// Reads one message from a connection; a client that connects and goes quiet is dropped
+/// after `READ_TIMEOUT` instead of holding up later launches
+fn receive(app: &AppHandle, stream: Stream) {
+    let mut line = String::new();
+    let read = stream.set_read_timeout(Some(READ_TIMEOUT)).and_then(|_| BufReader::new(stream).read_line(&mut line));
+    if let Err(e) = read {
+        log::warn!("Failed to read instance message: {}", e);
+        return;
+    }
+    // Launches checking whether we're running connect without sending anything
+    if line.trim().is_empty() {
+        return;
+    }
+    match serde_json::from_str::<Message>(&line) {
+        Ok(Message::Args(args)) => handle(app, &args, true),
+        Ok(Message::LibraryChanged) => publish_library(app, store::load_projects()),
+        Err(e) => log::warn!("Ignoring malformed instance message: {}", e),
+    }
+}
+
+/// Acts on startup arguments, either our own or ones forwarded by a second launch
+pub fn handle(app: &AppHandle, args: &StartupArgs, forwarded: bool) {
+    // A plain second launch should just bring the existing window back
+    if forwarded && (args.show || (args.targets.is_empty() && args.links.is_empty() && !args.minimized)) {
+        show_main_window(app);
+    }
+
+    for target in &args.targets {
+        if let Some(script) = &args.run {
+            run_in(app, target, script, forwarded);
+        } else if args.terminal {
+            if let Err(e) = launch_terminal(target) {
+                notify(app, "error", e);
+            }
+        } else {
+            open_or_add(app, target);
+        }
+    }
+    for link in &args.links {
+        links::follow(app, link);
+    }
+}
+
+pub fn same_path(a: &str, b: &str) -> bool {
+    let canonical = |p: &str| std::fs::canonicalize(p).unwrap_or_else(|_| PathBuf::from(p));
+    canonical(a) == canonical(b)
+}
+
+/// Opens a library project in the default IDE, or adds the directory to the library
+fn open_or_add(app: &AppHandle, target: &str) {
+    if !Path::new(target).is_dir() {
+        notify(app, "error", format!("Not a project directory: {}", target));
+        return;
+    }
+
+    let existing = store::load_projects()
+        .into_iter()
+        .find(|p| same_path(&p.path, target));
+
+    match existing {
+        Some(project) => {
+            let ide = store::load_settings().default_ide;
+            match launch_ide(&project.path, &ide) {
+                Ok(()) => mark_opened(app, &project.id),
+                Err(e) => notify(app, "error", e),
+            }
+        }
+        None => match add_to_library(app, target) {
+            Ok(project) => {
+                notify(app, "success", format!("Added \"{}\" to library", project.name));
+                show_main_window(app);
+            }
+            Err(e) => notify(app, "error", e),
+        },
+    }
+}
+
+/// Starts a package script in a terminal, used by the application launchers' "Run" action.
+/// Anything local can write to the instance channel, so forwarded requests are confirmed first.
+fn run_in(app: &AppHandle, target: &str, script: &str, forwarded: bool) {
+    let known = read_scripts(Path::new(target)).is_ok_and(|scripts| scripts.iter().any(|s| s.name == script));
+    if !known {
+        notify(app, "error", format!("{} has no \"{}\" script", target, script));
+        return;
+    }
+    let project = store::load_projects().into_iter().find(|p| same_path(&p.path, target));
+    if forwarded {
+        let source = "Another DevLaunch process";
+        if let Err(e) = links::confirm_run(app, target.to_string(), project, script.to_string(), source) {
+            notify(app, "error", e);
+        }
+        return;
+    }
+    match launch_script(target, script, project.and_then(|p| p.package_manager)) {
+        Ok(message) => notify(app, "success", message),
+        Err(e) => notify(app, "error", e),
+    }
+}
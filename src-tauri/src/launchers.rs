@@ -0,0 +1,48 @@
+use devlaunch_core::Project;
+
+/// Executable that desktop entries should start. An AppImage runs from a temporary mount,
+/// so there `$APPIMAGE` is the path that stays valid.
+#[cfg(target_os = "linux")]
+pub fn app_executable() -> Result<std::path::PathBuf, String> {
+    match std::env::var_os("APPIMAGE") {
+        Some(appimage) => Ok(appimage.into()),
+        None => std::env::current_exe().map_err(|e| format!("Failed to locate executable: {}", e)),
+    }
+}
+
+/// Writes or removes the application launcher of a project
+#[cfg(target_os = "linux")]
+pub fn set_launcher(project: &Project, enabled: bool) -> Result<(), String> {
+    use devlaunch_core::desktop;
+
+    let dir = desktop::applications_dir().ok_or("No data directory")?;
+    if enabled {
+        desktop::write_launcher(&dir, project, &app_executable()?)?;
+    } else {
+        desktop::remove_launcher(&dir, &project.id)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn set_launcher(_project: &Project, _enabled: bool) -> Result<(), String> {
+    Err("Application launchers are only supported on Linux".to_string())
+}
+
+/// Brings launchers in line with the library after it changed
+pub fn sync(projects: &[Project]) {
+    #[cfg(target_os = "linux")]
+    {
+        use devlaunch_core::desktop;
+
+        let (Some(dir), Ok(exe)) = (desktop::applications_dir(), app_executable()) else {
+            return;
+        };
+        if let Err(e) = desktop::sync_launchers(&dir, projects, &exe) {
+            log::warn!("Failed to update application launchers: {}", e);
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = projects;
+}
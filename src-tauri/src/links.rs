@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use tauri::AppHandle;
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+use devlaunch_core::deeplink::{self, DeepLink, LinkTarget};
+#[cfg(target_os = "linux")]
+use devlaunch_core::desktop;
+use devlaunch_core::launch::{launch_ide, launch_script, launch_terminal, read_scripts};
+use devlaunch_core::{search, store, Project};
+
+use crate::instance::same_path;
+#[cfg(target_os = "linux")]
+use crate::launchers::app_executable;
+use crate::{add_to_library, mark_opened, notify, show_main_window};
+
+/// Acts on a `devlaunch://` link passed on the command line, reporting problems as a toast
+pub fn follow(app: &AppHandle, link: &str) {
+    if let Err(e) = deeplink::parse(link).and_then(|link| dispatch(app, link)) {
+        notify(app, "error", e);
+    }
+}
+
+/// Directory the link points at, and its library entry if there is one
+fn resolve(target: &LinkTarget) -> Result<(String, Option<Project>), String> {
+    let projects = store::load_projects();
+    match target {
+        LinkTarget::Path(path) => {
+            if !Path::new(path).is_dir() {
+                return Err(format!("Not a directory: {}", path));
+            }
+            let project = projects.into_iter().find(|p| same_path(&p.path, path));
+            Ok((path.clone(), project))
+        }
+        LinkTarget::Project(query) => {
+            let project = search::best_match(&projects, query)
+                .cloned()
+                .ok_or_else(|| format!("No project matches \"{}\"", query))?;
+            Ok((project.path.clone(), Some(project)))
+        }
+    }
+}
+
+fn dispatch(app: &AppHandle, link: DeepLink) -> Result<(), String> {
+    // Links that execute commands only act once the user agreed, see `needs_confirmation`
+    if link.needs_confirmation() {
+        let DeepLink::Run { target, script } = link else {
+            return Err("This link needs a confirmation DevLaunch can't ask for".to_string());
+        };
+        let (path, project) = resolve(&target)?;
+        return confirm_run(app, path, project, script, "A link");
+    }
+
+    match link {
+        DeepLink::Open { target, ide } => {
+            let (path, project) = resolve(&target)?;
+            let ide = ide.unwrap_or_else(|| store::load_settings().default_ide);
+            launch_ide(&path, &ide)?;
+            if let Some(project) = project {
+                mark_opened(app, &project.id);
+            }
+            Ok(())
+        }
+        DeepLink::Terminal { target } => {
+            let (path, _) = resolve(&target)?;
+            launch_terminal(&path)
+        }
+        DeepLink::Add { path } => {
+            if store::load_projects().iter().any(|p| same_path(&p.path, &path)) {
+                notify(app, "info", format!("{} is already in the library", path));
+                return Ok(());
+            }
+            let project = add_to_library(app, &path)?;
+            notify(app, "success", format!("Added \"{}\" to library", project.name));
+            show_main_window(app);
+            Ok(())
+        }
+        DeepLink::Run { target, script } => {
+            let (path, project) = resolve(&target)?;
+            let message = launch_script(&path, &script, project.and_then(|p| p.package_manager))?;
+            notify(app, "success", message);
+            Ok(())
+        }
+    }
+}
+
+/// Runs a script asked for by `source` (a link, another process) only after the user has seen
+/// the exact command and agreed
+pub(crate) fn confirm_run(
+    app: &AppHandle,
+    path: String,
+    project: Option<Project>,
+    script: String,
+    source: &str,
+) -> Result<(), String> {
+    let command = read_scripts(Path::new(&path))?
+        .into_iter()
+        .find(|s| s.name == script)
+        .map(|s| s.command)
+        .ok_or_else(|| format!("{} has no \"{}\" script", path, script))?;
+
+    let name = project.as_ref().map(|p| p.name.clone()).unwrap_or_else(|| path.clone());
+    let package_manager = project.and_then(|p| p.package_manager);
+
+    let handle = app.clone();
+    app.dialog()
+        .message(format!(
+            "{} wants to run the \"{}\" script in {}:\n\n{}\n\nOnly continue if you trust where it came from.",
+            source, script, name, command
+        ))
+        .title("Run script?")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom("Run".to_string(), "Cancel".to_string()))
+        .show(move |confirmed| {
+            if !confirmed {
+                return;
+            }
+            match launch_script(&path, &script, package_manager) {
+                Ok(message) => notify(&handle, "success", message),
+                Err(e) => notify(&handle, "error", e),
+            }
+        });
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+const HANDLER_DESKTOP_FILE: &str = "devlaunch-url-handler.desktop";
+
+/// Registers this executable as the `devlaunch://` handler for the user, unless an
+/// installed package already declares it (see `linux/devlaunch.desktop`)
+#[cfg(target_os = "linux")]
+pub fn register_url_handler() -> Result<(), String> {
+    use std::process::Command;
+
+    let mime = format!("x-scheme-handler/{}", deeplink::SCHEME);
+    let current = Command::new("xdg-mime")
+        .args(["query", "default", &mime])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .map_err(|e| format!("Failed to run xdg-mime: {}", e))?;
+    if !current.is_empty() && current != HANDLER_DESKTOP_FILE {
+        return Ok(());
+    }
+
+    let entry = format!(
+        "[Desktop Entry]\nType=Application\nName=DevLaunch\nExec={} %u\nMimeType={};\nNoDisplay=true\nTerminal=false\n",
+        desktop::exec_arg(&app_executable()?.to_string_lossy()),
+        mime
+    );
+
+    let applications = desktop::applications_dir().ok_or("No data directory")?;
+    let path = applications.join(HANDLER_DESKTOP_FILE);
+    if current == HANDLER_DESKTOP_FILE && std::fs::read_to_string(&path).ok().as_deref() == Some(entry.as_str()) {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&applications).map_err(|e| format!("Failed to create {}: {}", applications.display(), e))?;
+    std::fs::write(&path, entry).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Command::new("xdg-mime")
+        .args(["default", HANDLER_DESKTOP_FILE, &mime])
+        .status()
+        .map_err(|e| format!("Failed to run xdg-mime: {}", e))?;
+    Ok(())
+}
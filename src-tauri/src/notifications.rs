@@ -0,0 +1,46 @@
+use devlaunch_core::launch::silent_command;
+
+/// Shows a desktop notification using the notifier that ships with the platform
+pub fn show(title: &str, body: &str) {
+    #[cfg(target_os = "linux")]
+    let result = silent_command("notify-send").args(["--app-name=DevLaunch", title, body]).status();
+
+    #[cfg(target_os = "macos")]
+    let result = silent_command("osascript")
+        .args([
+            "-e",
+            "on run argv",
+            "-e",
+            "display notification (item 2 of argv) with title (item 1 of argv)",
+            "-e",
+            "end run",
+            title,
+            body,
+        ])
+        .status();
+
+    // Title and body go through the environment so they never need PowerShell quoting
+    #[cfg(target_os = "windows")]
+    let result = silent_command("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", WINDOWS_TOAST])
+        .env("DEVLAUNCH_TITLE", title)
+        .env("DEVLAUNCH_BODY", body)
+        .status();
+
+    match result {
+        Ok(status) if status.success() => {}
+        Ok(status) => log::warn!("Notifier exited with {}", status),
+        Err(e) => log::warn!("Failed to show notification: {}", e),
+    }
+}
+
+#[cfg(target_os = "windows")]
+const WINDOWS_TOAST: &str = r#"
+[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null
+$template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02)
+$text = $template.GetElementsByTagName('text')
+$text.Item(0).AppendChild($template.CreateTextNode($env:DEVLAUNCH_TITLE)) | Out-Null
+$text.Item(1).AppendChild($template.CreateTextNode($env:DEVLAUNCH_BODY)) | Out-Null
+$toast = [Windows.UI.Notifications.ToastNotification]::new($template)
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('DevLaunch').Show($toast)
+"#;
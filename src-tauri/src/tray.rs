@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use tauri::{
+    image::Image,
+    menu::{Menu, MenuItem, PredefinedMenuItem, Submenu},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Manager,
+};
+
+use devlaunch_core::git::{get_git_info, pull_repo};
+use devlaunch_core::launch::{self, read_scripts};
+use devlaunch_core::{store, GitStatus, Project};
+
+use crate::{mark_opened, notify, show_main_window};
+
+pub const TRAY_ID: &str = "main";
+
+const MAX_TRAY_PROJECTS: usize = 10;
+
+/// Last known git state per project id, filled in by the background poller
+#[derive(Default)]
+pub struct TrayStatus {
+    git: Mutex<HashMap<String, GitStatus>>,
+}
+
+fn ide_display_name(ide: &str) -> &str {
+    match ide {
+        "code" | "vscode" => "VS Code",
+        "cursor" => "Cursor",
+        "webstorm" => "WebStorm",
+        "idea" => "IntelliJ IDEA",
+        "zed" => "Zed",
+        "xcode" => "Xcode",
+        "android-studio" => "Android Studio",
+        other => other,
+    }
+}
+
+/// Non-archived projects in tray order: pinned first, then most recently opened
+fn tray_projects(projects: &[Project]) -> Vec<&Project> {
+    let mut visible: Vec<&Project> = projects
+        .iter()
+        .filter(|p| !p.is_archived.unwrap_or(false))
+        .collect();
+
+    visible.sort_by(|a, b| {
+        b.is_pinned
+            .unwrap_or(false)
+            .cmp(&a.is_pinned.unwrap_or(false))
+            .then_with(|| b.last_opened.cmp(&a.last_opened))
+    });
+    visible.truncate(MAX_TRAY_PROJECTS);
+    visible
+}
+
+/// The script offered in the tray: the one last run from the app, else `dev` or `start`
+fn favorite_script(project: &Project) -> Option<String> {
+    if let Some(script) = &project.favorite_script {
+        return Some(script.clone());
+    }
+
+    let scripts = read_scripts(Path::new(&project.path)).unwrap_or_default();
+    ["dev", "start"]
+        .iter()
+        .find(|name| scripts.iter().any(|s| s.name == **name))
+        .map(|name| name.to_string())
+}
+
+/// Short git summary shown next to a project name, e.g. "● 3 changed"
+fn git_badge(status: &GitStatus) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(operation) = &status.operation {
+        parts.push(format!("{} in progress", operation));
+    }
+    if let Some(count) = status.count.filter(|count| *count > 0) {
+        parts.push(format!("● {} changed", count));
+    }
+    if let Some(behind) = status.behind.filter(|n| *n > 0) {
+        parts.push(format!("↓{}", behind));
+    }
+    if let Some(ahead) = status.ahead.filter(|n| *n > 0) {
+        parts.push(format!("↑{}", ahead));
+    }
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+fn needs_attention(status: &GitStatus) -> bool {
+    status.count.unwrap_or(0) > 0 || status.behind.unwrap_or(0) > 0 || status.operation.is_some()
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+}
+
+/// Tooltip text such as "3 repos with uncommitted changes, 1 repo behind upstream"
+fn status_summary(statuses: &HashMap<String, GitStatus>) -> String {
+    let dirty = statuses.values().filter(|s| s.count.unwrap_or(0) > 0).count();
+    let behind = statuses.values().filter(|s| s.behind.unwrap_or(0) > 0).count();
+    let unpushed = statuses.values().filter(|s| s.ahead.unwrap_or(0) > 0).count();
+    let busy = statuses.values().filter(|s| s.operation.is_some()).count();
+
+    let mut parts = Vec::new();
+    if busy > 0 {
+        parts.push(format!("{} mid-rebase or merge", plural(busy, "repo")));
+    }
+    if dirty > 0 {
+        parts.push(format!("{} with uncommitted changes", plural(dirty, "repo")));
+    }
+    if behind > 0 {
+        parts.push(format!("{} behind upstream", plural(behind, "repo")));
+    }
+    if unpushed > 0 {
+        parts.push(format!("{} with unpushed commits", plural(unpushed, "repo")));
+    }
+
+    if parts.is_empty() {
+        "DevLaunch · all repositories clean".to_string()
+    } else {
+        format!("DevLaunch · {}", parts.join(", "))
+    }
+}
+
+/// Default tray icon with an orange dot in the top-right corner
+fn badge_icon(base: &Image<'_>) -> Image<'static> {
+    let (width, height) = (base.width(), base.height());
+    let mut rgba = base.rgba().to_vec();
+
+    let radius = (width.min(height) as f32 * 0.22).max(2.0);
+    let (cx, cy) = (width as f32 - radius - 1.0, radius + 1.0);
+    for y in 0..height {
+        for x in 0..width {
+            let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+            if dx * dx + dy * dy <= radius * radius {
+                let i = ((y * width + x) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&[0xf5, 0x9e, 0x0b, 0xff]);
+            }
+        }
+    }
+
+    Image::new(&rgba, width, height).to_owned()
+}
+
+fn project_submenu(
+    app: &AppHandle,
+    project: &Project,
+    ide_name: &str,
+    git: Option<&GitStatus>,
+) -> tauri::Result<Submenu<tauri::Wry>> {
+    let id = &project.id;
+    let prefix = if project.is_pinned.unwrap_or(false) { "📌" } else { "📁" };
+    let label = match git.and_then(git_badge) {
+        Some(badge) => format!("{} {}   {}", prefix, project.name, badge),
+        None => format!("{} {}", prefix, project.name),
+    };
+
+    let open = MenuItem::with_id(app, format!("open:{}", id), format!("Open in {}", ide_name), true, None::<&str>)?;
+    let terminal = MenuItem::with_id(app, format!("terminal:{}", id), "Open Terminal", true, None::<&str>)?;
+    let reveal = MenuItem::with_id(app, format!("reveal:{}", id), "Reveal in File Manager", true, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let pull = MenuItem::with_id(app, format!("pull:{}", id), "Git Pull", project.has_git, None::<&str>)?;
+    let script = match favorite_script(project) {
+        Some(name) => MenuItem::with_id(app, format!("script:{}", id), format!("Run \"{}\"", name), true, None::<&str>)?,
+        None => MenuItem::with_id(app, format!("script:{}", id), "No favorite script", false, None::<&str>)?,
+    };
+
+    Submenu::with_id_and_items(
+        app,
+        format!("project:{}", id),
+        label,
+        true,
+        &[&open, &terminal, &reveal, &separator, &pull, &script],
+    )
+}
+
+fn build_menu(app: &AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let projects = store::load_projects();
+    let settings = store::load_settings();
+    let ide_name = ide_display_name(&settings.default_ide);
+
+    let menu = Menu::new(app)?;
+    menu.append(&MenuItem::with_id(app, "header", "── Quick Open ──", false, None::<&str>)?)?;
+    menu.append(&PredefinedMenuItem::separator(app)?)?;
+
+    let statuses = app.state::<TrayStatus>().git.lock().unwrap().clone();
+    let entries = tray_projects(&projects);
+    if entries.is_empty() {
+        menu.append(&MenuItem::with_id(app, "no_projects", "No projects yet", false, None::<&str>)?)?;
+    }
+    for project in entries {
+        menu.append(&project_submenu(app, project, ide_name, statuses.get(&project.id))?)?;
+    }
+
+    menu.append(&PredefinedMenuItem::separator(app)?)?;
+    menu.append(&MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?)?;
+    menu.append(&MenuItem::with_id(app, "quit", "Quit DevLaunch", true, None::<&str>)?)?;
+    Ok(menu)
+}
+
+pub fn create(app: &AppHandle) -> tauri::Result<()> {
+    app.manage(TrayStatus::default());
+    let menu = build_menu(app)?;
+
+    TrayIconBuilder::with_id(TRAY_ID)
+        .icon(app.default_window_icon().unwrap().clone())
+        .menu(&menu)
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Refreshes git state for the library and updates menu, tooltip and icon
+pub fn poll_status(app: &AppHandle) {
+    let statuses: HashMap<String, GitStatus> = store::load_projects()
+        .iter()
+        .filter(|p| p.has_git && !p.is_archived.unwrap_or(false))
+        .map(|p| (p.id.clone(), get_git_info(Path::new(&p.path))))
+        .collect();
+
+    *app.state::<TrayStatus>().git.lock().unwrap() = statuses;
+    refresh(app);
+    update_indicators(app);
+}
+
+fn update_indicators(app: &AppHandle) {
+    let tray = match app.tray_by_id(TRAY_ID) {
+        Some(tray) => tray,
+        None => return,
+    };
+
+    let statuses = app.state::<TrayStatus>().git.lock().unwrap().clone();
+    let _ = tray.set_tooltip(Some(status_summary(&statuses)));
+
+    if let Some(base) = app.default_window_icon() {
+        let attention = statuses.values().any(needs_attention);
+        let icon = if attention && store::load_settings().tray_badge {
+            badge_icon(base)
+        } else {
+            base.clone()
+        };
+        if let Err(e) = tray.set_icon(Some(icon)) {
+            log::warn!("Failed to update tray icon: {}", e);
+        }
+    }
+}
+
+/// Rebuilds the tray menu from the current library and settings
+pub fn refresh(app: &AppHandle) {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        match build_menu(app) {
+            Ok(menu) => {
+                if let Err(e) = tray.set_menu(Some(menu)) {
+                    log::warn!("Failed to update tray menu: {}", e);
+                }
+            }
+            Err(e) => log::warn!("Failed to build tray menu: {}", e),
+        }
+    }
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        "quit" => app.exit(0),
+        "show" => show_main_window(app),
+        _ => {
+            if let Some((action, project_id)) = id.split_once(':') {
+                run_project_action(app, action, project_id);
+            }
+        }
+    }
+}
+
+fn run_project_action(app: &AppHandle, action: &str, project_id: &str) {
+    let projects = store::load_projects();
+    let project = match projects.iter().find(|p| p.id == project_id) {
+        Some(project) => project.clone(),
+        None => return,
+    };
+
+    let result = match action {
+        "open" => {
+            let ide = store::load_settings().default_ide;
+            launch::launch_ide(&project.path, &ide).map(|_| mark_opened(app, &project.id))
+        }
+        "terminal" => launch::launch_terminal(&project.path),
+        "reveal" => launch::reveal_path(&project.path),
+        "pull" => {
+            // Pulling can take a while, keep the tray responsive
+            let app = app.clone();
+            std::thread::spawn(move || match pull_repo(&project.path, false) {
+                Ok(result) if !result.conflicts.is_empty() => {
+                    notify(&app, "warning", format!("Pulled {} with conflicts in {}", project.name, result.conflicts.join(", ")))
+                }
+                Ok(_) => notify(&app, "success", format!("Pulled {}", project.name)),
+                Err(e) => notify(&app, "error", e),
+            });
+            Ok(())
+        }
+        "script" => match favorite_script(&project) {
+            Some(script) => launch::launch_script(&project.path, &script, project.package_manager.clone())
+                .map(|message| notify(app, "success", message)),
+            None => Ok(()),
+        },
+        _ => Ok(()),
+    };
+
+    if let Err(e) = result {
+        notify(app, "error", e);
+    }
+}
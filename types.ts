@@ -5,9 +5,282 @@ export interface TechStack {
 
 export interface GitStatus {
     branch: string;
-    status: 'Clean' | 'Modified' | 'Behind' | 'Ahead' | 'Up to date' | 'N/A' | 'Unknown';
+    status: 'Clean' | 'Modified' | 'Behind' | 'Ahead' | 'Diverged' | 'Conflicted' | 'Detached'
+        | 'Rebasing' | 'Merging' | 'Cherry-picking' | 'Reverting' | 'Out of sync' | 'Up to date' | 'N/A' | 'Unknown';
     count?: number;
     type: 'success' | 'warning' | 'error' | 'neutral' | 'info';
+    upstream?: string;
+    // Both missing when the branch has no upstream
+    ahead?: number;
+    behind?: number;
+    staged?: number;
+    unstaged?: number;
+    untracked?: number;
+    conflicted?: number;
+    detached?: boolean;
+    operation?: 'rebase' | 'merge' | 'cherry-pick' | 'revert';
+    // Set on linked worktrees: the main working tree of the repository
+    worktreeOf?: string;
+    // Present when the repository has submodules
+    submodules?: SubmoduleStatus;
+    // Present when the repository stores files in Git LFS
+    lfs?: LfsStatus;
+}
+
+export interface SubmoduleStatus {
+    total: number;
+    uninitialized: number;
+    // Checked out at another commit than the one recorded
+    outOfDate: number;
+    // With changes inside
+    modified: number;
+}
+
+export interface LfsStatus {
+    files: number;
+    // Still pointers, not downloaded
+    missing: number;
+    installed: boolean;
+}
+
+export interface CloneOptions {
+    branch?: string;
+    // Commits of history to fetch; everything when unset
+    depth?: number;
+    recursive?: boolean;
+    // Folder name under the parent directory; derived from the URL when unset
+    directory?: string;
+}
+
+export interface CloneProgress {
+    // "Receiving objects", "Resolving deltas", ... or a whole message line
+    phase: string;
+    percent?: number;
+}
+
+export interface DuplicateClone {
+    path: string;
+    branch: string;
+    dirty: boolean;
+    // Bytes on disk, history included
+    size: number;
+}
+
+export interface DuplicateGroup {
+    // Normalized remote such as github.com/owner/repo, or the folder name
+    repository: string;
+    clones: DuplicateClone[];
+}
+
+export interface TeamRepo {
+    name: string;
+    url: string;
+    // Absolute once the manifest is read
+    path: string;
+    branch?: string;
+    depth?: number;
+    recursive: boolean;
+    tags: string[];
+    install: string[];
+}
+
+export interface LaunchProfile {
+    name: string;
+    repos: string[];
+    ide?: string;
+    script?: string;
+}
+
+export interface TeamManifest {
+    workspace: { root?: string; tags: string[] };
+    repos: TeamRepo[];
+    profiles: LaunchProfile[];
+}
+
+export interface TeamStep {
+    repo: string;
+    step: 'clone' | 'install' | 'library';
+    status: 'ok' | 'failed' | 'skipped';
+    message: string;
+    // Output of an install command
+    logFile?: string;
+}
+
+export interface TeamReport {
+    manifest: string;
+    steps: TeamStep[];
+    profiles: string[];
+}
+
+export interface RemoteInfo {
+    name: string;
+    url: string;
+    host: string;
+    path: string;
+    provider: 'github' | 'gitlab' | 'bitbucket' | 'gitea' | 'unknown';
+    webUrl: string;
+    branchUrl?: string;
+    // Pull/merge request page from the current branch into the default one
+    compareUrl?: string;
+}
+
+export interface GitHost {
+    // git.example.com, or *.example.com for every subdomain
+    pattern: string;
+    provider: string;
+}
+
+export interface BranchInfo {
+    name: string;
+    isRemote: boolean;
+    isCurrent: boolean;
+    commit: string;
+    summary: string;
+    lastCommitDate: string;
+    upstream?: string;
+    ahead: number;
+    behind: number;
+}
+
+// Outcome of a branch operation on one project
+export interface BranchResult {
+    path: string;
+    ok: boolean;
+    message: string;
+    // Refused because of uncommitted changes; can be retried with stash
+    dirty: boolean;
+}
+
+export interface CommitInfo {
+    id: string;
+    shortId: string;
+    summary: string;
+    author: string;
+    authorEmail: string;
+    date: string;
+    filesChanged: number;
+    insertions: number;
+    deletions: number;
+}
+
+export interface ActivityCount {
+    // YYYY-MM-DD; the Monday for weekly counts
+    date: string;
+    commits: number;
+}
+
+export interface AuthorActivity {
+    name: string;
+    email: string;
+    commits: number;
+    insertions: number;
+    deletions: number;
+}
+
+export interface FileActivity {
+    // Prefixed with the project folder in library-wide totals
+    path: string;
+    commits: number;
+    insertions: number;
+    deletions: number;
+}
+
+export interface CommitActivity {
+    // Empty for library-wide totals
+    path: string;
+    days: number;
+    commits: number;
+    insertions: number;
+    deletions: number;
+    // Every day of the window, oldest first
+    daily: ActivityCount[];
+    weekly: ActivityCount[];
+    authors: AuthorActivity[];
+    files: FileActivity[];
+    // Latest commit by the configured git user.email
+    lastOwnCommit?: CommitInfo;
+}
+
+export interface LibraryActivity {
+    total: CommitActivity;
+    projects: CommitActivity[];
+}
+
+export interface FileChange {
+    path: string;
+    oldPath?: string;
+    status: 'added' | 'modified' | 'deleted' | 'renamed' | 'copied' | 'typechange' | 'untracked' | 'conflicted';
+    staged: boolean;
+    unstaged: boolean;
+    insertions: number;
+    deletions: number;
+}
+
+export interface DiffSummary {
+    files: FileChange[];
+    insertions: number;
+    deletions: number;
+}
+
+export interface StashEntry {
+    index: number;
+    message: string;
+    branch?: string;
+    date: string;
+}
+
+export interface PullResult {
+    message: string;
+    // Local changes were stashed before pulling...
+    stashed: boolean;
+    // ...and re-applied; otherwise they are still in the stash
+    restored: boolean;
+    conflicts: string[];
+}
+
+export type BulkGitOp = 'fetch' | 'pull' | 'status';
+
+export interface BulkGitResult {
+    path: string;
+    ok: boolean;
+    // Left alone on purpose, e.g. a pull over uncommitted changes
+    skipped: boolean;
+    message: string;
+    status?: GitStatus;
+}
+
+export interface BulkGitProgress {
+    op: BulkGitOp;
+    done: number;
+    total: number;
+}
+
+export interface WorktreeInfo {
+    path: string;
+    head?: string;
+    // Missing when HEAD is detached
+    branch?: string;
+    isMain: boolean;
+    bare: boolean;
+    detached: boolean;
+    locked: boolean;
+    // Its directory is gone
+    prunable: boolean;
+}
+
+export interface UnpushedBranch {
+    branch: string;
+    // Missing when the branch tracks nothing or its upstream was deleted
+    upstream?: string;
+    commits: number;
+}
+
+export interface UnpushedWork {
+    path: string;
+    uncommitted: number;
+    branches: UnpushedBranch[];
+    stashes: number;
+    hasRemote: boolean;
 }
 
 export interface ProjectScript {
@@ -40,6 +313,7 @@ export interface Project {
     buildStorage?: string;
     isArchived?: boolean;
     isPinned?: boolean;
+    hasLauncher?: boolean;
     hasNodeModules: boolean;
     hasBuildFolder: boolean;
     buildFolderName?: string;
@@ -47,9 +321,11 @@ export interface Project {
     notes?: string;
     tags?: string[];
     scripts?: ProjectScript[];
+    favoriteScript?: string;
     healthStatus?: HealthStatus;
     projectType?: 'node' | 'flutter' | 'android' | 'python' | 'rust' | 'go' | 'other';
     hasGit?: boolean;
+    remotes?: RemoteInfo[];
 }
 
 export interface ProjectScanResult {
@@ -67,9 +343,22 @@ export interface AppSettings {
     confirmBeforeDelete: boolean;
     showArchivedProjects: boolean;
     defaultSort: SortOption;
+    trayBadge: boolean;
+    statusPollMinutes: number;
+    runInBackground: boolean;
+    apiEnabled: boolean;
+    backgroundFetch: boolean;
+    fetchIntervalMinutes: number;
+    fetchRecentDays: number;
+    unpushedReminder: boolean;
+    unpushedReminderTime: string;
+    autoClean: boolean;
+    autoCleanDays: number;
+    watchLibrary: boolean;
+    gitHosts: GitHost[];
 }
 
-export type ViewType = 'library' | 'settings' | 'activity' | 'statistics' | 'templates';
+export type ViewType = 'library' | 'settings' | 'activity' | 'statistics' | 'templates' | 'unpushed' | 'duplicates';
 
 export interface Toast {
     id: string;